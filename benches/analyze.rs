@@ -0,0 +1,33 @@
+//! Benchmarks for the audio analysis pipeline.
+
+use std::{fs::File, io::Read};
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use klib::analyze::base::{get_notes_from_audio_data, get_notes_from_audio_data_batch};
+
+const CLIP_COUNT: usize = 16;
+
+fn load_test_data() -> Vec<f32> {
+    let mut file = File::open("tests/vec.bin").unwrap();
+    let mut buffer = vec![];
+
+    file.read_to_end(&mut buffer).unwrap();
+
+    buffer.chunks_exact(4).map(|b| f32::from_ne_bytes([b[0], b[1], b[2], b[3]])).collect()
+}
+
+fn bench_analyze(c: &mut Criterion) {
+    let clips = vec![load_test_data(); CLIP_COUNT];
+
+    let mut group = c.benchmark_group("analyze");
+    group.sample_size(10);
+
+    group.bench_function("sequential", |b| b.iter(|| clips.iter().map(|clip| get_notes_from_audio_data(clip, 5).unwrap()).collect::<Vec<_>>()));
+
+    group.bench_function("batch", |b| b.iter(|| get_notes_from_audio_data_batch(&clips, 5).unwrap()));
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_analyze);
+criterion_main!(benches);
@@ -0,0 +1,56 @@
+//! Benchmarks for guessing chords from notes, and computing the notes of chords.
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use klib::core::{
+    analyzer::ChordAnalyzer,
+    base::Parsable,
+    chord::{Chord, Chordable, HasChord},
+    note::Note,
+};
+
+const VOICINGS: [&str; 8] = ["C", "Am7", "F♯m7(♭5)", "B♭7(♭9)", "E♭maj7", "Gsus4", "C/E", "Dm7/G"];
+
+fn load_clusters() -> Vec<Vec<Note>> {
+    VOICINGS
+        .iter()
+        .flat_map(|name| {
+            let chord = Chord::parse(name).unwrap();
+
+            (0..3).map(move |inversion| chord.clone().with_inversion(inversion).chord())
+        })
+        .collect()
+}
+
+fn bench_chord(c: &mut Criterion) {
+    let clusters = load_clusters();
+    let analyzer = ChordAnalyzer::new();
+
+    let mut group = c.benchmark_group("chord");
+    group.sample_size(10);
+
+    group.bench_function("try_from_notes", |b| b.iter(|| clusters.iter().map(|notes| Chord::try_from_notes(notes).unwrap()).collect::<Vec<_>>()));
+
+    group.bench_function("analyzer", |b| b.iter(|| clusters.iter().map(|notes| analyzer.analyze(notes)).collect::<Vec<_>>()));
+
+    group.finish();
+}
+
+fn bench_chord_notes(c: &mut Criterion) {
+    let chords = VOICINGS.iter().map(|name| Chord::parse(name).unwrap()).collect::<Vec<_>>();
+
+    // Prime the caches, so that only repeated calls are measured.
+    for chord in &chords {
+        chord.chord();
+    }
+
+    let mut group = c.benchmark_group("chord_notes");
+
+    group.bench_function("uncached", |b| b.iter(|| chords.iter().map(|chord| chord.clone().chord()).collect::<Vec<_>>()));
+
+    group.bench_function("cached", |b| b.iter(|| chords.iter().map(|chord| chord.chord()).collect::<Vec<_>>()));
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_chord, bench_chord_notes);
+criterion_main!(benches);
@@ -9,33 +9,261 @@ use rustfft::{
     FftPlanner,
 };
 
-use crate::core::note::{HasPrimaryHarmonicSeries, ALL_PITCH_NOTES_WITH_FREQUENCY};
+use crate::core::note::{CEight, COne, HasPrimaryHarmonicSeries, A, ALL_PITCH_NOTES_WITH_FREQUENCY};
+
+use crate::core::{base::Res, helpers::cents, note::Note, pitch::HasFrequency};
+
+// Options.
+
+/// Options that control how spectral peaks are mapped to notes.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AnalyzeOptions {
+    /// Whether or not to attribute peaks at (roughly) integer multiples of a detected fundamental (2×, 3×, 4×, etc.)
+    /// to that fundamental's harmonics, rather than reporting them as separate notes.
+    ///
+    /// This is off by default, since it also drops notes that are really played at those intervals (e.g., an octave,
+    /// or an octave and a fifth, above the bass).
+    pub collapse_octave_harmonics: bool,
+    /// When collapsing harmonics, a higher note is still reported as a separate note if its energy exceeds
+    /// the fundamental's energy by more than this ratio (i.e., it is louder than harmonics would explain).
+    pub max_harmonic_energy_ratio: f32,
+    /// Spectral peaks within this many cents of a stronger peak are merged into it (at their magnitude-weighted average
+    /// frequency) before being mapped to notes, which keeps vibrato and pitch drift from splitting one note into several.
+    ///
+    /// A tolerance of `0.0` disables merging.
+    pub merge_tolerance_cents: f32,
+}
 
-use crate::core::{base::Res, note::Note, pitch::HasFrequency};
+impl Default for AnalyzeOptions {
+    fn default() -> Self {
+        Self {
+            collapse_octave_harmonics: false,
+            max_harmonic_energy_ratio: 4.0,
+            merge_tolerance_cents: 50.0,
+        }
+    }
+}
 
-/// Gets notes from audio data.
-pub fn get_notes_from_audio_data(data: &[f32], length_in_seconds: u8) -> Res<Vec<Note>> {
-    if length_in_seconds < 1 {
-        return Err(anyhow::Error::msg("Listening length in seconds must be greater than 1."));
+// Config.
+
+/// The sample rate (in Hz) that the analysis thresholds (and the ML models) were tuned for.
+pub const REFERENCE_SAMPLE_RATE: u32 = 44_100;
+
+/// The default number of (1 Hz) bins of the smoothed frequency space that are analyzed.
+pub const DEFAULT_FREQUENCY_SPACE_SIZE: usize = 8192;
+
+/// The shape of the audio data to analyze, and of the frequency space computed from it.
+///
+/// The FFT magnitudes grow with the number of samples, so they are normalized to [`REFERENCE_SAMPLE_RATE`], which
+/// allows audio recorded at any sample rate to be analyzed consistently.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AnalysisConfig {
+    /// The sample rate (in Hz) of the (mono) audio data.
+    pub sample_rate: u32,
+    /// The number of (1 Hz) bins of the smoothed frequency space that are analyzed.
+    pub frequency_space_size: usize,
+    /// The length of audio data (in seconds) that is analyzed.
+    pub length_in_seconds: u8,
+}
+
+impl AnalysisConfig {
+    /// Returns a config for audio data at the given sample rate and length (with the default frequency space size).
+    pub fn new(sample_rate: u32, length_in_seconds: u8) -> Self {
+        Self {
+            sample_rate,
+            length_in_seconds,
+            ..Default::default()
+        }
+    }
+
+    /// Returns a config for `num_samples` of audio data that were recorded over `length_in_seconds` (i.e., the sample rate
+    /// is inferred from the length of the recording).
+    pub fn from_recording(num_samples: usize, length_in_seconds: u8) -> Self {
+        let sample_rate = num_samples.checked_div(length_in_seconds as usize).unwrap_or_default();
+
+        Self::new(sample_rate as u32, length_in_seconds)
+    }
+
+    /// Returns the number of samples that are analyzed.
+    pub fn num_samples(&self) -> usize {
+        self.sample_rate as usize * self.length_in_seconds as usize
+    }
+}
+
+impl Default for AnalysisConfig {
+    fn default() -> Self {
+        Self {
+            sample_rate: REFERENCE_SAMPLE_RATE,
+            frequency_space_size: DEFAULT_FREQUENCY_SPACE_SIZE,
+            length_in_seconds: 5,
+        }
     }
+}
+
+/// Gets notes from audio data that was recorded over `length_in_seconds` (see [`AnalysisConfig::from_recording`]).
+pub fn get_notes_from_audio_data(data: &[f32], length_in_seconds: u8) -> Res<Vec<Note>> {
+    get_notes_from_audio_data_with_options(data, length_in_seconds, &AnalyzeOptions::default())
+}
+
+/// Gets notes from audio data that was recorded over `length_in_seconds`, using the provided [`AnalyzeOptions`].
+pub fn get_notes_from_audio_data_with_options(data: &[f32], length_in_seconds: u8, options: &AnalyzeOptions) -> Res<Vec<Note>> {
+    get_notes_from_audio_data_with_config(data, &AnalysisConfig::from_recording(data.len(), length_in_seconds), options)
+}
+
+/// Gets notes from audio data described by the [`AnalysisConfig`], using the provided [`AnalyzeOptions`].
+pub fn get_notes_from_audio_data_with_config(data: &[f32], config: &AnalysisConfig, options: &AnalyzeOptions) -> Res<Vec<Note>> {
+    let smoothed_frequency_space = get_smoothed_frequency_space_with_config(data, config)?;
 
+    Ok(get_notes_from_smoothed_frequency_space_with_options(&smoothed_frequency_space, options))
+}
+
+/// Gets the smoothed (1 Hz bin) frequency space of audio data described by the [`AnalysisConfig`].
+///
+/// Only the first `length_in_seconds` of the data are analyzed, and the magnitudes are normalized to [`REFERENCE_SAMPLE_RATE`].
+/// The result has (at most) `frequency_space_size` bins.
+pub fn get_smoothed_frequency_space_with_config(data: &[f32], config: &AnalysisConfig) -> Res<Vec<(f32, f32)>> {
     let num_nan = data.iter().filter(|n| n.is_nan()).count();
     if num_nan > 0 {
         return Err(anyhow::Error::msg(format!("{num_nan} NaNs in audio data.")));
     }
 
+    if config.length_in_seconds < 1 {
+        return Err(anyhow::Error::msg("Listening length in seconds must be greater than 1."));
+    }
+
+    if config.sample_rate == 0 || config.frequency_space_size == 0 {
+        return Err(anyhow::Error::msg("The sample rate and frequency space size must be greater than zero."));
+    }
+
+    let num_samples = config.num_samples();
+
+    if data.len() < num_samples {
+        return Err(anyhow::Error::msg(format!(
+            "Expected at least {num_samples} samples ({} seconds at {} Hz), but got {}.",
+            config.length_in_seconds,
+            config.sample_rate,
+            data.len()
+        )));
+    }
+
+    let data = &data[..num_samples];
+
+    let scale = REFERENCE_SAMPLE_RATE as f32 / config.sample_rate as f32;
+    let frequency_space = get_frequency_space(data, config.length_in_seconds).into_iter().map(|(f, m)| (f, m * scale)).collect::<Vec<_>>();
+
+    Ok(get_smoothed_frequency_space(&frequency_space, config.length_in_seconds)
+        .into_iter()
+        .take(config.frequency_space_size)
+        .collect())
+}
+
+/// Gets notes from a batch of audio clips (each of which is `length_in_seconds` long).
+///
+/// When the `analyze_parallel` feature is enabled, the clips are processed in parallel with [`rayon`];
+/// otherwise, they are processed sequentially.  Either way, the results are identical, and in the same order as the clips.
+pub fn get_notes_from_audio_data_batch<T>(clips: &[T], length_in_seconds: u8) -> Res<Vec<Vec<Note>>>
+where
+    T: AsRef<[f32]> + Sync,
+{
+    #[cfg(feature = "analyze_parallel")]
+    {
+        use rayon::prelude::*;
+
+        clips.par_iter().map(|clip| get_notes_from_audio_data(clip.as_ref(), length_in_seconds)).collect()
+    }
+
+    #[cfg(not(feature = "analyze_parallel"))]
+    {
+        clips.iter().map(|clip| get_notes_from_audio_data(clip.as_ref(), length_in_seconds)).collect()
+    }
+}
+
+/// Analyzes the intonation of the notes in the audio data.
+///
+/// For every detected note, returns the note along with the average deviation (in cents) of its sounding partials
+/// from equal temperament, where `reference` is the frequency of A4 (e.g., `440.0`).  The average is weighted by
+/// the magnitude of each partial, and a positive deviation means that the note is sharp.
+pub fn analyze_intonation(data: &[f32], length_in_seconds: u8, reference: f32) -> Res<Vec<(Note, f32)>> {
+    // Only the first few partials are considered, since higher partials of real instruments are often inharmonic.
+    const MAX_PARTIAL: usize = 4;
+
+    let notes = get_notes_from_audio_data(data, length_in_seconds)?;
     let frequency_space = get_frequency_space(data, length_in_seconds);
 
-    // Smooth the frequency space.
+    // Only the first half of the spectrum is meaningful (the rest is the mirror image).
+    let frequency_space = &frequency_space[..frequency_space.len() / 2];
+    let reference_ratio = reference / A.frequency();
+
+    let result = notes
+        .into_iter()
+        .map(|note| {
+            let expected = note.frequency() * reference_ratio;
+
+            let partials = (1..=MAX_PARTIAL)
+                .filter_map(|partial| {
+                    let target = expected * partial as f32;
+
+                    get_interpolated_peak(frequency_space, target).map(|(frequency, magnitude)| (cents(target, frequency), magnitude))
+                })
+                .collect::<Vec<_>>();
 
-    let smoothed_frequency_space = get_smoothed_frequency_space(&frequency_space, length_in_seconds);
-    //plot_frequency_space(&smoothed_frequency_space, "frequency_space", 100f32, 1000f32);
+            // Ignore partials that are too quiet to be reliable (they are most likely spectral leakage).
+            let max_magnitude = partials.iter().map(|(_, m)| *m).fold(0f32, f32::max);
+            let partials = partials.into_iter().filter(|(_, m)| *m >= max_magnitude * 0.1);
 
-    Ok(get_notes_from_smoothed_frequency_space(&smoothed_frequency_space))
+            let (weighted_sum, total_magnitude) = partials.fold((0f32, 0f32), |(ws, tm), (c, m)| (ws + c * m, tm + m));
+            let deviation = if total_magnitude > 0f32 { weighted_sum / total_magnitude } else { 0f32 };
+
+            (note, deviation)
+        })
+        .collect();
+
+    Ok(result)
+}
+
+/// Computes the chromagram of the audio data (the energy in each of the 12 pitch classes, where `C` is the first bin).
+///
+/// The (unsmoothed) frequency space of the whole clip is folded into pitch classes by the nearest equal-tempered note
+/// (only the bins between `C1` and `C8` are considered), and the result is normalized such that the strongest pitch class
+/// is `1.0`.  Silence (or an empty clip) yields all zeros.
+pub fn chromagram(data: &[f32], sample_rate: u32) -> [f32; 12] {
+    let mut chroma = [0f32; 12];
+
+    if data.is_empty() || sample_rate == 0 || data.iter().any(|n| n.is_nan()) {
+        return chroma;
+    }
+
+    // The frequency space is computed for a one second clip, so its bins need to be rescaled to the clip's true length.
+    let bin_width = sample_rate as f32 / data.len() as f32;
+    let frequency_space = get_frequency_space(data, 1);
+
+    for (k, magnitude) in frequency_space[..frequency_space.len() / 2].iter().map(|(k, m)| (*k, *m)) {
+        let frequency = k * bin_width;
+
+        if frequency < COne.frequency() || frequency > CEight.frequency() {
+            continue;
+        }
+
+        let semitones_from_a = (cents(A.frequency(), frequency) / 100.0).round() as i32;
+        chroma[(semitones_from_a + 9).rem_euclid(12) as usize] += magnitude * magnitude;
+    }
+
+    let max = chroma.iter().copied().fold(0f32, f32::max);
+
+    if max > 0f32 {
+        chroma.iter_mut().for_each(|c| *c /= max);
+    }
+
+    chroma
 }
 
 /// Gets notes from pre-smoothed frequency data (helps with model training deterministic features).
 pub fn get_notes_from_smoothed_frequency_space(smoothed_frequency_space: &[(f32, f32)]) -> Vec<Note> {
+    get_notes_from_smoothed_frequency_space_with_options(smoothed_frequency_space, &AnalyzeOptions::default())
+}
+
+/// Gets notes from pre-smoothed frequency data, using the provided [`AnalyzeOptions`].
+pub fn get_notes_from_smoothed_frequency_space_with_options(smoothed_frequency_space: &[(f32, f32)], options: &AnalyzeOptions) -> Vec<Note> {
     // Translate the frequency space into a "peak space" (dampen values that are not the "peak" of a specified window).
 
     let peak_space = translate_frequency_space_to_peak_space(smoothed_frequency_space);
@@ -43,7 +271,7 @@ pub fn get_notes_from_smoothed_frequency_space(smoothed_frequency_space: &[(f32,
 
     // Bucket top N bins into their proper notes, and keep "magnitude".
 
-    let peak_best_notes = get_likely_notes_from_peak_space(&peak_space, 0.1);
+    let peak_best_notes = get_likely_notes_from_peak_space(&peak_space, 0.1, options);
     //.into_iter().map(|(n, _)| n).collect::<Vec<_>>();
     let best_notes = peak_best_notes;
     //let binned_best_notes = get_likely_notes_using_bins(smoothed_frequency_space, 0.5, 0.1);
@@ -52,7 +280,7 @@ pub fn get_notes_from_smoothed_frequency_space(smoothed_frequency_space: &[(f32,
 
     // Fold the harmonic series into the core notes.
 
-    reduce_notes_by_harmonic_series(&best_notes, 0.1)
+    reduce_notes_by_harmonic_series(&best_notes, 0.1, options)
 }
 
 /// Gets the frequency space from the audio data.
@@ -138,17 +366,17 @@ pub fn compute_cqt(frequency_space: &[f32]) -> Vec<f32> {
 
 /// Calculates the "smoothed" frequency space by normalizing to 1.0 seconds of playback.
 pub fn get_smoothed_frequency_space(frequency_space: &[(f32, f32)], length_in_seconds: u8) -> Vec<(f32, f32)> {
-    let mut smoothed_frequency_space = Vec::new();
     let size = length_in_seconds as usize;
 
-    for k in (0..frequency_space.len()).step_by(size) {
-        let average_frequency = frequency_space[k..k + size].iter().map(|(f, _)| f).sum::<f32>() / size as f32;
-        let average_magnitude = frequency_space[k..k + size].iter().map(|(_, m)| m).sum::<f32>() / size as f32;
-
-        smoothed_frequency_space.push((average_frequency, average_magnitude));
-    }
+    // Using `chunks_exact` (rather than indexing) allows the compiler to elide bounds checks and vectorize the sums.
+    frequency_space
+        .chunks_exact(size)
+        .map(|chunk| {
+            let (frequency_sum, magnitude_sum) = chunk.iter().fold((0f32, 0f32), |(fs, ms), (f, m)| (fs + f, ms + m));
 
-    smoothed_frequency_space
+            (frequency_sum / size as f32, magnitude_sum / size as f32)
+        })
+        .collect()
 }
 
 /// Translate the frequency space into a "peak space".
@@ -160,7 +388,7 @@ pub fn translate_frequency_space_to_peak_space(frequency_space: &[(f32, f32)]) -
 
     // Compute proper start and end indexes.  // Only need to find peaks within the limits of a piano / singing.
     let min_index = 50;
-    let max_index = 8_000;
+    let max_index = 8_000.min(frequency_space.len() * 50 / 51);
 
     let mut peak_space = frequency_space.to_vec();
 
@@ -199,12 +427,18 @@ pub fn translate_frequency_space_to_peak_space(frequency_space: &[(f32, f32)]) -
     let skip = min_index;
     let take = max_index - min_index;
 
-    for (k, (_, magnitude)) in peak_space.iter_mut().enumerate().skip(skip).take(take) {
-        let window_size = 3;
+    let window_size = 3;
 
+    // Each bin only depends on its neighbors in the (unmodified) frequency space, so the windows can be zipped together
+    // without any bounds checks, which allows the compiler to vectorize this loop.
+    let left = frequency_space[skip - window_size..].iter();
+    let center = frequency_space[skip..].iter();
+    let right = frequency_space[skip + window_size..].iter();
+
+    for ((((_, magnitude), (_, l)), (_, c)), (_, r)) in peak_space[skip..skip + take].iter_mut().zip(left).zip(center).zip(right) {
         // Compute the average derivative.
-        let average_right_derivative = ((frequency_space[k + window_size].1 - frequency_space[k].1) / window_size as f32).abs();
-        let average_left_derivative = ((frequency_space[k].1 - frequency_space[k - window_size].1) / window_size as f32).abs();
+        let average_right_derivative = ((r - c) / window_size as f32).abs();
+        let average_left_derivative = ((c - l) / window_size as f32).abs();
         let average_derivative = (average_right_derivative + average_left_derivative) / 2f32;
 
         // Zero out the peaks with a low relative derivative.
@@ -218,7 +452,7 @@ pub fn translate_frequency_space_to_peak_space(frequency_space: &[(f32, f32)]) -
 }
 
 /// Get likely notes from the peak space.
-fn get_likely_notes_from_peak_space(peak_space: &[(f32, f32)], cutoff: f32) -> Vec<(Note, f32)> {
+fn get_likely_notes_from_peak_space(peak_space: &[(f32, f32)], cutoff: f32, options: &AnalyzeOptions) -> Vec<(Note, f32)> {
     let mut peak_space = peak_space.iter().filter(|(_, m)| *m > 0.1).copied().collect::<Vec<_>>();
     peak_space.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap());
 
@@ -227,6 +461,9 @@ fn get_likely_notes_from_peak_space(peak_space: &[(f32, f32)], cutoff: f32) -> V
     // Take all peaks with 10% or more of the max power.
     let peak_space = peak_space.into_iter().filter(|(_, m)| *m > max_power * cutoff).collect::<Vec<_>>();
 
+    // Merge near-duplicate peaks into their strongest neighbor.
+    let peak_space = merge_nearby_peaks(&peak_space, options.merge_tolerance_cents);
+
     let mut candidates = HashMap::new();
 
     for (frequency, magnitude) in &peak_space {
@@ -240,8 +477,45 @@ fn get_likely_notes_from_peak_space(peak_space: &[(f32, f32)], cutoff: f32) -> V
     candidates.into_iter().collect::<Vec<_>>()
 }
 
+/// Merges every peak into the strongest peak within `tolerance_cents` of it.
+///
+/// The peaks must be sorted from largest magnitude to smallest.  Each merged peak is placed at the magnitude-weighted
+/// average frequency of its members, and carries their combined magnitude.
+fn merge_nearby_peaks(peaks: &[(f32, f32)], tolerance_cents: f32) -> Vec<(f32, f32)> {
+    if tolerance_cents <= 0f32 {
+        return peaks.to_vec();
+    }
+
+    // Each cluster is (strongest frequency, weighted frequency sum, magnitude sum).
+    let mut clusters: Vec<(f32, f32, f32)> = Vec::new();
+
+    for (frequency, magnitude) in peaks {
+        match clusters.iter_mut().find(|(anchor, _, _)| cents(*anchor, *frequency).abs() <= tolerance_cents) {
+            Some((_, weighted_sum, total)) => {
+                *weighted_sum += frequency * magnitude;
+                *total += magnitude;
+            }
+            None => clusters.push((*frequency, frequency * magnitude, *magnitude)),
+        }
+    }
+
+    clusters.into_iter().map(|(_, weighted_sum, total)| (weighted_sum / total, total)).collect()
+}
+
+/// Returns whether or not `other` is (roughly) an integer multiple (up to the 16th harmonic) of the `fundamental` frequency.
+fn is_integer_harmonic(fundamental: f32, other: f32) -> bool {
+    // The equal tempered approximations of the lower harmonics (2×, 3×, 4×, 5×, 6×, 8×, 9×, 10×, 12×, 15×, 16×)
+    // are all within this many cents of the "true" harmonic.
+    const HARMONIC_TOLERANCE_CENTS: f32 = 15.0;
+
+    let ratio = other / fundamental;
+    let harmonic = ratio.round();
+
+    (2.0..=16.0).contains(&harmonic) && (1200.0 * (ratio / harmonic).log2()).abs() < HARMONIC_TOLERANCE_CENTS
+}
+
 /// Reduce a vector of notes by removing all notes that are part of the harmonic series of another note.
-fn reduce_notes_by_harmonic_series(notes: &[(Note, f32)], cutoff: f32) -> Vec<Note> {
+fn reduce_notes_by_harmonic_series(notes: &[(Note, f32)], cutoff: f32, options: &AnalyzeOptions) -> Vec<Note> {
     let mut working_set = notes.to_vec();
     working_set.sort_unstable_by(|a, b| a.0.frequency().partial_cmp(&b.0.frequency()).unwrap());
 
@@ -255,15 +529,18 @@ fn reduce_notes_by_harmonic_series(notes: &[(Note, f32)], cutoff: f32) -> Vec<No
         while j < working_set.len() {
             let other_note = working_set[j].0;
 
-            for harmonic in note.primary_harmonic_series() {
-                if harmonic.frequency() == other_note.frequency() {
-                    working_set[k].1 += working_set[j].1;
-                    working_set.remove(j);
-                    j -= 1;
-                }
-            }
+            let is_in_series = note.primary_harmonic_series().iter().any(|harmonic| harmonic.frequency() == other_note.frequency())
+                || (options.collapse_octave_harmonics && is_integer_harmonic(note.frequency(), other_note.frequency()));
+
+            // When collapsing octave harmonics, only attribute the higher note to the harmonics if they could explain its energy.
+            let is_explained = !options.collapse_octave_harmonics || working_set[j].1 <= working_set[k].1 * options.max_harmonic_energy_ratio;
 
-            j += 1;
+            if is_in_series && is_explained {
+                working_set[k].1 += working_set[j].1;
+                working_set.remove(j);
+            } else {
+                j += 1;
+            }
         }
 
         k += 1;
@@ -281,6 +558,37 @@ fn reduce_notes_by_harmonic_series(notes: &[(Note, f32)], cutoff: f32) -> Vec<No
     working_set.into_iter().map(|(note, _)| note).collect()
 }
 
+/// Finds the largest peak within a quarter tone of the `target` frequency, and returns its (frequency, magnitude).
+///
+/// The frequency is refined with a parabolic interpolation over the neighboring bins, which allows for a resolution
+/// that is much finer than the bin width.
+fn get_interpolated_peak(frequency_space: &[(f32, f32)], target: f32) -> Option<(f32, f32)> {
+    let bin_width = frequency_space.get(1)?.0 - frequency_space[0].0;
+
+    let low = ((target * 2f32.powf(-50.0 / 1200.0) / bin_width).floor() as usize).max(1);
+    let high = ((target * 2f32.powf(50.0 / 1200.0) / bin_width).ceil() as usize).min(frequency_space.len() - 2);
+
+    if low > high {
+        return None;
+    }
+
+    let (k, (_, magnitude)) = frequency_space[low..=high].iter().enumerate().max_by(|a, b| a.1 .1.partial_cmp(&b.1 .1).unwrap())?;
+    let k = k + low;
+
+    if *magnitude <= 0f32 {
+        return None;
+    }
+
+    let alpha = frequency_space[k - 1].1;
+    let beta = frequency_space[k].1;
+    let gamma = frequency_space[k + 1].1;
+
+    let denominator = alpha - 2f32 * beta + gamma;
+    let offset = if denominator == 0f32 { 0f32 } else { 0.5 * (alpha - gamma) / denominator };
+
+    Some(((k as f32 + offset) * bin_width, *magnitude))
+}
+
 /// For every note, get its "frequency window", which is halfway between the frequency of the note and the frequency of the
 /// the one before, and the next one.
 ///
@@ -356,7 +664,7 @@ where
 pub(crate) mod tests {
     use std::{fs::File, io::Read};
 
-    use crate::core::note::ALL_PITCH_NOTES;
+    use crate::core::note::{BFlat, CFive, CSharpFive, CThree, DFlatFive, EFive, ALL_PITCH_NOTES, C, E, G};
 
     use super::*;
 
@@ -383,11 +691,62 @@ pub(crate) mod tests {
     }
 
     #[test]
-    #[should_panic]
+    #[should_panic(expected = "NaNs")]
     fn test_get_notes_from_audio_data_nan() {
         get_notes_from_audio_data(&[0.0, 0.0, f32::NAN], 10).unwrap();
     }
 
+    #[test]
+    fn test_analysis_config() {
+        let signal = |sample_rate: u32| {
+            (0..sample_rate)
+                .map(|n| {
+                    let t = n as f32 / sample_rate as f32;
+
+                    [A, CSharpFive, EFive].iter().map(|note| (2.0 * std::f32::consts::PI * note.frequency() * t).sin()).sum::<f32>()
+                })
+                .collect::<Vec<_>>()
+        };
+
+        // The default config reproduces the original analysis.
+        let data = signal(44_100);
+        let config = AnalysisConfig::new(44_100, 1);
+
+        assert_eq!(
+            get_notes_from_audio_data_with_config(&data, &config, &AnalyzeOptions::default()).unwrap(),
+            get_notes_from_audio_data(&data, 1).unwrap()
+        );
+
+        // The same signal at 48 kHz yields the same notes, and the same (normalized) frequency space.
+        let mut at_44_1 = get_notes_from_audio_data_with_config(&data, &config, &AnalyzeOptions::default()).unwrap();
+        let mut at_48 = get_notes_from_audio_data_with_config(&signal(48_000), &AnalysisConfig::new(48_000, 1), &AnalyzeOptions::default()).unwrap();
+        at_44_1.sort();
+        at_48.sort();
+
+        assert_eq!(at_48, at_44_1);
+        assert_eq!(at_48, vec![A, DFlatFive, EFive]);
+
+        // Without a config, the sample rate is inferred from the length of the recording.
+        assert_eq!(AnalysisConfig::from_recording(96_000, 2), AnalysisConfig::new(48_000, 2));
+        assert_eq!(AnalysisConfig::from_recording(96_000, 0).sample_rate, 0);
+
+        let mut inferred_48 = get_notes_from_audio_data(&signal(48_000), 1).unwrap();
+        inferred_48.sort();
+
+        assert_eq!(inferred_48, at_48);
+
+        let space_44_1 = get_smoothed_frequency_space_with_config(&data, &config).unwrap();
+        let space_48 = get_smoothed_frequency_space_with_config(&signal(48_000), &AnalysisConfig::new(48_000, 1)).unwrap();
+
+        assert_eq!(space_48.len(), DEFAULT_FREQUENCY_SPACE_SIZE);
+        assert!((space_48[440].1 - space_44_1[440].1).abs() / space_44_1[440].1 < 0.01);
+
+        // Too little data (or a bad config) is an error, and extra data is ignored.
+        assert!(get_smoothed_frequency_space_with_config(&data[..1000], &config).is_err());
+        assert!(get_smoothed_frequency_space_with_config(&data, &AnalysisConfig { length_in_seconds: 0, ..config }).is_err());
+        assert_eq!(get_smoothed_frequency_space_with_config(&[data.clone(), data.clone()].concat(), &config).unwrap(), space_44_1);
+    }
+
     #[test]
     fn test_get_time_space() {
         let data = load_test_data();
@@ -396,6 +755,123 @@ pub(crate) mod tests {
         let _ = get_time_space(&frequency_space);
     }
 
+    #[test]
+    fn test_collapse_octave_harmonics() {
+        // A synthesized C3 with ten decaying harmonics (the 8th harmonic is three octaves up, which is not in the primary harmonic series).
+        let sample_rate = 44_100;
+        let data = (0..sample_rate * 5)
+            .map(|n| {
+                let t = n as f32 / sample_rate as f32;
+
+                (1..=10).map(|h| 0.9f32.powi(h) * (2.0 * std::f32::consts::PI * 130.81 * h as f32 * t).sin()).sum::<f32>()
+            })
+            .collect::<Vec<_>>();
+
+        let options = AnalyzeOptions {
+            collapse_octave_harmonics: true,
+            ..Default::default()
+        };
+
+        assert_eq!(get_notes_from_audio_data(&data, 5).unwrap().len(), 2);
+        assert_eq!(get_notes_from_audio_data_with_options(&data, 5, &options).unwrap(), vec![CThree]);
+    }
+
+    #[test]
+    fn test_analyze_intonation() {
+        // A synthesized A4 that is 20 cents sharp, and a C5 that is 15 cents flat.
+        let sample_rate = 44_100;
+        let a = 440.0 * 2f32.powf(20.0 / 1200.0);
+        let c = CFive.frequency() * 2f32.powf(-15.0 / 1200.0);
+        let data = (0..sample_rate)
+            .map(|n| {
+                let t = n as f32 / sample_rate as f32;
+
+                (2.0 * std::f32::consts::PI * a * t).sin() + (2.0 * std::f32::consts::PI * c * t).sin()
+            })
+            .collect::<Vec<_>>();
+
+        let mut intonation = analyze_intonation(&data, 1, 440.0).unwrap();
+        intonation.sort_by_key(|(note, _)| *note);
+
+        assert_eq!(intonation.iter().map(|(n, _)| *n).collect::<Vec<_>>(), vec![A, CFive]);
+        assert!((intonation[0].1 - 20.0).abs() < 2.0);
+        assert!((intonation[1].1 + 15.0).abs() < 2.0);
+
+        // With a different reference, the deviations shift accordingly (A4 = 442 Hz is ~7.85 cents above A4 = 440 Hz).
+        let mut intonation = analyze_intonation(&data, 1, 442.0).unwrap();
+        intonation.sort_by_key(|(note, _)| *note);
+
+        assert!((intonation[0].1 - 12.15).abs() < 2.0);
+    }
+
+    #[test]
+    fn test_chromagram() {
+        // A synthesized C major triad at 48 kHz, with a quieter G.
+        let sample_rate = 48_000;
+        let data = (0..sample_rate / 2)
+            .map(|n| {
+                let t = n as f32 / sample_rate as f32;
+
+                [(C.frequency(), 1.0), (E.frequency(), 1.0), (G.frequency(), 0.5)]
+                    .iter()
+                    .map(|(f, a)| a * (2.0 * std::f32::consts::PI * f * t).sin())
+                    .sum::<f32>()
+            })
+            .collect::<Vec<_>>();
+
+        let chroma = chromagram(&data, sample_rate);
+        let mut strongest = (0..12).collect::<Vec<_>>();
+        strongest.sort_by(|a, b| chroma[*b].total_cmp(&chroma[*a]));
+
+        // The C and the E are equally loud, so their relative order is unspecified.
+        let mut loudest = strongest[..2].to_vec();
+        loudest.sort();
+
+        assert_eq!(loudest, vec![0, 4]);
+        assert_eq!(strongest[2], 7);
+        assert!(chroma[0] > 0.9 && chroma[4] > 0.9);
+        assert!(chroma[7] > 0.1 && chroma[7] < 0.5);
+        assert_eq!(chromagram(&[], sample_rate), [0f32; 12]);
+        assert_eq!(chromagram(&[0f32; 1000], sample_rate), [0f32; 12]);
+    }
+
+    #[test]
+    fn test_merge_nearby_peaks() {
+        // Two peaks 30 cents apart (straddling the boundary between A4 and A♯4) merge into the stronger one.
+        let peaks = [(440.0 * 2f32.powf(40.0 / 1200.0), 3.0), (440.0 * 2f32.powf(70.0 / 1200.0), 1.0), (CFive.frequency(), 1.0)];
+
+        let merged = merge_nearby_peaks(&peaks, AnalyzeOptions::default().merge_tolerance_cents);
+
+        assert_eq!(merged.len(), 2);
+        assert_eq!(merged[0].1, 4.0);
+        assert!((cents(440.0, merged[0].0) - 47.5).abs() < 1.0);
+        assert_eq!(merge_nearby_peaks(&peaks, 0.0), peaks.to_vec());
+
+        let notes = |peaks: &[(f32, f32)], merge_tolerance_cents| {
+            let options = AnalyzeOptions {
+                merge_tolerance_cents,
+                ..Default::default()
+            };
+            let mut notes = get_likely_notes_from_peak_space(peaks, 0.1, &options).into_iter().map(|(n, _)| n).collect::<Vec<_>>();
+            notes.sort();
+
+            notes
+        };
+
+        assert_eq!(notes(&peaks, 0.0), vec![A, BFlat, CFive]);
+        assert_eq!(notes(&peaks, 50.0), vec![A, CFive]);
+    }
+
+    #[test]
+    fn test_get_notes_from_audio_data_batch() {
+        let data = load_test_data();
+
+        let sequential = vec![get_notes_from_audio_data(&data, 5).unwrap(); 4];
+        let batch = get_notes_from_audio_data_batch(&vec![data; 4], 5).unwrap();
+
+        assert_eq!(batch, sequential);
+    }
+
     #[test]
     fn test_get_frequency_bins() {
         let bins = get_frequency_bins(&ALL_PITCH_NOTES.iter().skip(24).take(62).cloned().collect::<Vec<_>>());
@@ -3,6 +3,12 @@
 #[cfg(feature = "analyze_base")]
 pub mod base;
 
+#[cfg(feature = "analyze_base")]
+pub mod stream;
+
+#[cfg(feature = "analyze_base")]
+pub use base::chromagram;
+
 #[cfg(feature = "analyze_mic")]
 pub mod mic;
 
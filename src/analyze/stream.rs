@@ -0,0 +1,258 @@
+//! Streaming audio analysis.
+//!
+//! Allows for audio to be analyzed as it arrives (e.g., from a microphone), rather than all at once.
+
+use std::collections::VecDeque;
+
+use crate::core::{base::Res, note::Note};
+
+use super::base::{get_notes_from_audio_data_with_config, AnalysisConfig, AnalyzeOptions};
+
+// Struct.
+
+/// A streaming audio analyzer.
+///
+/// Successive chunks of (mono) samples are pushed into the analyzer, which keeps a rolling window of the most
+/// recent `length_in_seconds` of audio.  Once the window is full, the [`Note`]s in it are detected every
+/// `hop_size` samples, so a smaller hop trades more analysis work for lower latency.
+#[derive(Clone, Debug)]
+pub struct AudioAnalyzer {
+    /// The sample rate of the pushed samples.
+    sample_rate: u32,
+    /// The length of the analysis window.
+    length_in_seconds: u8,
+    /// The number of samples between successive detections.
+    hop_size: usize,
+    /// The number of samples pushed since the last detection.
+    pending: usize,
+    /// The most recent samples (at most one window).
+    window: VecDeque<f32>,
+}
+
+// Impls.
+
+impl AudioAnalyzer {
+    /// Creates a new [`AudioAnalyzer`] for samples at the given sample rate, analyzing windows of `length_in_seconds`.
+    ///
+    /// By default, the hop is a full window (i.e., the windows do not overlap).
+    pub fn new(sample_rate: u32, length_in_seconds: u8) -> Res<Self> {
+        if length_in_seconds < 1 {
+            return Err(anyhow::Error::msg("Listening length in seconds must be greater than 1."));
+        }
+
+        if sample_rate == 0 {
+            return Err(anyhow::Error::msg("The sample rate must be greater than zero."));
+        }
+
+        Ok(Self {
+            sample_rate,
+            length_in_seconds,
+            hop_size: sample_rate as usize * length_in_seconds as usize,
+            pending: 0,
+            window: VecDeque::with_capacity(sample_rate as usize * length_in_seconds as usize),
+        })
+    }
+
+    /// Sets the number of samples between successive detections (which must be between 1 and the window size).
+    pub fn with_hop_size(mut self, hop_size: usize) -> Res<Self> {
+        if hop_size < 1 || hop_size > self.window_size() {
+            return Err(anyhow::Error::msg("Hop size must be between 1 and the window size."));
+        }
+
+        self.hop_size = hop_size;
+
+        Ok(self)
+    }
+
+    /// Returns the number of samples between successive detections.
+    pub fn hop_size(&self) -> usize {
+        self.hop_size
+    }
+
+    /// Returns the number of samples in a full analysis window.
+    pub fn window_size(&self) -> usize {
+        self.sample_rate as usize * self.length_in_seconds as usize
+    }
+
+    /// Returns whether or not a full window of samples has been pushed.
+    pub fn is_ready(&self) -> bool {
+        self.window.len() == self.window_size()
+    }
+
+    /// Pushes a chunk of samples into the analyzer, discarding the samples that have fallen out of the window.
+    ///
+    /// Returns the [`Note`]s detected at each hop completed by the chunk (in order), which is empty until
+    /// a full window of samples has been pushed.
+    pub fn push(&mut self, samples: &[f32]) -> Res<Vec<Vec<Note>>> {
+        let window_size = self.window_size();
+        let mut detections = Vec::new();
+        let mut remaining = samples;
+
+        while !remaining.is_empty() {
+            // Take samples up to the next hop, or, if a hop has elapsed before the window filled up, up to a full window.
+            let wanted = if self.pending < self.hop_size {
+                self.hop_size - self.pending
+            } else {
+                window_size - self.window.len()
+            };
+            let (chunk, rest) = remaining.split_at(wanted.min(remaining.len()));
+
+            let overflow = (self.window.len() + chunk.len()).saturating_sub(window_size);
+            self.window.drain(..overflow);
+            self.window.extend(chunk);
+
+            self.pending += chunk.len();
+            remaining = rest;
+
+            if self.pending >= self.hop_size && self.is_ready() {
+                detections.push(self.current_notes()?);
+                self.pending = 0;
+            }
+        }
+
+        Ok(detections)
+    }
+
+    /// Flushes the analyzer, and returns the [`Note`]s detected in the last (zero-padded) partial window, if any
+    /// samples have been pushed since the last detection.
+    ///
+    /// The analyzer is cleared afterward, so it can be reused for a new stream.
+    pub fn finish(&mut self) -> Res<Option<Vec<Note>>> {
+        let result = if self.pending == 0 {
+            None
+        } else {
+            let mut data = self.window_data();
+            data.resize(self.window_size(), 0.0);
+
+            Some(self.analyze(&data)?)
+        };
+
+        self.clear();
+
+        Ok(result)
+    }
+
+    /// Removes all of the pushed samples.
+    pub fn clear(&mut self) {
+        self.window.clear();
+        self.pending = 0;
+    }
+
+    /// Returns the [`Note`]s detected in the current window.
+    ///
+    /// Returns an empty list until a full window of samples has been pushed.
+    pub fn current_notes(&self) -> Res<Vec<Note>> {
+        if !self.is_ready() {
+            return Ok(Vec::new());
+        }
+
+        self.analyze(&self.window_data())
+    }
+
+    /// Returns the samples in the current window (in order).
+    fn window_data(&self) -> Vec<f32> {
+        let (front, back) = self.window.as_slices();
+
+        [front, back].concat()
+    }
+
+    /// Detects the [`Note`]s in a full window of samples.
+    fn analyze(&self, data: &[f32]) -> Res<Vec<Note>> {
+        let config = AnalysisConfig::new(self.sample_rate, self.length_in_seconds);
+
+        get_notes_from_audio_data_with_config(data, &config, &AnalyzeOptions::default())
+    }
+}
+
+// Tests.
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{
+        note::{CSharpFive, DFlatFive, EFive, A},
+        pitch::HasFrequency,
+    };
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_audio_analyzer() {
+        let sample_rate = 44_100;
+        let frequencies = [A, CSharpFive, EFive].map(|n| n.frequency());
+
+        let mut analyzer = AudioAnalyzer::new(sample_rate, 1).unwrap();
+        assert_eq!(analyzer.window_size(), 44_100);
+
+        // Push silence, and then a full window of the chord in small chunks.
+        assert_eq!(analyzer.push(&vec![0f32; 10_000]).unwrap(), Vec::<Vec<Note>>::new());
+        assert!(!analyzer.is_ready());
+        assert_eq!(analyzer.current_notes().unwrap(), vec![]);
+
+        let samples = (0..sample_rate)
+            .map(|n| {
+                let t = n as f32 / sample_rate as f32;
+
+                frequencies.iter().map(|f| (2.0 * std::f32::consts::PI * f * t).sin()).sum::<f32>()
+            })
+            .collect::<Vec<_>>();
+
+        let detections = samples.chunks(128).flat_map(|chunk| analyzer.push(chunk).unwrap()).collect::<Vec<_>>();
+
+        // The first full window is the silence plus most of the chord.
+        assert_eq!(detections.len(), 1);
+        assert!(analyzer.is_ready());
+
+        let mut notes = analyzer.current_notes().unwrap();
+        notes.sort();
+
+        assert_eq!(notes, vec![A, DFlatFive, EFive]);
+
+        analyzer.clear();
+        assert!(!analyzer.is_ready());
+    }
+
+    #[test]
+    fn test_audio_analyzer_hop_and_finish() {
+        let sample_rate = 44_100;
+        let frequencies = [A, CSharpFive, EFive].map(|n| n.frequency());
+
+        let samples = (0..sample_rate * 2)
+            .map(|n| {
+                let t = n as f32 / sample_rate as f32;
+
+                frequencies.iter().map(|f| (2.0 * std::f32::consts::PI * f * t).sin()).sum::<f32>()
+            })
+            .collect::<Vec<_>>();
+
+        assert!(AudioAnalyzer::new(sample_rate, 0).is_err());
+        assert!(AudioAnalyzer::new(0, 1).is_err());
+        assert!(AudioAnalyzer::new(sample_rate, 1).unwrap().with_hop_size(0).is_err());
+        assert!(AudioAnalyzer::new(sample_rate, 1).unwrap().with_hop_size(44_101).is_err());
+
+        // With a quarter second hop, two seconds of audio yields a detection at the first full window, and then every hop.
+        let mut analyzer = AudioAnalyzer::new(sample_rate, 1).unwrap().with_hop_size(11_025).unwrap();
+        assert_eq!(analyzer.hop_size(), 11_025);
+
+        let detections = samples.chunks(1000).flat_map(|chunk| analyzer.push(chunk).unwrap()).collect::<Vec<_>>();
+        assert_eq!(detections.len(), 5);
+
+        for mut notes in detections {
+            notes.sort();
+            assert_eq!(notes, vec![A, DFlatFive, EFive]);
+        }
+
+        // Nothing is pending right after a hop.
+        assert_eq!(analyzer.finish().unwrap(), None);
+
+        // A partial window is zero-padded and flushed.
+        let mut analyzer = AudioAnalyzer::new(sample_rate, 1).unwrap();
+        assert_eq!(analyzer.push(&samples[..30_000]).unwrap(), Vec::<Vec<Note>>::new());
+
+        let mut notes = analyzer.finish().unwrap().unwrap();
+        notes.sort();
+
+        assert_eq!(notes, vec![A, DFlatFive, EFive]);
+        assert!(!analyzer.is_ready());
+        assert_eq!(analyzer.finish().unwrap(), None);
+    }
+}
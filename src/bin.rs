@@ -231,6 +231,34 @@ enum MlCommand {
         /// Suppresses the training plots.
         #[arg(long, action=ArgAction::SetTrue, default_value_t = false)]
         no_plots: bool,
+
+        /// Writes the per-epoch metrics history to this path (as CSV if it ends in `.csv`, otherwise as JSON).
+        #[arg(long)]
+        metrics_out: Option<String>,
+
+        /// Stops training after this many epochs without validation accuracy improvement (and keeps the best model).
+        #[arg(long)]
+        early_stopping_patience: Option<usize>,
+
+        /// The minimum increase in validation accuracy that counts as an improvement for early stopping.
+        #[arg(long, default_value_t = 0.0)]
+        early_stopping_min_delta: f64,
+
+        /// The learning rate schedule, applied per epoch: `constant`, `step:<step_size>:<gamma>`, `cosine:<min_lr>`, or `warmup:<warmup_epochs>`.
+        #[arg(long)]
+        lr_schedule: Option<String>,
+
+        /// The loss function that is minimized: `mse`, `bce`, `focal:<gamma>`, or `f1:<beta>`.
+        #[arg(long)]
+        loss: Option<String>,
+
+        /// A directory of real (recorded) samples to mix into the synthetic training data.
+        #[arg(long)]
+        real_source: Option<String>,
+
+        /// The fraction of the training data that is real when mixing in real samples.
+        #[arg(long, default_value_t = 0.3)]
+        real_ratio: f32,
     },
 
     /// Records audio from the microphone, and using the trained model, guesses the chord.
@@ -318,18 +346,18 @@ fn main() -> Void {
 fn start(args: Args) -> Void {
     match args.command {
         Some(Command::Describe { symbol, octave }) => {
-            let chord = Chord::parse(&symbol)?.with_octave(Octave::Zero + octave);
+            let chord = Chord::parse_with_suggestions(&symbol)?.with_octave(Octave::Zero + octave);
 
             describe(&chord);
         }
         Some(Command::Play { symbol, delay, length, fade_in }) => {
-            let chord = Chord::parse(&symbol)?;
+            let chord = Chord::parse_with_suggestions(&symbol)?;
 
             play(&chord, delay, length, fade_in)?;
         }
         Some(Command::Guess { notes }) => {
             // Parse the notes.
-            let notes = notes.into_iter().map(|n| Note::parse(&n)).collect::<Result<Vec<_>, _>>()?;
+            let notes = notes.iter().map(|n| Note::parse_list(n)).collect::<Result<Vec<_>, _>>()?.concat();
 
             // Get the chord from the notes.
             let candidates = Chord::try_from_notes(&notes)?;
@@ -413,9 +441,16 @@ fn start(args: Args) -> Void {
                 adam_epsilon,
                 sigmoid_strength,
                 no_plots,
+                metrics_out,
+                early_stopping_patience,
+                early_stopping_min_delta,
+                lr_schedule,
+                loss,
+                real_source,
+                real_ratio,
             }) => {
                 use burn_autodiff::ADBackendDecorator;
-                use klib::ml::base::TrainConfig;
+                use klib::ml::base::{EarlyStopping, LrSchedule, RealSampleMix, TrainConfig, TrainLoss};
 
                 let config = TrainConfig {
                     source,
@@ -439,6 +474,11 @@ fn start(args: Args) -> Void {
                     adam_epsilon,
                     sigmoid_strength,
                     no_plots,
+                    metrics_out,
+                    early_stopping: early_stopping_patience.map(|patience| EarlyStopping::new(patience, early_stopping_min_delta)),
+                    lr_schedule: lr_schedule.map(|s| s.parse::<LrSchedule>()).transpose()?,
+                    loss: loss.map(|s| s.parse::<TrainLoss>()).transpose()?,
+                    real_mix: real_source.map(|source| RealSampleMix { source, ratio: real_ratio }),
                 };
 
                 match device.as_str() {
@@ -596,7 +636,7 @@ fn start(args: Args) -> Void {
 }
 
 fn describe(chord: &Chord) {
-    println!("{chord}");
+    println!("{}", chord.summary());
 }
 
 fn play(chord: &Chord, delay: f32, length: f32, fade_in: f32) -> Void {
@@ -0,0 +1,100 @@
+//! A module for rendering chords to [ABC notation](https://abcnotation.com/).
+
+use crate::core::{
+    base::{HasName, HasStaticName},
+    chord::{Chord, HasChord},
+    note::{HasMidiNumber, Note},
+    octave::HasOctave,
+};
+
+// Impls.
+
+impl Chord {
+    /// Returns the chord in ABC notation, as a (quoted) chord symbol followed by the notes of the chord (e.g., `"C"[CEG]`).
+    pub fn to_abc(&self) -> String {
+        format!("\"{}\"{}", self.abc_symbol(), self.abc_notes())
+    }
+
+    /// Returns the chord symbol of the chord, as used in ABC notation (i.e., [`HasName::name`] with ASCII accidentals).
+    pub fn abc_symbol(&self) -> String {
+        self.name()
+            .replace("♯𝄪", "###")
+            .replace("♭𝄫", "bbb")
+            .replace('𝄪', "##")
+            .replace('𝄫', "bb")
+            .replace('♯', "#")
+            .replace('♭', "b")
+    }
+
+    /// Returns the notes of the chord in ABC notation, as a bracketed chord (e.g., `[CEG]`).
+    pub fn abc_notes(&self) -> String {
+        format!("[{}]", self.chord().iter().map(note_to_abc).collect::<String>())
+    }
+}
+
+/// Returns an ABC tune of the chords, where each chord fills a (whole-note) bar (e.g., for pasting a lead sheet into an ABC document).
+pub fn progression_to_abc(chords: &[Chord]) -> String {
+    let bars = chords.iter().map(Chord::to_abc).collect::<Vec<_>>();
+
+    format!("X:1\nM:4/4\nL:1/1\nK:C\n|{}|\n", bars.join("|"))
+}
+
+// Helpers.
+
+/// Returns the note in ABC notation (e.g., `^C` for `C♯4`, `c'` for `C6`, or `_B,` for `B♭3`).
+///
+/// ABC has no triple accidentals, so those notes are respelled (using naturals and sharps).
+fn note_to_abc(note: &Note) -> String {
+    let note = if note.static_name().chars().count() > 2 {
+        Note::from_midi_number(note.to_midi_number()).unwrap_or(*note)
+    } else {
+        *note
+    };
+
+    let mut chars = note.static_name().chars();
+    let letter = chars.next().unwrap_or('C');
+
+    let accidental = chars
+        .map(|c| match c {
+            '♯' => "^",
+            '𝄪' => "^^",
+            '♭' => "_",
+            '𝄫' => "__",
+            _ => "",
+        })
+        .collect::<String>();
+
+    let octave = note.octave() as i8;
+
+    let (letter, marks) = if octave >= 5 {
+        (letter.to_ascii_lowercase(), "'".repeat(octave as usize - 5))
+    } else {
+        (letter, ",".repeat(4 - octave as usize))
+    };
+
+    format!("{accidental}{letter}{marks}")
+}
+
+// Tests.
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{base::Parsable, note::*};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_abc() {
+        assert_eq!(Chord::parse("C").unwrap().to_abc(), "\"C\"[CEG]");
+        assert_eq!(Chord::parse("Bbmaj7").unwrap().to_abc(), "\"Bbmaj7\"[_Bdfa]");
+        assert_eq!(Chord::parse("F#m/C#").unwrap().to_abc(), "\"F#m/C#\"[^C^FA^c]");
+
+        assert_eq!(note_to_abc(&CSix), "c'");
+        assert_eq!(note_to_abc(&ETwo), "E,,");
+        assert_eq!(note_to_abc(&GDoubleFlatFour), "__G");
+        assert_eq!(note_to_abc(&CTripleSharpFour), "^D");
+
+        let tune = progression_to_abc(&[Chord::parse("C").unwrap(), Chord::parse("G7").unwrap()]);
+        assert_eq!(tune, "X:1\nM:4/4\nL:1/1\nK:C\n|\"C\"[CEG]|\"G7\"[GBdf]|\n");
+    }
+}
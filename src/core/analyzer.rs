@@ -0,0 +1,176 @@
+//! A module that contains the [`ChordAnalyzer`] struct, which guesses many chords reusing precomputed candidate tables.
+
+use std::collections::HashMap;
+
+use crate::core::{
+    chord::{Chord, Chordable, HasChord},
+    helpers::cents,
+    modifier::{known_modifier_sets, likely_extension_sets, one_off_modifier_sets, Extension, Modifier},
+    note::{CFour, Note, NoteRecreator},
+    octave::HasOctave,
+    pitch::HasFrequency,
+};
+
+// Struct.
+
+/// A batch chord analyzer, which guesses chords from notes exactly like [`Chord::try_from_notes`].
+///
+/// [`Chord::try_from_notes`] builds (and voices) every candidate chord on every call.  Instead, the analyzer voices
+/// every candidate once (on a reference root), and indexes them by their shape (the semitones of each chord tone above
+/// the root).  Analyzing notes is then a lookup of the notes' shape, so only the matching candidates are ever built.
+/// This is useful when analyzing many note clusters (e.g., a long recording).
+#[derive(Clone, Debug)]
+pub struct ChordAnalyzer {
+    /// Every candidate (in the order that [`Chord::try_from_notes`] tries them).
+    templates: Vec<Template>,
+    /// The indexes of the candidates with each shape, for each inversion.
+    tables: [HashMap<Vec<i32>, Vec<usize>>; 3],
+}
+
+/// A candidate chord, without a root.
+#[derive(Clone, Debug)]
+struct Template {
+    /// The modifier sets of the candidate.
+    modifiers: [&'static [Modifier]; 2],
+    /// The extensions of the candidate.
+    extensions: &'static [Extension],
+    /// The inversion of the candidate.
+    inversion: u8,
+    /// Whether or not the candidate is crunchy.
+    is_crunchy: bool,
+}
+
+// Impls.
+
+impl ChordAnalyzer {
+    /// Returns a new [`ChordAnalyzer`], precomputing the candidate tables.
+    pub fn new() -> Self {
+        let mut templates = Vec::new();
+        let mut tables: [HashMap<Vec<i32>, Vec<usize>>; 3] = Default::default();
+
+        for (inversion, table) in tables.iter_mut().enumerate() {
+            for mod_set in known_modifier_sets() {
+                for mod_set2 in one_off_modifier_sets() {
+                    for ext_set in likely_extension_sets() {
+                        for is_crunchy in [false, true] {
+                            let template = Template {
+                                modifiers: [mod_set, mod_set2],
+                                extensions: ext_set,
+                                inversion: inversion as u8,
+                                is_crunchy,
+                            };
+
+                            table.entry(shape(CFour, &template.build(CFour).chord())).or_default().push(templates.len());
+                            templates.push(template);
+                        }
+                    }
+                }
+            }
+        }
+
+        Self { templates, tables }
+    }
+
+    /// Attempts to guess the chord from the notes, returning the same candidates as [`Chord::try_from_notes`].
+    ///
+    /// At least three notes are required, so fewer notes return no candidates.
+    pub fn analyze(&self, notes: &[Note]) -> Vec<Chord> {
+        if notes.len() < 3 {
+            return Vec::new();
+        }
+
+        let mut notes = notes.to_vec();
+        notes.sort();
+
+        let mut result = Vec::new();
+
+        for (inversion, table) in self.tables.iter().enumerate() {
+            let proper_root = if inversion == 0 {
+                notes[0]
+            } else {
+                let note = notes[notes.len() - inversion];
+
+                note.with_octave(note.octave() - 1)
+            };
+
+            let proper_root_slash = if inversion == 0 { notes[1] } else { proper_root };
+
+            // A slash candidate is the slash note below a candidate voiced from the remaining notes.
+            let root_matches = table.get(&shape(proper_root, &notes)).map(Vec::as_slice).unwrap_or_default();
+            let slash_matches = table.get(&shape(proper_root_slash, &notes[1..])).map(Vec::as_slice).unwrap_or_default();
+
+            let mut matches = root_matches.iter().map(|i| (*i, false)).chain(slash_matches.iter().map(|i| (*i, true))).collect::<Vec<_>>();
+            matches.sort();
+
+            for (index, is_slash) in matches {
+                let template = &self.templates[index];
+
+                let candidate = if is_slash {
+                    template.build(proper_root_slash).with_slash(notes[0])
+                } else {
+                    template.build(proper_root)
+                };
+
+                // The shapes are in whole semitones, so confirm the exact match.
+                let candidate_notes = candidate.chord();
+                if notes.len() == candidate_notes.len() && notes.iter().zip(&candidate_notes).all(|(a, b)| a.frequency() == b.frequency()) {
+                    result.push(candidate);
+                }
+            }
+        }
+
+        Chord::finalize_candidates(result)
+    }
+}
+
+impl Default for ChordAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Template {
+    /// Builds the candidate chord on the given root.
+    fn build(&self, root: Note) -> Chord {
+        Chord::new(root)
+            .with_modifiers(self.modifiers[0])
+            .with_modifiers(self.modifiers[1])
+            .with_extensions(self.extensions)
+            .with_inversion(self.inversion)
+            .with_crunchy(self.is_crunchy)
+    }
+}
+
+// Helpers.
+
+/// Returns the shape of the notes (i.e., the semitones of each note above the root).
+fn shape(root: Note, notes: &[Note]) -> Vec<i32> {
+    notes.iter().map(|n| (cents(root.frequency(), n.frequency()) / 100.0).round() as i32).collect()
+}
+
+// Tests.
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::base::{HasName, Parsable};
+    use pretty_assertions::assert_eq;
+    use std::collections::BTreeSet;
+
+    #[test]
+    fn test_analyze() {
+        let analyzer = ChordAnalyzer::new();
+
+        // Ties in the "simplicity" ordering are not stable, so compare the (sorted) names.
+        let names = |chords: Vec<Chord>| chords.iter().map(|c| c.name()).collect::<BTreeSet<_>>();
+
+        for (name, inversion) in [("C", 0), ("C", 1), ("Am7", 0), ("F♯m7(♭5)", 2), ("B♭7(♭9)", 0), ("Dm7/G", 0)] {
+            let notes = Chord::parse(name).unwrap().with_inversion(inversion).chord();
+
+            assert_eq!(names(analyzer.analyze(&notes)), names(Chord::try_from_notes(&notes).unwrap()));
+        }
+
+        assert_eq!(analyzer.analyze(&Chord::parse("C").unwrap().chord())[0].name(), "C");
+        assert_eq!(analyzer.analyze(&[CFour]), vec![]);
+    }
+}
@@ -2,11 +2,20 @@
 
 // Helpers.
 
-use std::time::Duration;
+use core::time::Duration;
+
+#[cfg(not(feature = "std"))]
+use alloc::{string::String, vec, vec::Vec};
 
 #[cfg(feature = "audio")]
 use rodio::{OutputStream, OutputStreamHandle, Sink};
 
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 /// Global result type.
 pub type Res<T> = anyhow::Result<T>;
 
@@ -50,6 +59,289 @@ pub trait Parsable {
         Self: Sized;
 }
 
+/// Options that control how notes are displayed (e.g., in chord-tone listings).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DisplayOptions {
+    /// Whether or not to show the octave of each note (e.g., `C4` vs. `C`).
+    pub show_octave: bool,
+}
+
+impl DisplayOptions {
+    /// Returns options that hide the octave of each note (e.g., `C E G`).
+    pub fn without_octave() -> Self {
+        Self { show_octave: false }
+    }
+}
+
+impl Default for DisplayOptions {
+    fn default() -> Self {
+        Self { show_octave: true }
+    }
+}
+
+/// A trait for types that can receive rendered audio samples (mono, in the range `[-1.0, 1.0]`).
+///
+/// This decouples synthesis from device I/O: samples can be sent to the system's audio output (see `DeviceSink`),
+/// to memory (see [`MemorySink`]), or to any other destination (a ring buffer, a file, a callback, etc.).
+pub trait OutputSink {
+    /// Returns the sample rate (in Hz) that the sink expects.
+    fn sample_rate(&self) -> u32;
+
+    /// Writes the samples to the sink.
+    fn write(&mut self, samples: &[f32]) -> Void;
+}
+
+/// A trait for types that can be rendered into an [`OutputSink`].
+pub trait Renderable {
+    /// Renders the [`Renderable`] into the provided [`OutputSink`] (using sine tones).
+    ///
+    /// The arguments have the same meaning as they do for `Playable::play`.
+    fn render(&self, sink: &mut dyn OutputSink, delay: Duration, length: Duration, fade_in: Duration) -> Void {
+        self.render_with_options(sink, delay, length, RenderOptions::with_fade_in(fade_in))
+    }
+
+    /// Renders the [`Renderable`] into the provided [`OutputSink`], using the given [`RenderOptions`].
+    ///
+    /// The sink's sample rate is used (rather than the `sample_rate` of the options).  Every note is released at `length`,
+    /// so the rendered audio rings on for the envelope's `release` past `length`.
+    fn render_with_options(&self, sink: &mut dyn OutputSink, delay: Duration, length: Duration, options: RenderOptions) -> Void;
+}
+
+/// An enum representing the waveform (i.e., the timbre) used to synthesize tones.
+///
+/// The waveforms are band-limited: each one is the sum of its partials (harmonics) that fall below the Nyquist frequency.
+#[derive(PartialEq, Eq, Copy, Clone, Hash, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "wasm", wasm_bindgen(js_name = KordWaveform))]
+pub enum Waveform {
+    /// A sine wave (only the fundamental).
+    #[default]
+    Sine,
+    /// A square wave (the odd harmonics, falling off as `1/k`).
+    Square,
+    /// A sawtooth wave (all of the harmonics, falling off as `1/k`).
+    Saw,
+    /// A triangle wave (the odd harmonics, falling off as `1/k²`).
+    Triangle,
+}
+
+impl Waveform {
+    /// Returns the `(harmonic, amplitude)` partials of the waveform for a tone at the given frequency.
+    ///
+    /// Only the partials below the Nyquist frequency of the sample rate are returned, and the amplitudes are scaled
+    /// such that the waveform peaks at (about) `1.0`.
+    pub fn partials(&self, frequency: f32, sample_rate: u32) -> Vec<(f32, f32)> {
+        use core::f32::consts::PI;
+
+        let nyquist = sample_rate as f32 / 2.0;
+        let harmonics = (1..).map(|k| k as f32).take_while(|k| k * frequency < nyquist);
+
+        match self {
+            Waveform::Sine => vec![(1.0, 1.0)],
+            Waveform::Square => harmonics.filter(|k| k % 2.0 == 1.0).map(|k| (k, 4.0 / (PI * k))).collect(),
+            Waveform::Saw => harmonics.map(|k| (k, 2.0 / (PI * k) * if k % 2.0 == 1.0 { 1.0 } else { -1.0 })).collect(),
+            Waveform::Triangle => harmonics
+                .filter(|k| k % 2.0 == 1.0)
+                .map(|k| (k, 8.0 / (PI * PI * k * k) * if k % 4.0 == 1.0 { 1.0 } else { -1.0 }))
+                .collect(),
+        }
+    }
+}
+
+/// An enum representing the direction in which a chord is arpeggiated (or strummed).
+#[derive(PartialEq, Eq, Copy, Clone, Hash, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "wasm", wasm_bindgen(js_name = KordArpeggioDirection))]
+pub enum ArpeggioDirection {
+    /// From the lowest chord tone to the highest.
+    #[default]
+    Up,
+    /// From the highest chord tone to the lowest.
+    Down,
+}
+
+/// An ADSR (attack, decay, sustain, release) amplitude envelope, which is applied to each note.
+///
+/// A note ramps linearly from silence to full amplitude over the `attack`, falls linearly to the `sustain` level
+/// over the `decay`, holds that level until the note is released, and then falls linearly to silence over the `release`.
+#[derive(PartialEq, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Envelope {
+    /// The time it takes to ramp up to full amplitude.
+    pub attack: Duration,
+    /// The time it takes to fall from full amplitude to the `sustain` level.
+    pub decay: Duration,
+    /// The level (in the range `[0.0, 1.0]`) held until the note is released.
+    pub sustain: f32,
+    /// The time it takes to fall to silence once the note is released.
+    pub release: Duration,
+}
+
+impl Envelope {
+    /// Creates a new [`Envelope`].
+    pub fn new(attack: Duration, decay: Duration, sustain: f32, release: Duration) -> Self {
+        Self {
+            attack,
+            decay,
+            sustain: sustain.clamp(0.0, 1.0),
+            release,
+        }
+    }
+
+    /// Returns the envelope equivalent to a `fade_in` (i.e., just an attack, held at full amplitude with no release).
+    pub fn from_fade_in(fade_in: Duration) -> Self {
+        Self::new(fade_in, Duration::ZERO, 1.0, Duration::ZERO)
+    }
+
+    /// Returns the amplitude of the envelope `n` samples into a note that is released after `held` samples.
+    pub fn level(&self, n: f32, held: f32, sample_rate: u32) -> f32 {
+        let attack = self.attack.as_secs_f32() * sample_rate as f32;
+        let decay = self.decay.as_secs_f32() * sample_rate as f32;
+        let release = self.release.as_secs_f32() * sample_rate as f32;
+
+        let held_level = |n: f32| {
+            if n < attack {
+                n / attack
+            } else if n < attack + decay {
+                1.0 - (1.0 - self.sustain) * (n - attack) / decay
+            } else {
+                self.sustain
+            }
+        };
+
+        if n < held {
+            held_level(n)
+        } else if n < held + release {
+            held_level(held) * (1.0 - (n - held) / release)
+        } else {
+            0.0
+        }
+    }
+}
+
+impl Default for Envelope {
+    fn default() -> Self {
+        Self::from_fade_in(Duration::ZERO)
+    }
+}
+
+impl From<Duration> for Envelope {
+    fn from(fade_in: Duration) -> Self {
+        Self::from_fade_in(fade_in)
+    }
+}
+
+/// Options that control how a [`Renderable`] is synthesized.
+#[derive(PartialEq, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RenderOptions {
+    /// The waveform used to synthesize each tone.
+    pub waveform: Waveform,
+    /// The envelope applied to each note (when set, this replaces the `fade_in`).
+    pub envelope: Option<Envelope>,
+    /// The time it takes each note to fade in (i.e., an envelope with just an attack).
+    pub fade_in: Duration,
+    /// The sample rate (in Hz) of the output created from the options (e.g., a WAV file, or the default audio output).
+    pub sample_rate: u32,
+}
+
+impl RenderOptions {
+    /// Returns the default options with the given `fade_in`.
+    pub fn with_fade_in(fade_in: Duration) -> Self {
+        Self { fade_in, ..Default::default() }
+    }
+
+    /// Returns the [`Envelope`] applied to each note.
+    pub fn to_envelope(&self) -> Envelope {
+        self.envelope.unwrap_or_else(|| Envelope::from_fade_in(self.fade_in))
+    }
+}
+
+impl Default for RenderOptions {
+    fn default() -> Self {
+        Self {
+            waveform: Waveform::Sine,
+            envelope: None,
+            fade_in: Duration::ZERO,
+            sample_rate: PLAYBACK_SAMPLE_RATE,
+        }
+    }
+}
+
+/// An [`OutputSink`] that captures the samples in memory.
+#[derive(Clone, Debug, Default)]
+pub struct MemorySink {
+    sample_rate: u32,
+    samples: Vec<f32>,
+}
+
+impl MemorySink {
+    /// Creates a new, empty [`MemorySink`] with the given sample rate.
+    pub fn new(sample_rate: u32) -> Self {
+        Self { sample_rate, samples: Vec::new() }
+    }
+
+    /// Returns the samples that have been written to the sink.
+    pub fn samples(&self) -> &[f32] {
+        &self.samples
+    }
+}
+
+impl OutputSink for MemorySink {
+    fn sample_rate(&self) -> u32 {
+        self.sample_rate
+    }
+
+    fn write(&mut self, samples: &[f32]) -> Void {
+        self.samples.extend_from_slice(samples);
+
+        Ok(())
+    }
+}
+
+/// The sample rate (in Hz) at which a [`Playable`] is rendered for the system's default audio output.
+pub const PLAYBACK_SAMPLE_RATE: u32 = 44_100;
+
+/// An [`OutputSink`] that plays the samples via the system's default audio output.
+#[cfg(feature = "audio")]
+pub struct DeviceSink {
+    stream: OutputStream,
+    stream_handle: OutputStreamHandle,
+    sink: Sink,
+    sample_rate: u32,
+}
+
+#[cfg(feature = "audio")]
+impl DeviceSink {
+    /// Creates a new [`DeviceSink`] on the default audio output device.
+    pub fn try_default(sample_rate: u32) -> Res<Self> {
+        let (stream, stream_handle) = OutputStream::try_default()?;
+        let sink = Sink::try_new(&stream_handle)?;
+
+        Ok(Self { stream, stream_handle, sink, sample_rate })
+    }
+
+    /// Converts the sink into a [`PlaybackHandle`], which keeps the audio playing until it is dropped.
+    pub fn into_playback_handle(self) -> PlaybackHandle {
+        PlaybackHandle::new(self.stream, self.stream_handle, vec![self.sink])
+    }
+}
+
+#[cfg(feature = "audio")]
+impl OutputSink for DeviceSink {
+    fn sample_rate(&self) -> u32 {
+        self.sample_rate
+    }
+
+    fn write(&mut self, samples: &[f32]) -> Void {
+        use rodio::buffer::SamplesBuffer;
+
+        self.sink.append(SamplesBuffer::new(1, self.sample_rate, samples.to_vec()));
+
+        Ok(())
+    }
+}
+
 /// A struct for holding the types for a [`Playable`].
 #[cfg(feature = "audio")]
 pub struct PlaybackHandle {
@@ -84,9 +376,43 @@ impl PlaybackHandle {
 /// );
 /// std::thread::sleep(Duration::from_secs(1));
 /// ```
+///
+/// Any [`Renderable`] can also be played into an arbitrary [`OutputSink`] via [`Playable::play_to`].
 #[cfg(feature = "audio")]
 pub trait Playable {
-    /// Plays the [`Playable`].
+    /// Plays the [`Playable`] via the system's default audio output (see [`Playable::play_to`]).
     #[must_use = "Dropping the PlayableResult will stop the playback."]
-    fn play(&self, delay: Duration, length: Duration, fade_in: Duration) -> Res<PlaybackHandle>;
+    fn play(&self, delay: Duration, length: Duration, fade_in: Duration) -> Res<PlaybackHandle>
+    where
+        Self: Renderable,
+    {
+        let mut sink = DeviceSink::try_default(PLAYBACK_SAMPLE_RATE)?;
+
+        self.play_to(&mut sink, delay, length, fade_in)?;
+
+        Ok(sink.into_playback_handle())
+    }
+
+    /// Plays the [`Playable`] into the provided [`OutputSink`], rather than the default audio device.
+    fn play_to(&self, sink: &mut dyn OutputSink, delay: Duration, length: Duration, fade_in: Duration) -> Void
+    where
+        Self: Renderable,
+    {
+        self.render(sink, delay, length, fade_in)
+    }
+
+    /// Plays the [`Playable`] via the system's default audio output, using the given [`RenderOptions`].
+    ///
+    /// Every note is released at `length`, so the playback rings on for the envelope's `release` past `length`.
+    #[must_use = "Dropping the PlayableResult will stop the playback."]
+    fn play_with_options(&self, delay: Duration, length: Duration, options: RenderOptions) -> Res<PlaybackHandle>
+    where
+        Self: Renderable,
+    {
+        let mut sink = DeviceSink::try_default(options.sample_rate)?;
+
+        self.render_with_options(&mut sink, delay, length, options)?;
+
+        Ok(sink.into_playback_handle())
+    }
 }
@@ -1,22 +1,25 @@
 //! A module that contains the [`Chord`] struct and related traits.
 
-use std::{cmp::Ordering, collections::HashSet, fmt::Display, time::Duration};
+use std::{cmp::Ordering, collections::HashSet, fmt::Display, str::FromStr, time::Duration};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use once_cell::sync::OnceCell;
 use pest::Parser;
 
 use crate::core::{
-    base::{HasDescription, HasName, HasPreciseName, HasStaticName, Parsable, Res},
-    interval::Interval,
-    known_chord::{HasRelativeChord, HasRelativeScale, KnownChord},
+    base::{ArpeggioDirection, DisplayOptions, Err, HasDescription, HasName, HasPreciseName, HasStaticName, MemorySink, OutputSink, Parsable, RenderOptions, Renderable, Res, Void},
+    helpers::{encode_wav, render_enveloped_tones},
+    interval::{Interval, ALL_INTERVALS},
+    known_chord::{ChordQuality, HasRelativeChord, HasRelativeScale, KnownChord},
     modifier::{known_modifier_sets, likely_extension_sets, one_off_modifier_sets, Degree, Extension, HasIsDominant, Modifier},
-    named_pitch::HasNamedPitch,
-    note::{CZero, Note, NoteRecreator},
+    named_pitch::{HasNamedPitch, NamedPitch},
+    note::{CZero, HasEnharmonics, HasMidiNumber, Note, NoteRecreator},
     octave::{HasOctave, Octave},
-    parser::{note_str_to_note, octave_str_to_octave, ChordParser, Rule},
-    pitch::HasFrequency,
+    parser::{complete_chord_symbols, normalize_symbol, note_str_to_note, octave_str_to_octave, roman_numeral_str_to_degree, suggest_chord_symbols, ChordParser, ParseErrorWithSuggestions, Rule},
+    pitch::{HasFrequency, HasPitch, Pitch},
+    scale::{HasScaleKind, ScaleKind},
 };
 
 // Traits.
@@ -51,6 +54,14 @@ pub trait HasInversion {
     fn inversion(&self) -> u8;
 }
 
+/// A trait that represents a type that has a named inversion.
+pub trait HasInversionName {
+    /// Returns the name of the inversion of the implementor (most likely a [`Chord`]).
+    ///
+    /// Triads and seventh chords also include the figured-bass form of the inversion (e.g., `first inversion (6)`).
+    fn inversion_name(&self) -> String;
+}
+
 /// A trait that represents a type that has "crunchiness".
 pub trait HasIsCrunchy {
     /// Returns the "crunchiness" of the implementor (most likely a [`Chord`]).
@@ -75,6 +86,15 @@ pub trait HasChord {
     fn chord(&self) -> Vec<Note>;
 }
 
+/// A trait that represents a type that has a set of intervals (from the root) that define it.
+pub trait HasIntervals {
+    /// Returns the intervals of each chord tone above the root of the implementor (most likely a [`Chord`]).
+    ///
+    /// These are independent of the root, inversion, and slash note, so they act as a "fingerprint"
+    /// for comparing chords regardless of their transposition.
+    fn intervals(&self) -> Vec<Interval>;
+}
+
 /// A trait that represents a type that has a chord.
 ///
 /// These methods all take ownership of the existing implementor (usually a [`Chord`]),
@@ -92,9 +112,14 @@ pub trait Chordable {
     /// Sets the inversion number of the implementor (most likely a [`Chord`]), and returns a new chord.
     fn with_inversion(self, inversion: u8) -> Chord;
     /// Sets the slash note of the implementor (most likely a [`Chord`]), and returns a new chord.
+    ///
+    /// The octave of the slash note is ignored, since it is always voiced below the rest of the chord (see [`Chordable::with_bass_octave`]).
     fn with_slash(self, slash: Note) -> Chord;
     /// Sets the octave of the implementor (most likely the root note of a chord), and returns a new chord.
     fn with_octave(self, octave: Octave) -> Chord;
+    /// Sets the octave of the bass note (the slash note, or the lowest chord tone) of the implementor (most likely a [`Chord`]),
+    /// independently of the octave of the root, and returns a new chord.
+    fn with_bass_octave(self, octave: Octave) -> Chord;
     /// Sets whether or not the implementor (most likely a [`Chord`]) is crunchy.
     fn with_crunchy(self, is_crunchy: bool) -> Chord;
 
@@ -166,6 +191,11 @@ pub trait Chordable {
     /// Returns a new chord with a half-diminished (m7♭5) modifier on the implementor (most likely a [`Chord`]).
     fn half_dim(self) -> Chord;
 
+    /// Returns a new chord with an altered (`7alt`) modifier on the implementor (most likely a [`Chord`]), adding a dominant 7 if needed.
+    fn altered(self) -> Chord;
+    /// Returns a new chord with an altered (`7alt`) modifier on the implementor (most likely a [`Chord`]), adding a dominant 7 if needed.
+    fn alt(self) -> Chord;
+
     // Extensions.
 
     /// Returns a new chord with a sus2 extension on the implementor (most likely a [`Chord`]).
@@ -237,12 +267,17 @@ pub trait HasDomninantDegree {
 // Struct.
 
 /// The primary chord struct.
+///
+/// Equality (i.e., [`PartialEq`]) is structural: two chords are equal only if they are spelled and built the same way
+/// (so `C♯` and `D♭` are _not_ equal).  Use [`Chord::is_enharmonically_equal`] to compare the sounding pitches instead.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(PartialEq, Eq, Clone, Debug)]
 pub struct Chord {
     /// The root note of the chord.
     root: Note,
     /// The slash note of the chord.
+    ///
+    /// The slash note is always voiced below the rest of the chord, so its octave is normalized (see [`normalize_slash`]).
     slash: Option<Note>,
     /// The modifiers of the chord.
     modifiers: HashSet<Modifier>,
@@ -254,12 +289,83 @@ pub struct Chord {
     ///
     /// Crunchy chords take extensions down an octave, which gives the chord some "crunch".
     is_crunchy: bool,
+    /// The octave of the bass note, if it has been placed explicitly.
+    ///
+    /// When this is `None`, the slash note is placed within an octave below the rest of the chord.
+    bass_octave: Option<Octave>,
+    /// Whether or not the awkward spellings of the chord's tones (other than the root) are simplified.
+    ///
+    /// This is only set when it changes the spelling of a tone, and it is part of the precise name (as a trailing `~`).
+    /// See [`Chord::simplify_enharmonics`].
+    is_simplified: bool,
+    /// The lazily computed notes of the chord.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    cache: ChordCache,
+}
+
+/// A builder for [`Chord`]s, which is an alternative to chaining the [`Chordable`] methods.
+///
+/// E.g., `ChordBuilder::new().root(D).modifier(Modifier::Minor).modifier(Modifier::Dominant(Degree::Seven)).build()` is `Dm7`.
+#[derive(Clone, Debug)]
+pub struct ChordBuilder {
+    /// The root note of the chord (which defaults to `C4`).
+    root: Note,
+    /// The slash note of the chord.
+    slash: Option<Note>,
+    /// The modifiers of the chord.
+    modifiers: Vec<Modifier>,
+    /// The extensions of the chord.
+    extensions: Vec<Extension>,
+    /// The inversion of the chord.
+    inversion: u8,
+    /// Whether or not the chord is "crunchy".
+    is_crunchy: bool,
+}
+
+/// The lazily computed notes of a [`Chord`] (i.e., [`HasChord::chord`] and [`HasScale::scale`]).
+///
+/// The cache is reset whenever the chord is changed, and clones start empty (so that a clone which is then changed
+/// through struct update syntax never sees stale notes).  It never affects equality.
+#[derive(Default)]
+struct ChordCache {
+    /// The notes of the chord.
+    chord: OnceCell<Vec<Note>>,
+    /// The notes of the scale of the chord.
+    scale: OnceCell<Vec<Note>>,
 }
 
 // Impls.
 
+impl Clone for ChordCache {
+    fn clone(&self) -> Self {
+        Self::default()
+    }
+}
+
+impl PartialEq for ChordCache {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for ChordCache {}
+
+impl std::fmt::Debug for ChordCache {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ChordCache").finish_non_exhaustive()
+    }
+}
+
 impl Ord for Chord {
     fn cmp(&self, other: &Self) -> Ordering {
+        self.cmp_simplicity(other, 2)
+    }
+}
+
+impl Chord {
+    /// Compares the chords by "simplicity" (i.e., least changes, then least inversion, slashes, extensions, and modifiers), where
+    /// an inversion counts as `inversion_weight` changes (and a slash always counts as two).
+    fn cmp_simplicity(&self, other: &Self, inversion_weight: u8) -> Ordering {
         let a_inversion = self.inversion;
         let b_inversion = other.inversion;
         let cmp_inversion = a_inversion.cmp(&b_inversion);
@@ -306,8 +412,8 @@ impl Ord for Chord {
         let a_inversion_exists = u8::from(a_inversion != 0);
         let b_inversion_exists = u8::from(b_inversion != 0);
 
-        let a_all_changes_len = a_extensions_len + a_modifiers_len + 2 * a_slashes + 2 * a_inversion_exists;
-        let b_all_changes_len = b_extensions_len + b_modifiers_len + 2 * b_slashes + 2 * b_inversion_exists;
+        let a_all_changes_len = a_extensions_len + a_modifiers_len + 2 * a_slashes + inversion_weight * a_inversion_exists;
+        let b_all_changes_len = b_extensions_len + b_modifiers_len + 2 * b_slashes + inversion_weight * b_inversion_exists;
 
         let cmp_all_changes = a_all_changes_len.cmp(&b_all_changes_len);
 
@@ -341,11 +447,206 @@ impl Chord {
             extensions: HashSet::new(),
             inversion: 0,
             is_crunchy: false,
+            bass_octave: None,
+            is_simplified: false,
+            cache: ChordCache::default(),
+        }
+    }
+
+    /// Removes the extensions and modifiers that are already expressed elsewhere in the chord
+    /// (e.g., an `add9` on a `13` chord, or a `m` on a `dim` chord).
+    fn remove_redundancies(&mut self) {
+        self.cache = ChordCache::default();
+
+        if let Some(degree) = self.dominant_degree() {
+            match degree {
+                Degree::Nine => {
+                    self.extensions.remove(&Extension::Add9);
+                }
+                Degree::Eleven => {
+                    self.extensions.remove(&Extension::Add9);
+                    self.extensions.remove(&Extension::Add11);
+                }
+                Degree::Thirteen => {
+                    self.extensions.remove(&Extension::Add9);
+                    self.extensions.remove(&Extension::Add11);
+                    self.extensions.remove(&Extension::Add13);
+                }
+                Degree::Seven => {}
+            }
+        }
+
+        if self.modifiers.contains(&Modifier::Diminished) {
+            self.modifiers.remove(&Modifier::Minor);
+            self.modifiers.remove(&Modifier::Flat5);
+            self.modifiers.remove(&Modifier::Augmented5);
+        }
+    }
+}
+
+impl Chord {
+    /// Returns the chord transposed up by the given interval, keeping its quality, inversion, and crunchiness.
+    ///
+    /// The root (and slash) are respelled with at most one accidental (e.g., `D♯` up a major third is `G`, rather than `F𝄪`),
+    /// keeping flat spellings flat.
+    pub fn transpose(&self, interval: Interval) -> Chord {
+        Chord {
+            root: simplify_spelling(self.root + interval),
+            slash: self.slash.map(|slash| normalize_slash(simplify_spelling(slash + interval))),
+            ..self.clone()
+        }
+    }
+
+    /// Returns the notes of this chord whose pitch class is also in the `other` chord (spelled as they appear in this chord).
+    ///
+    /// The comparison is by [`Pitch`](super::pitch::Pitch), so it ignores octaves and enharmonic spelling (e.g., the `C♯` in `A`
+    /// is shared with the `D♭` in `D♭`).
+    pub fn common_tones(&self, other: &Chord) -> Vec<Note> {
+        let other_pitches = other.chord().iter().map(HasPitch::pitch).collect::<HashSet<_>>();
+        let mut seen = HashSet::new();
+
+        self.chord().into_iter().filter(|n| other_pitches.contains(&n.pitch()) && seen.insert(n.pitch())).collect()
+    }
+
+    /// Returns the minimal total movement (in semitones) of the voices that morph this chord into the `other` chord.
+    ///
+    /// This is the classic "parsimonious" voice-leading cost.  Voices may move to any octave, so each voice moves between
+    /// pitch classes the shorter way around (at most 6 semitones).  Every tone of the larger chord is matched to a tone of
+    /// the smaller chord, such that every tone of the smaller chord is used (i.e., the smaller chord's tones may be doubled),
+    /// and the minimal cost matching is found by dynamic programming over the subsets of the smaller chord's tones.
+    pub fn voice_leading_distance(&self, other: &Chord) -> u32 {
+        let a = self.chord().iter().map(|n| n.pitch() as u32).collect::<Vec<_>>();
+        let b = other.chord().iter().map(|n| n.pitch() as u32).collect::<Vec<_>>();
+
+        let (larger, smaller) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+
+        if smaller.is_empty() {
+            return 0;
+        }
+
+        let distance = |x: u32, y: u32| {
+            let d = x.abs_diff(y);
+
+            d.min(12 - d)
+        };
+
+        // `costs[mask]` is the minimal cost of matching the voices so far, where `mask` is the set of the smaller chord's tones used.
+        let full = (1usize << smaller.len()) - 1;
+        let mut costs = vec![u32::MAX; full + 1];
+        costs[0] = 0;
+
+        for voice in larger {
+            let mut next = vec![u32::MAX; full + 1];
+
+            for (mask, cost) in costs.iter().enumerate().filter(|(_, c)| **c != u32::MAX) {
+                for (k, tone) in smaller.iter().enumerate() {
+                    let next_mask = mask | (1 << k);
+
+                    next[next_mask] = next[next_mask].min(cost + distance(voice, *tone));
+                }
+            }
+
+            costs = next;
+        }
+
+        costs[full]
+    }
+
+    /// Returns the chord with the given root (spelled as given), keeping its quality, inversion, and crunchiness.
+    ///
+    /// The slash (if any) is moved along with the root, and respelled as it would be by [`Chord::transpose`].
+    pub fn transpose_to(&self, new_root: Note) -> Chord {
+        let interval = ALL_INTERVALS
+            .iter()
+            .take_while(|i| **i != Interval::PerfectOctave)
+            .find(|i| (self.root + **i).named_pitch() == new_root.named_pitch());
+
+        let slash = self.slash.map(|slash| match interval {
+            Some(interval) => simplify_spelling(slash + *interval),
+            None => {
+                // The new root is not a (simple) interval away, so just move the slash by the same number of semitones.
+                let distance = new_root.pitch() as i16 - self.root.pitch() as i16;
+                let number = (slash.to_midi_number() as i16 + distance.rem_euclid(12)).clamp(12, 127) as u8;
+
+                Note::from_midi_number(number).map(simplify_spelling).unwrap_or(slash)
+            }
+        });
+
+        Chord {
+            root: new_root,
+            slash: slash.map(normalize_slash),
+            ..self.clone()
+        }
+    }
+
+    /// Returns the chord with its awkwardly spelled tones respelled, preserving their pitch (and the root's spelling).
+    ///
+    /// A tone is awkward when it has a double accidental, or is one of `E♯`, `B♯`, `F♭`, or `C♭`; it is respelled by
+    /// [`Chord::transpose`]'s rules (e.g., the `F𝄪` in `D♯+` becomes `G`).  The slash is cleaned up the same way, so the
+    /// name reflects the simplification (e.g., `G♯/B♯` becomes `G♯/C`), and the respelled tones are marked in the precise
+    /// name with a trailing `~` (e.g., `D♯+~`), so that it reparses to the same chord.
+    ///
+    /// Chords without awkward tones are returned unchanged (e.g., `Fm7.simplify_enharmonics() == Fm7`).
+    pub fn simplify_enharmonics(&self) -> Chord {
+        let chord = Chord {
+            slash: self.slash.map(|slash| normalize_slash(simplify_awkward_spelling(slash))),
+            is_simplified: false,
+            ..self.clone()
+        };
+
+        let simplified = Chord {
+            is_simplified: true,
+            ..chord.clone()
+        };
+
+        if simplified.chord() == chord.chord() {
+            chord
+        } else {
+            simplified
         }
     }
 }
 
+/// Respells the note (see [`simplify_spelling`]) if it has a double accidental, or is one of `E♯`, `B♯`, `F♭`, or `C♭`.
+fn simplify_awkward_spelling(note: Note) -> Note {
+    let named_pitch = note.named_pitch();
+    let is_awkward = matches!(named_pitch, NamedPitch::ESharp | NamedPitch::BSharp | NamedPitch::FFlat | NamedPitch::CFlat)
+        || named_pitch.static_name().contains(['𝄪', '𝄫']);
+
+    if is_awkward {
+        simplify_spelling(note)
+    } else {
+        note
+    }
+}
+
+/// Places the slash note in the default octave, so that the same slash chord always compares equal (and reparses from its name).
+///
+/// The octave of the slash note does not matter to the chord tones, since it is always voiced below the rest of the chord
+/// (see [`Chordable::with_bass_octave`] to place it explicitly).
+fn normalize_slash(slash: Note) -> Note {
+    slash.with_octave(Octave::default())
+}
+
+/// Respells the note with as few accidentals as possible, keeping flat spellings flat (and all others sharp).
+fn simplify_spelling(note: Note) -> Note {
+    let name = note.named_pitch().static_name();
+
+    if name.contains('♭') || name.contains('𝄫') {
+        note.respell_prefer_flats()
+    } else {
+        note.respell_prefer_sharps()
+    }
+}
+
 impl Chord {
+    /// Attempts to guess the chord from a list of notes (e.g., `"C E G"`, `"C, E, G"`, or `"C-E-G"`).
+    ///
+    /// The notes may be separated by any mix of whitespace, commas, hyphens, and slashes (see [`Note::parse_list`]).
+    pub fn try_from_notes_str(notes: &str) -> Res<Vec<Self>> {
+        Self::try_from_notes(&Note::parse_list(notes)?)
+    }
+
     /// Attempts to guess the chord from the notes.
     pub fn try_from_notes(notes: &[Note]) -> Res<Vec<Self>> {
         if notes.len() < 3 {
@@ -411,33 +712,16 @@ impl Chord {
             }
         }
 
+        Ok(Chord::finalize_candidates(result))
+    }
+
+    /// Simplifies, orders, and deduplicates the exact candidates found by [`Chord::try_from_notes`] (or a [`ChordAnalyzer`]).
+    ///
+    /// [`ChordAnalyzer`]: crate::core::analyzer::ChordAnalyzer
+    pub(crate) fn finalize_candidates(mut result: Vec<Chord>) -> Vec<Chord> {
         // Remove extensions and modifiers that are expressed elsewhere in the chord.
         for c in &mut result {
-            let dominant_degree = c.dominant_degree();
-
-            if let Some(degree) = dominant_degree {
-                match degree {
-                    Degree::Nine => {
-                        c.extensions.remove(&Extension::Add9);
-                    }
-                    Degree::Eleven => {
-                        c.extensions.remove(&Extension::Add9);
-                        c.extensions.remove(&Extension::Add11);
-                    }
-                    Degree::Thirteen => {
-                        c.extensions.remove(&Extension::Add9);
-                        c.extensions.remove(&Extension::Add11);
-                        c.extensions.remove(&Extension::Add13);
-                    }
-                    Degree::Seven => {}
-                }
-            }
-
-            if c.modifiers.contains(&Modifier::Diminished) {
-                c.modifiers.remove(&Modifier::Minor);
-                c.modifiers.remove(&Modifier::Flat5);
-                c.modifiers.remove(&Modifier::Augmented5);
-            }
+            c.remove_redundancies();
         }
 
         // Order the candidates by "simplicity" (i.e., least slashes, least extensions, least modifiers, and least inversion).
@@ -446,164 +730,821 @@ impl Chord {
         // Remove duplicates (and ignore crunchy; i.e., `C7` and `C7!` should be treated as "the same").
         result.dedup_by(|a, b| a.modifiers == b.modifiers && a.extensions == b.extensions && a.slash == b.slash && a.inversion == b.inversion);
 
-        Ok(result)
+        result
     }
-}
 
-impl HasName for Chord {
-    fn name(&self) -> String {
-        let known_name = self.known_chord().name();
-        let known_name = known_name.as_str();
-        let mut name = String::new();
+    /// Attempts to guess the chord from the notes, preferring to explain the lowest note as an inversion.
+    ///
+    /// The candidates are the same as those of [`Chord::try_from_notes`], but an inversion only counts as one change when
+    /// ordering them (rather than two), so, e.g., `E G C` yields `C` in first inversion before `Em(♯5)`.  Use
+    /// [`Chord::bass_name`] to show the detected inversion (e.g., `C/E`).
+    pub fn try_from_notes_detecting_inversion(notes: &[Note]) -> Res<Vec<Self>> {
+        let mut result = Self::try_from_notes(notes)?;
 
-        name.push_str(self.root.static_name());
+        result.sort_by(|a, b| a.cmp_simplicity(b, 1));
 
-        name.push_str(known_name);
+        Ok(result)
+    }
 
-        // Add special modifiers that are true modifiers when not part of their "special case".
+    /// Returns the name of the chord, followed by its bass note when it is inverted (e.g., `C/E` for the first inversion of `C`).
+    ///
+    /// Unlike [`HasName::name`], which only shows explicit slash notes, this shows which chord tone is in the bass.
+    pub fn bass_name(&self) -> String {
+        let name = self.name();
 
-        if self.modifiers.contains(&Modifier::Flat5) && !known_name.contains("(♭5)") {
-            name.push_str("(♭5)");
+        match self.chord().first() {
+            Some(bass) if self.slash.is_none() && self.inversion != 0 => format!("{}/{}", name, bass.static_name()),
+            _ => name,
         }
+    }
 
-        if self.modifiers.contains(&Modifier::Augmented5) && !known_name.contains('+') {
-            name.push_str("(♯5)");
-        }
+    /// Attempts to guess the chord from the notes, preserving the octave of the bass note (and the inversion) of the input voicing.
+    ///
+    /// Unlike [`Chord::try_from_notes`], the notes may be spread across multiple octaves (e.g., an "open" voicing).
+    /// The notes are first collapsed into the octave above the lowest note, and then each candidate is voiced
+    /// such that its lowest tone is the lowest input note.  Only the bass note keeps its octave: the other tones are
+    /// voiced closely above it (e.g., `[E3, C4, G5]` yields `C@3^1`, which is `[E3, G3, C4]`).
+    pub fn try_from_notes_preserving_bass_octave(notes: &[Note]) -> Res<Vec<Self>> {
+        let bass = *notes.iter().min().ok_or_else(|| anyhow::Error::msg("Must have at least one note to guess a chord."))?;
+
+        // Collapse all of the notes into the octave frame above the bass note.
+        let mut collapsed = notes
+            .iter()
+            .map(|n| {
+                let mut note = n.with_octave(bass.octave());
+
+                if note < bass {
+                    note = note.with_octave(note.octave() + 1);
+                }
 
-        if self.modifiers.contains(&Modifier::Flat9) && !known_name.contains("(♭9)") {
-            name.push_str("(♭9)");
-        }
+                note
+            })
+            .collect::<Vec<_>>();
 
-        if self.modifiers.contains(&Modifier::Sharp9) && !known_name.contains("(♯9)") {
-            name.push_str("(♯9)");
-        }
+        collapsed.sort();
+        collapsed.dedup();
 
-        if self.modifiers.contains(&Modifier::Sharp11) && !known_name.contains("(♯11)") {
-            name.push_str("(♯11)");
+        if collapsed.len() < 3 {
+            return Err(anyhow::Error::msg("Must have at least three distinct notes (ignoring octaves) to guess a chord."));
         }
 
-        // Add extensions.
-        if !self.extensions.is_empty() {
-            for e in &self.extensions {
-                name.push_str(&format!("({})", e.static_name()));
+        let mut result = Self::try_from_notes(&collapsed)?;
+
+        // Ensure the candidates are voiced from the original bass note.
+        result.retain(|c| c.chord().first().map(|n| n.frequency()) == Some(bass.frequency()));
+
+        Ok(result)
+    }
+
+    /// Attempts to guess the chord from the notes, pairing each candidate with a confidence score (between `0.0` and `1.0`).
+    ///
+    /// Unlike [`Chord::try_from_notes`], partial matches are included: in addition to the exact candidates, every known
+    /// chord quality is tried on each of the input pitch classes.  The score is the fraction of the combined pitch classes
+    /// that are shared by the notes and the chord (so extra and missing notes are penalized), and is reduced by 10% when
+    /// the chord is not voiced from the bass note (so an inversion or slash chord that matches the bass is not penalized).
+    /// Candidates sharing less than half of the pitch classes are omitted (before the bass penalty is applied).
+    ///
+    /// The candidates are ordered by descending score, with the [`Chord::try_from_notes`] "simplicity" ordering as the tiebreak.
+    pub fn try_from_notes_with_confidence(notes: &[Note]) -> Res<Vec<(Self, f32)>> {
+        let bass = *notes.iter().min().ok_or_else(|| anyhow::Error::msg("Must have at least one note to guess a chord."))?;
+        let pitches = notes.iter().map(|n| n.pitch()).collect::<HashSet<_>>();
+
+        let mut candidates = Self::try_from_notes(notes).unwrap_or_default();
+
+        for root in notes {
+            for mod_set in known_modifier_sets() {
+                candidates.push(Chord::new(root.with_octave(Octave::default())).with_modifiers(mod_set));
             }
         }
 
-        // Add slash note.
-        if let Some(slash) = self.slash {
-            name.push_str(&format!("/{}", slash.static_name()));
-        }
+        // Returns the overlap, and the score (i.e., the overlap with the bass penalty).
+        let score = |chord: &Chord| {
+            let chord_tones = chord.chord();
+            let chord_pitches = chord_tones.iter().map(|n| n.pitch()).collect::<HashSet<_>>();
+            let overlap = pitches.intersection(&chord_pitches).count() as f32 / pitches.union(&chord_pitches).count() as f32;
 
-        // Add special information about the chord.
+            if chord_tones.first().map(|n| n.pitch()) == Some(bass.pitch()) {
+                (overlap, overlap)
+            } else {
+                (overlap, overlap * 0.9)
+            }
+        };
 
-        name
-    }
-}
+        candidates.sort();
 
-impl HasPreciseName for Chord {
-    fn precise_name(&self) -> String {
-        let mut name = String::new();
+        let mut seen = HashSet::new();
+        let mut result = candidates
+            .into_iter()
+            .filter(|c| seen.insert(c.name()))
+            .filter_map(|c| {
+                let (overlap, score) = score(&c);
 
-        name.push_str(&self.name());
+                (overlap >= 0.5).then_some((c, score))
+            })
+            .collect::<Vec<_>>();
 
-        // Add octave modifier.
-        if self.root.octave() != Octave::Four {
-            name.push_str(&format!("@{}", self.root.octave().static_name()));
-        }
+        // The sort is stable, so equal scores keep the "simplicity" order.
+        result.sort_by(|(_, a), (_, b)| b.total_cmp(a));
 
-        // Add inversion modifier.
-        if self.inversion != 0 {
-            name.push_str(&format!("^{}", self.inversion));
+        Ok(result)
+    }
+
+    /// Attempts to guess the chord from a set of pitch classes (i.e., without octave information, as from a chromagram).
+    ///
+    /// The pitch classes are voiced in the default octave, ascending from each of them in turn, and the candidates of
+    /// all of the voicings are combined, and ordered by "simplicity" (so, e.g., `[C, E, G]` yields `C` first).
+    pub fn try_from_pitch_classes(pitches: &[Pitch]) -> Res<Vec<Self>> {
+        let mut pitches = pitches.to_vec();
+        pitches.sort();
+        pitches.dedup();
+
+        if pitches.len() < 3 {
+            return Err(anyhow::Error::msg("Must have at least three pitch classes to guess a chord."));
         }
 
-        // Add crunchy modifier.
-        if self.is_crunchy {
-            name.push('!');
+        let mut result = Vec::new();
+
+        for k in 0..pitches.len() {
+            let bass = Note::new(pitches[k].into(), Octave::default());
+
+            let notes = pitches
+                .iter()
+                .cycle()
+                .skip(k)
+                .take(pitches.len())
+                .map(|p| {
+                    let note = Note::new(p.into(), Octave::default());
+
+                    if note < bass {
+                        note.with_octave(note.octave() + 1)
+                    } else {
+                        note
+                    }
+                })
+                .collect::<Vec<_>>();
+
+            result.extend(Self::try_from_notes(&notes)?);
         }
 
-        name
-    }
-}
+        result.sort();
 
-impl HasRoot for Chord {
-    fn root(&self) -> Note {
-        self.root
-    }
-}
+        let mut seen = HashSet::new();
+        result.retain(|c| seen.insert(c.name()));
 
-impl HasSlash for Chord {
-    fn slash(&self) -> Note {
-        self.slash.unwrap_or(self.root)
+        Ok(result)
     }
 }
 
-impl HasModifiers for Chord {
-    fn modifiers(&self) -> &HashSet<Modifier> {
-        &self.modifiers
+impl Chord {
+    /// Returns the chord in every inversion, from root position (inversion `0`) through the highest inversion.
+    ///
+    /// There is one inversion per chord tone (e.g., four for a seventh chord), and everything else about the chord
+    /// (e.g., its slash, octave, and crunchiness) is kept.
+    pub fn all_inversions(&self) -> Vec<Chord> {
+        (0..self.intervals().len() as u8).map(|inversion| self.clone().with_inversion(inversion)).collect()
     }
-}
 
-impl HasExtensions for Chord {
-    fn extensions(&self) -> &HashSet<Extension> {
-        &self.extensions
+    /// Returns whether or not the chord (including its slash note) contains the pitch class of the given note.
+    ///
+    /// The spelling and octave of the note are ignored (e.g., `C7` contains `A♯2`, since it contains `B♭4`).
+    /// Use [`Chord::contains_exact`] to also match the spelling and octave.
+    pub fn contains_note(&self, note: &Note) -> bool {
+        self.contains_pitch(note.pitch())
     }
-}
 
-impl HasInversion for Chord {
-    fn inversion(&self) -> u8 {
-        self.inversion
+    /// Returns whether or not the chord (including its slash note) contains the given pitch class.
+    pub fn contains_pitch(&self, pitch: Pitch) -> bool {
+        self.chord().iter().any(|tone| tone.pitch() == pitch)
     }
-}
 
-impl HasIsCrunchy for Chord {
-    fn is_crunchy(&self) -> bool {
-        self.is_crunchy
+    /// Returns whether or not the chord (including its slash note) contains exactly the given note, as it is voiced in [`HasChord::chord`]
+    /// (i.e., matching both the spelling and the octave).
+    pub fn contains_exact(&self, note: &Note) -> bool {
+        self.chord().contains(note)
     }
-}
-
-impl Display for Chord {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let scale = self.scale().iter().map(HasStaticName::static_name).collect::<Vec<_>>().join(", ");
-        let chord = self.chord().iter().map(HasStaticName::static_name).collect::<Vec<_>>().join(", ");
 
-        write!(f, "{}\n   {}\n   {}\n   {}", self.precise_name(), self.description(), scale, chord)
-    }
-}
+    /// Returns the chord tones (in the order of [`HasChord::chord`]) with their octaves adjusted so that the frequencies are
+    /// strictly ascending.
+    ///
+    /// Each tone that is not above the previous tone is moved up by octaves until it is (e.g., for crunchy chords, whose
+    /// extensions are brought down into the first octave, or for enharmonically equal tones).
+    pub fn chord_ascending(&self) -> Vec<Note> {
+        let mut result: Vec<Note> = Vec::new();
+
+        for mut note in self.chord() {
+            if let Some(previous) = result.last() {
+                while note.frequency() <= previous.frequency() && note.octave() < Octave::Nine {
+                    note += Interval::PerfectOctave;
+                }
+            }
 
-impl Chordable for Chord {
-    fn with_modifier(mut self, modifier: Modifier) -> Chord {
-        // Augmented modifiers trump b5 and dim modifiers.
-        if modifier == Modifier::Augmented5 {
-            self.modifiers.remove(&Modifier::Flat5);
-            self.modifiers.remove(&Modifier::Diminished);
+            result.push(note);
         }
 
-        if (modifier == Modifier::Diminished || modifier == Modifier::Flat5) && self.modifiers.contains(&Modifier::Augmented5) {
-            return self;
-        }
+        result
+    }
 
-        self.modifiers.insert(modifier);
+    /// Returns whether this [`Chord`] sounds the same as the `other` [`Chord`], regardless of spelling (e.g., `C♯` and `D♭`).
+    ///
+    /// This compares the sets of sounding [`Pitch`]es, as well as the [`Pitch`] of the bass note (so inversions and slash
+    /// chords are only equal to chords with the same bass).  Octaves are ignored.
+    pub fn is_enharmonically_equal(&self, other: &Chord) -> bool {
+        let pitches = |chord: &Chord| chord.chord().iter().map(HasPitch::pitch).collect::<HashSet<_>>();
+        let bass = |chord: &Chord| chord.chord().first().map(HasPitch::pitch);
 
-        self
+        bass(self) == bass(other) && pitches(self) == pitches(other)
     }
 
-    fn with_modifiers(self, modifiers: &[Modifier]) -> Chord {
-        let mut chord = self;
+    /// Returns the chord tones as a space-separated string, using the provided [`DisplayOptions`].
+    ///
+    /// E.g., `C E G` (without octaves), or `C4 E4 G4` (with octaves).
+    pub fn chord_string(&self, options: DisplayOptions) -> String {
+        self.chord().iter().map(|n| n.name_with_options(options)).collect::<Vec<_>>().join(" ")
+    }
 
-        for m in modifiers {
-            chord = chord.with_modifier(*m);
-        }
+    /// Returns a multi-line summary of the [`Chord`]: its precise name, description, scale, and chord tones.
+    pub fn summary(&self) -> String {
+        let scale = self.scale().iter().map(HasStaticName::static_name).collect::<Vec<_>>().join(", ");
+        let chord = self.chord().iter().map(HasStaticName::static_name).collect::<Vec<_>>().join(", ");
 
-        chord
+        format!("{}\n   {}\n   {}\n   {}", self.precise_name(), self.description(), scale, chord)
     }
 
-    fn with_extension(mut self, extension: Extension) -> Chord {
-        self.extensions.insert(extension);
-
-        self
+    /// Returns the scale tones as a space-separated string, using the provided [`DisplayOptions`].
+    pub fn scale_string(&self, options: DisplayOptions) -> String {
+        self.scale().iter().map(|n| n.name_with_options(options)).collect::<Vec<_>>().join(" ")
     }
+}
 
-    fn with_extensions(self, extensions: &[Extension]) -> Chord {
+impl Chord {
+    /// Parses a chord symbol, and, on failure, returns the error along with a ranked list of suggested corrections.
+    ///
+    /// E.g., `Cmja7` fails to parse, but suggests `Cmaj7`.
+    pub fn parse_with_suggestions(input: &str) -> Result<Self, ParseErrorWithSuggestions> {
+        Chord::parse(input).map_err(|e| ParseErrorWithSuggestions {
+            message: e.to_string(),
+            suggestions: suggest_chord_symbols(input).into_iter().filter(|s| Chord::parse(s).is_ok()).collect(),
+        })
+    }
+
+    /// Returns the chord symbols that complete the given partial symbol (e.g., for auto-completing an input box).
+    ///
+    /// E.g., `Cm` completes to `Cm`, `Cm7`, `Cmaj7`, `Cm7b5`, `Cm9`, etc.  Only symbols that parse are returned.
+    pub fn suggest(partial: &str) -> Vec<String> {
+        complete_chord_symbols(partial).into_iter().filter(|s| Chord::parse(s).is_ok()).collect()
+    }
+}
+
+impl Chord {
+    /// Returns a simple walking bass line of `beats` notes under this chord.
+    ///
+    /// The line lands on the root on the first beat of every bar (of four beats), walks up (or down, on alternating bars)
+    /// through the chord tones, and uses a chromatic approach tone on the last beat of the bar into the next root.
+    /// The line is voiced in the second octave, and is entirely deterministic.
+    pub fn walking_bass_notes(&self, beats: usize) -> Vec<Note> {
+        let root = self.root.with_octave(Octave::Two);
+        let tones = self.relative_chord().into_iter().filter(|i| *i < Interval::PerfectOctave).map(|i| root + i).collect::<Vec<_>>();
+
+        let top = tones[tones.len() - 1];
+        let next_to_top = tones[tones.len() - 2];
+
+        (0..beats)
+            .map(|beat| {
+                let is_ascending = (beat / 4) % 2 == 0;
+
+                match (beat % 4, is_ascending) {
+                    (0, _) => root,
+                    (1, true) => tones[1],
+                    (2, true) => tones[2],
+                    (3, true) => root - Interval::MinorSecond,
+                    (1, false) => top - Interval::PerfectOctave,
+                    (2, false) => next_to_top - Interval::PerfectOctave,
+                    _ => root + Interval::MinorSecond,
+                }
+            })
+            .collect()
+    }
+}
+
+impl Chord {
+    /// Returns the similarity (from `0.0` to `1.0`) between the pitch classes of this chord's tones and another's.
+    ///
+    /// This is the number of shared pitch classes divided by the number of distinct pitch classes across both chords,
+    /// so octaves and enharmonic spellings are ignored (e.g., `C` and `C/E` have a similarity of `1.0`).
+    pub fn similarity(&self, other: &Chord) -> f32 {
+        let a = self.chord().iter().map(|n| n.pitch()).collect::<HashSet<_>>();
+        let b = other.chord().iter().map(|n| n.pitch()).collect::<HashSet<_>>();
+
+        let union = a.union(&b).count();
+
+        if union == 0 {
+            return 0.0;
+        }
+
+        a.intersection(&b).count() as f32 / union as f32
+    }
+
+    /// Returns the standard chord (one with a known modifier set, and no extensions) that is most similar to this chord,
+    /// along with its [`Chord::similarity`] score.
+    ///
+    /// Every chord tone is considered as a potential root, and ties are broken in favor of this chord's root, and then
+    /// the simpler chord.  This gives a graceful label (e.g., "≈ Cmaj7") for chords that do not have a standard symbol.
+    pub fn closest_named_chord(&self) -> (Chord, f32) {
+        let mut roots = vec![self.root];
+        for note in self.chord() {
+            let note = note.with_octave(self.root.octave());
+
+            if !roots.iter().any(|r| r.pitch() == note.pitch()) {
+                roots.push(note);
+            }
+        }
+
+        let mut best: Option<(Chord, f32)> = None;
+
+        for root in roots {
+            for mod_set in known_modifier_sets() {
+                let candidate = Chord::new(root).with_modifiers(mod_set);
+                let score = self.similarity(&candidate);
+
+                let is_better = match &best {
+                    None => true,
+                    Some((best_chord, best_score)) => score > *best_score || (score == *best_score && best_chord.root == root && candidate < *best_chord),
+                };
+
+                if is_better {
+                    best = Some((candidate, score));
+                }
+            }
+        }
+
+        best.unwrap_or_else(|| (Chord::new(self.root), 0.0))
+    }
+}
+
+impl Chord {
+    /// Returns the quality of the chord (e.g., [`ChordQuality::MinorSeventh`] for both `Dm7` and `Gm9/B`), or [`None`]
+    /// if the chord does not have one of the standard qualities (e.g., `C(♭5)`).
+    ///
+    /// The quality ignores the root, inversion, slash, and octave, as well as any tensions (e.g., `♭9`, or `add9`)
+    /// and alterations of a dominant chord (e.g., `7(♭5)` is still a [`ChordQuality::DominantSeventh`]).
+    pub fn quality(&self) -> Option<ChordQuality> {
+        let is_suspended = self.extensions.contains(&Extension::Sus2) || self.extensions.contains(&Extension::Sus4);
+        let is_sixth = self.extensions.contains(&Extension::Add6);
+        let is_flat_five = self.modifiers.contains(&Modifier::Flat5);
+
+        let quality = match self.known_chord() {
+            KnownChord::Unknown => return None,
+            KnownChord::Major if is_flat_five => return None,
+            KnownChord::Major if is_suspended => {
+                if self.extensions.contains(&Extension::Sus4) {
+                    ChordQuality::Suspended4
+                } else {
+                    ChordQuality::Suspended2
+                }
+            }
+            KnownChord::Major if is_sixth => ChordQuality::MajorSixth,
+            KnownChord::Major => ChordQuality::Major,
+            KnownChord::Minor if is_flat_five => ChordQuality::Diminished,
+            KnownChord::Minor if is_sixth => ChordQuality::MinorSixth,
+            KnownChord::Minor => ChordQuality::Minor,
+            KnownChord::Major7(_) => ChordQuality::MajorSeventh,
+            KnownChord::Dominant(_) | KnownChord::DominantFlat9(_) | KnownChord::DominantSharp9(_) | KnownChord::DominantSharp11(_) | KnownChord::Altered if is_suspended => {
+                ChordQuality::SuspendedSeventh
+            }
+            KnownChord::Dominant(_) | KnownChord::DominantFlat9(_) | KnownChord::DominantSharp9(_) | KnownChord::DominantSharp11(_) | KnownChord::Altered => ChordQuality::DominantSeventh,
+            KnownChord::MinorMajor7(_) => ChordQuality::MinorMajorSeventh,
+            KnownChord::MinorDominant(_) => ChordQuality::MinorSeventh,
+            KnownChord::HalfDiminished(_) => ChordQuality::HalfDiminishedSeventh,
+            KnownChord::Diminished => ChordQuality::DiminishedSeventh,
+            KnownChord::Augmented => ChordQuality::Augmented,
+            KnownChord::AugmentedDominant(_) => ChordQuality::AugmentedSeventh,
+            KnownChord::AugmentedMajor7(_) => ChordQuality::AugmentedMajorSeventh,
+        };
+
+        Some(quality)
+    }
+}
+
+impl Chord {
+    /// Returns the chord described by a Roman numeral (e.g., `"V7"`, `"ii"`, `"♭VI"`, or `"viiø7"`) in the given key.
+    ///
+    /// The numeral picks the scale degree of the key's [`ScaleKind`], and any leading accidentals (`b`, `♭`, `#`, `♯`) alter
+    /// that degree (so `"♭VI"` in C major is `A♭`).  Uppercase numerals are major, and lowercase numerals are minor, unless
+    /// followed by `°` / `o` (diminished), `ø` (half-diminished), or `+` (augmented).  Anything after that (e.g., `7`, `maj7`,
+    /// or `sus4`) is parsed as it would be in a chord symbol.  Applied chords are relative to the key of their target
+    /// (e.g., `"V7/V"` in C major is `D7`).
+    pub fn from_roman_numeral(numeral: &str, key: Note, mode: ScaleKind) -> Res<Chord> {
+        let root = ChordParser::parse(Rule::roman, numeral.trim())?.next().unwrap();
+
+        assert_eq!(Rule::roman, root.as_rule());
+
+        let mut components = root.into_inner().filter(|p| p.as_rule() == Rule::roman_chord).collect::<Vec<_>>();
+        let numeral = components.remove(0);
+
+        // Resolve the applied targets from the innermost (rightmost) outward, each of which becomes the key of the next.
+        let (mut key, mut mode) = (key, mode);
+        for target in components.into_iter().rev() {
+            let (chord, is_minor) = Chord::from_roman_chord_pair(target, key, mode)?;
+
+            key = chord.root();
+            mode = if is_minor { ScaleKind::Minor } else { ScaleKind::Major };
+        }
+
+        Ok(Chord::from_roman_chord_pair(numeral, key, mode)?.0)
+    }
+
+    /// Returns the chord described by a Roman numeral (e.g., `"bVII7"`, or `"V7/V"`) in the given major key.
+    ///
+    /// This is shorthand for [`Chord::from_roman_numeral`] with [`ScaleKind::Major`].
+    pub fn parse_roman(numeral: &str, key: Note) -> Res<Chord> {
+        Chord::from_roman_numeral(numeral, key, ScaleKind::Major)
+    }
+
+    /// Returns the chord described by a Nashville number (e.g., `"4"`, `"5-"`, `"2-7"`, `"7°"`, `"b7"`, or `"1/3"`) in the given key.
+    ///
+    /// The number picks the degree of the key's major scale, and any leading accidentals (`b`, `♭`, `#`, `♯`) alter that
+    /// degree.  Plain numbers are major, and a dash (`-`) or `m` makes them minor, while `°` (diminished), `ø`
+    /// (half-diminished), and `+` (augmented) mark the other qualities.  Anything after that (e.g., `7`, `maj7`, or `sus4`)
+    /// is parsed as it would be in a chord symbol, and a trailing `/` number is the bass note.
+    pub fn parse_nashville(number: &str, key: Note) -> Res<Chord> {
+        let root = ChordParser::parse(Rule::nashville, number.trim())?.next().unwrap();
+
+        assert_eq!(Rule::nashville, root.as_rule());
+
+        let mut chord = None;
+
+        for component in root.into_inner() {
+            match component.as_rule() {
+                Rule::nashville_chord => {
+                    let (suffix, parts): (Vec<_>, Vec<_>) = component.into_inner().partition(|p| p.as_rule() == Rule::nashville_suffix);
+
+                    let root = nashville_note(parts.into_iter(), key)?;
+                    let suffix = suffix.first().map(|p| p.as_str()).unwrap_or_default();
+                    let suffix = match suffix.strip_prefix('°') {
+                        Some(rest) => format!("dim{rest}"),
+                        None => suffix.to_owned(),
+                    };
+
+                    chord = Some(Chord::parse(&format!("{}{}", root.name_without_octave(), suffix))?.with_octave(root.octave()));
+                }
+                Rule::nashville_bass => {
+                    let bass = nashville_note(component.into_inner(), key)?;
+
+                    chord = chord.map(|c| c.with_slash(bass));
+                }
+                Rule::EOI => {}
+                _ => unreachable!(),
+            }
+        }
+
+        Ok(chord.unwrap())
+    }
+
+    /// Returns the Nashville number of the [`Chord`] in the given key (e.g., `Dm7` in `C` is `"2-7"`).
+    ///
+    /// Roots outside of the key's major scale use flats (e.g., `"b7"`), except for the tritone, which is `"#4"`.  Minor
+    /// chords use the dash (`-`), and diminished chords use `°`.
+    pub fn to_nashville(&self, key: Note) -> String {
+        let quality = self.quality_name();
+        let quality = quality.as_str();
+
+        let quality = if let Some(rest) = quality.strip_prefix("dim") {
+            format!("°{rest}")
+        } else if quality.starts_with('m') && !quality.starts_with("maj") {
+            format!("-{}", &quality[1..])
+        } else {
+            quality.to_owned()
+        };
+
+        let mut result = format!("{}{}", nashville_degree(self.root, key), quality);
+
+        if self.slash.is_some() || self.inversion > 0 {
+            let bass = self.chord().first().copied().unwrap_or(self.root);
+
+            result.push_str(&format!("/{}", nashville_degree(bass, key)));
+        }
+
+        result
+    }
+
+    /// Returns the Roman numeral (functional) label of the [`Chord`] in the given major key (e.g., `G7` in `C` is `"V7"`, and
+    /// `Am` is `"vi"`).
+    ///
+    /// Minor, diminished, and half-diminished chords use lowercase numerals (marked with `°` and `ø`), and augmented chords
+    /// are marked with `+`.  Non-diatonic dominant chords that resolve to a major or minor chord of the key are labeled as
+    /// applied chords (e.g., `D7` in `C` is `"V7/V"`), and any other roots outside of the key's major scale use flats
+    /// (e.g., `"bVII"`), except for the tritone, which is `"#IV"`.  The bass of slash chords and inversions is ignored.
+    pub fn relative_to(&self, key: Note) -> String {
+        let chord = Chord {
+            slash: None,
+            inversion: 0,
+            ..self.clone()
+        };
+
+        let scale = ScaleKind::Major.relative_scale().into_iter().map(|i| (key + i).pitch()).collect::<Vec<_>>();
+        let is_diatonic = chord.chord().iter().all(|n| scale.contains(&n.pitch()));
+        let is_dominant = ![Modifier::Minor, Modifier::Flat5, Modifier::Augmented5, Modifier::Major7, Modifier::Diminished]
+            .iter()
+            .any(|m| self.modifiers.contains(m));
+
+        let quality = chord.quality_name();
+        let (is_lower, quality) = if let Some(rest) = quality.strip_prefix("dim") {
+            (true, format!("°{rest}"))
+        } else if let Some(rest) = quality.strip_prefix("m7(♭5)") {
+            (true, format!("ø7{rest}"))
+        } else if quality.starts_with('m') && !quality.starts_with("maj") {
+            (true, quality[1..].to_owned())
+        } else {
+            (false, quality)
+        };
+
+        // Dominant chords outside of the key are labeled by the degree that they resolve to (down a fifth).
+        if !is_diatonic && is_dominant {
+            let target = roman_degree(self.root + Interval::PerfectFourth, key);
+
+            if let Some(target) = ["ii", "iii", "IV", "V", "vi"].into_iter().find(|t| t.eq_ignore_ascii_case(target)) {
+                return format!("V{quality}/{target}");
+            }
+        }
+
+        let degree = roman_degree(self.root, key);
+        let degree = if is_lower { degree.to_lowercase() } else { degree.to_owned() };
+
+        format!("{degree}{quality}")
+    }
+
+    /// Returns the name of the [`Chord`] without its root or bass (e.g., `Dm7/C` is `"m7"`).
+    fn quality_name(&self) -> String {
+        let name = self.name();
+        let mut quality = name.strip_prefix(self.root.static_name()).unwrap_or(&name);
+
+        if self.slash.is_some() || self.inversion > 0 {
+            if let Some(index) = quality.rfind('/') {
+                quality = &quality[..index];
+            }
+        }
+
+        quality.to_owned()
+    }
+
+    /// Builds the chord for a single (parsed) Roman numeral, and returns whether or not the numeral was lowercase.
+    fn from_roman_chord_pair(pair: pest::iterators::Pair<'_, Rule>, key: Note, mode: ScaleKind) -> Res<(Chord, bool)> {
+        let mut root = None;
+        let mut accidentals = Vec::new();
+        let mut is_lower = false;
+        let mut quality = "";
+        let mut suffix = "";
+
+        for component in pair.into_inner() {
+            match component.as_rule() {
+                Rule::roman_accidental => accidentals.push(component.as_str()),
+                Rule::roman_numeral => {
+                    let letters = component.as_str();
+                    let degree = roman_numeral_str_to_degree(letters)?;
+
+                    let interval = mode
+                        .relative_scale()
+                        .get(degree)
+                        .copied()
+                        .ok_or_else(|| anyhow::Error::msg(format!("The {} scale does not have a degree {}.", mode.static_name(), letters)))?;
+
+                    is_lower = letters.chars().all(|c| c.is_ascii_lowercase());
+                    root = Some(key + interval);
+                }
+                Rule::roman_quality => {
+                    quality = match component.as_str() {
+                        "°" | "o" => "dim",
+                        "ø" => "ø",
+                        "+" => "+",
+                        _ => unreachable!(),
+                    };
+                }
+                Rule::roman_suffix => suffix = component.as_str(),
+                _ => unreachable!(),
+            }
+        }
+
+        let mut root = root.unwrap();
+        for accidental in accidentals {
+            root = match accidental {
+                "b" | "♭" => root - Interval::AugmentedUnison,
+                _ => root + Interval::AugmentedUnison,
+            };
+        }
+
+        // The seventh is implied by the half-diminished symbol.
+        if quality == "ø" {
+            suffix = suffix.strip_prefix('7').unwrap_or(suffix);
+        }
+
+        if quality.is_empty() && is_lower {
+            quality = "m";
+        }
+
+        let chord = Chord::parse(&format!("{}{}{}", root.name_without_octave(), quality, suffix))?;
+
+        Ok((chord.with_octave(root.octave()), is_lower))
+    }
+}
+
+impl HasName for Chord {
+    fn name(&self) -> String {
+        let known_chord = self.known_chord();
+        let mut name = String::new();
+
+        name.push_str(self.root.static_name());
+        name.push_str(&known_chord.quality_name());
+
+        // The quality of an augmented known chord already names its sharp fifth (e.g., `+7`, rather than `7(♯5)`).
+        let is_augmented = matches!(known_chord, KnownChord::Augmented | KnownChord::AugmentedMajor7(_) | KnownChord::AugmentedDominant(_));
+
+        // Add the alterations: the fifth comes before the upper alterations (e.g., `7(♭5)(♭9)`, rather than `7(♭9)(♭5)`).
+        for alteration in [Modifier::Flat5, Modifier::Augmented5, Modifier::Flat9, Modifier::Sharp9, Modifier::Sharp11] {
+            if !self.modifiers.contains(&alteration) {
+                continue;
+            }
+
+            match alteration {
+                Modifier::Augmented5 if is_augmented => {}
+                Modifier::Augmented5 => name.push_str("(♯5)"),
+                _ => name.push_str(&format!("({})", alteration.static_name())),
+            }
+        }
+
+        // Add extensions.
+        if !self.extensions.is_empty() {
+            for e in &self.extensions {
+                name.push_str(&format!("({})", e.static_name()));
+            }
+        }
+
+        // Add slash note.
+        if let Some(slash) = self.slash {
+            name.push_str(&format!("/{}", slash.static_name()));
+        }
+
+        // Add special information about the chord.
+
+        name
+    }
+}
+
+impl HasPreciseName for Chord {
+    fn precise_name(&self) -> String {
+        let mut name = String::new();
+
+        name.push_str(&self.name());
+
+        // Add bass octave modifier.
+        if let Some(octave) = self.bass_octave {
+            name.push_str(&format!("_{}", octave.static_name()));
+        }
+
+        // Add octave modifier.
+        if self.root.octave() != Octave::Four {
+            name.push_str(&format!("@{}", self.root.octave().static_name()));
+        }
+
+        // Add inversion modifier.
+        if self.inversion != 0 {
+            name.push_str(&format!("^{}", self.inversion));
+        }
+
+        // Add crunchy modifier.
+        if self.is_crunchy {
+            name.push('!');
+        }
+
+        // Add simplified modifier.
+        if self.is_simplified {
+            name.push('~');
+        }
+
+        name
+    }
+}
+
+impl HasRoot for Chord {
+    fn root(&self) -> Note {
+        self.root
+    }
+}
+
+impl HasSlash for Chord {
+    fn slash(&self) -> Note {
+        self.slash.unwrap_or(self.root)
+    }
+}
+
+impl HasModifiers for Chord {
+    fn modifiers(&self) -> &HashSet<Modifier> {
+        &self.modifiers
+    }
+}
+
+impl HasExtensions for Chord {
+    fn extensions(&self) -> &HashSet<Extension> {
+        &self.extensions
+    }
+}
+
+impl HasInversion for Chord {
+    fn inversion(&self) -> u8 {
+        self.inversion
+    }
+}
+
+impl HasInversionName for Chord {
+    fn inversion_name(&self) -> String {
+        let tones = self.relative_chord().len();
+        let inversion = self.inversion as usize % tones;
+
+        let name = match inversion {
+            0 => "root position",
+            1 => "first inversion",
+            2 => "second inversion",
+            3 => "third inversion",
+            4 => "fourth inversion",
+            5 => "fifth inversion",
+            _ => "sixth inversion",
+        };
+
+        let figured_bass = match (tones, inversion) {
+            (3, 1) => Some("6"),
+            (3, 2) => Some("6/4"),
+            (4, 0) => Some("7"),
+            (4, 1) => Some("6/5"),
+            (4, 2) => Some("4/3"),
+            (4, 3) => Some("2"),
+            _ => None,
+        };
+
+        match figured_bass {
+            Some(figured_bass) => format!("{} ({})", name, figured_bass),
+            None => name.to_string(),
+        }
+    }
+}
+
+impl HasIsCrunchy for Chord {
+    fn is_crunchy(&self) -> bool {
+        self.is_crunchy
+    }
+}
+
+impl Display for Chord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.precise_name())
+    }
+}
+
+impl Chordable for Chord {
+    fn with_modifier(mut self, modifier: Modifier) -> Chord {
+        self.cache = ChordCache::default();
+
+        // Altered modifiers trump b5 and #5 modifiers (the altered scale already implies both).
+        if modifier == Modifier::Altered {
+            self.modifiers.remove(&Modifier::Flat5);
+            self.modifiers.remove(&Modifier::Augmented5);
+        }
+
+        if (modifier == Modifier::Augmented5 || modifier == Modifier::Flat5) && self.modifiers.contains(&Modifier::Altered) {
+            return self;
+        }
+
+        // Augmented modifiers trump b5 and dim modifiers.
+        if modifier == Modifier::Augmented5 {
+            self.modifiers.remove(&Modifier::Flat5);
+            self.modifiers.remove(&Modifier::Diminished);
+        }
+
+        if (modifier == Modifier::Diminished || modifier == Modifier::Flat5) && self.modifiers.contains(&Modifier::Augmented5) {
+            return self;
+        }
+
+        self.modifiers.insert(modifier);
+
+        self
+    }
+
+    fn with_modifiers(self, modifiers: &[Modifier]) -> Chord {
+        let mut chord = self;
+
+        for m in modifiers {
+            chord = chord.with_modifier(*m);
+        }
+
+        chord
+    }
+
+    fn with_extension(mut self, extension: Extension) -> Chord {
+        self.cache = ChordCache::default();
+        self.extensions.insert(extension);
+
+        self
+    }
+
+    fn with_extensions(self, extensions: &[Extension]) -> Chord {
         let mut chord = self;
 
         for e in extensions {
@@ -614,13 +1555,15 @@ impl Chordable for Chord {
     }
 
     fn with_inversion(mut self, inversion: u8) -> Chord {
+        self.cache = ChordCache::default();
         self.inversion = inversion;
 
         self
     }
 
     fn with_slash(mut self, slash: Note) -> Chord {
-        self.slash = Some(slash);
+        self.cache = ChordCache::default();
+        self.slash = Some(normalize_slash(slash));
 
         self
     }
@@ -628,11 +1571,27 @@ impl Chordable for Chord {
     fn with_octave(self, octave: Octave) -> Self {
         let root = Note::new(self.root.named_pitch(), octave);
 
-        Chord { root, ..self }
+        Chord {
+            root,
+            cache: ChordCache::default(),
+            ..self
+        }
+    }
+
+    fn with_bass_octave(self, octave: Octave) -> Chord {
+        Chord {
+            bass_octave: Some(octave),
+            cache: ChordCache::default(),
+            ..self
+        }
     }
 
     fn with_crunchy(self, is_crunchy: bool) -> Chord {
-        Chord { is_crunchy, ..self }
+        Chord {
+            is_crunchy,
+            cache: ChordCache::default(),
+            ..self
+        }
     }
 
     // Modifiers.
@@ -747,6 +1706,16 @@ impl Chordable for Chord {
         self.half_diminished()
     }
 
+    fn altered(self) -> Chord {
+        let chord = if self.dominant_degree().is_some() { self } else { self.seven() };
+
+        chord.with_modifier(Modifier::Altered)
+    }
+
+    fn alt(self) -> Chord {
+        self.altered()
+    }
+
     // Extensions.
 
     fn sus2(self) -> Chord {
@@ -858,7 +1827,7 @@ impl HasKnownChord for Chord {
             KnownChord::Diminished
         } else if modifiers.contains(&Modifier::Minor) {
             if modifiers.contains(&Modifier::Major7) {
-                return KnownChord::MinorMajor7;
+                return KnownChord::MinorMajor7(degree);
             }
 
             if contains_dominant {
@@ -873,7 +1842,7 @@ impl HasKnownChord for Chord {
         } else {
             if modifiers.contains(&Modifier::Augmented5) {
                 if modifiers.contains(&Modifier::Major7) {
-                    return KnownChord::AugmentedMajor7;
+                    return KnownChord::AugmentedMajor7(degree);
                 }
 
                 if contains_dominant {
@@ -884,10 +1853,14 @@ impl HasKnownChord for Chord {
             }
 
             if self.modifiers.contains(&Modifier::Major7) {
-                return KnownChord::Major7;
+                return KnownChord::Major7(degree);
             }
 
             if contains_dominant {
+                if modifiers.contains(&Modifier::Altered) {
+                    return KnownChord::Altered;
+                }
+
                 if modifiers.contains(&Modifier::Flat9) {
                     return KnownChord::DominantFlat9(degree);
                 }
@@ -920,22 +1893,45 @@ impl HasRelativeScale for Chord {
     }
 }
 
+impl HasScaleKind for Chord {
+    fn scale_kind(&self) -> ScaleKind {
+        self.known_chord().scale_kind()
+    }
+}
+
 impl HasRelativeChord for Chord {
     fn relative_chord(&self) -> Vec<Interval> {
-        let mut result = self.known_chord().relative_chord();
+        let known_chord = self.known_chord();
+        let mut result = known_chord.relative_chord();
         let modifiers = &self.modifiers;
         let extensions = &self.extensions;
 
         // Dominant extensions.
 
-        if modifiers.contains(&Modifier::Dominant(Degree::Nine)) {
-            result.push(Interval::MajorNinth);
+        // An altered ninth (or eleventh) replaces the natural one implied by the dominant degree (e.g., `C13(♯11)` has no `F`).
+        let ninth = !modifiers.contains(&Modifier::Flat9) && !modifiers.contains(&Modifier::Sharp9);
+        let eleventh = !modifiers.contains(&Modifier::Sharp11) && !extensions.contains(&Extension::Flat11);
+
+        if known_chord == KnownChord::Altered {
+            // The altered tensions replace the natural ones implied by the dominant degree.
+        } else if modifiers.contains(&Modifier::Dominant(Degree::Nine)) {
+            if ninth {
+                result.push(Interval::MajorNinth);
+            }
         } else if modifiers.contains(&Modifier::Dominant(Degree::Eleven)) {
-            result.push(Interval::MajorNinth);
-            result.push(Interval::PerfectEleventh);
+            if ninth {
+                result.push(Interval::MajorNinth);
+            }
+            if eleventh {
+                result.push(Interval::PerfectEleventh);
+            }
         } else if modifiers.contains(&Modifier::Dominant(Degree::Thirteen)) {
-            result.push(Interval::MajorNinth);
-            result.push(Interval::PerfectEleventh);
+            if ninth {
+                result.push(Interval::MajorNinth);
+            }
+            if eleventh {
+                result.push(Interval::PerfectEleventh);
+            }
             result.push(Interval::MajorThirteenth);
         }
 
@@ -1021,12 +2017,25 @@ impl HasRelativeChord for Chord {
 
 impl HasScale for Chord {
     fn scale(&self) -> Vec<Note> {
-        self.relative_scale().into_iter().map(|i| self.root + i).collect()
+        self.cache.scale.get_or_init(|| self.relative_scale().into_iter().map(|i| self.root + i).collect()).clone()
+    }
+}
+
+impl HasIntervals for Chord {
+    fn intervals(&self) -> Vec<Interval> {
+        self.relative_chord()
     }
 }
 
 impl HasChord for Chord {
     fn chord(&self) -> Vec<Note> {
+        self.cache.chord.get_or_init(|| self.compute_chord()).clone()
+    }
+}
+
+impl Chord {
+    /// Computes the notes of the chord (see [`HasChord::chord`], which caches them).
+    fn compute_chord(&self) -> Vec<Note> {
         let mut result: Vec<_> = self.relative_chord().into_iter().map(|i| self.root + i).collect();
 
         // Perform inversions.
@@ -1052,6 +2061,15 @@ impl HasChord for Chord {
             }
         }
 
+        // Respell the awkward tones (other than the root).
+        if self.is_simplified {
+            for note in &mut result {
+                if note.named_pitch() != self.root.named_pitch() {
+                    *note = simplify_awkward_spelling(*note);
+                }
+            }
+        }
+
         // Add slash note.
         if let Some(mut slash) = self.slash {
             // Fix slash note (it should be less than, or equal to, one octave away from the bottom tone).
@@ -1066,6 +2084,13 @@ impl HasChord for Chord {
             result.insert(0, slash);
         }
 
+        // Place the bass note in its explicit octave (leaving the upper structure where it is).
+        if let Some(octave) = self.bass_octave {
+            if let Some(bass) = result.first_mut() {
+                *bass = bass.with_octave(octave);
+            }
+        }
+
         // Crunchiness, etc. can introduce changes, so resort, and dedup.
         result.sort();
         result.dedup();
@@ -1097,7 +2122,8 @@ impl Parsable for Chord {
     where
         Self: Sized,
     {
-        let root = ChordParser::parse(Rule::chord, input)?.next().unwrap();
+        let input = normalize_symbol(input);
+        let root = ChordParser::parse(Rule::chord, &input)?.next().unwrap();
 
         assert_eq!(Rule::chord, root.as_rule());
 
@@ -1111,9 +2137,23 @@ impl Parsable for Chord {
 
         while let Some(component) = components.next() {
             match component.as_rule() {
-                Rule::maj7_modifier => {
-                    result = result.major7();
-                }
+                Rule::maj7_modifier => match component.as_str() {
+                    "maj7" => {
+                        result = result.major7();
+                    }
+                    "maj9" => {
+                        result = result.major7().nine();
+                    }
+                    "maj11" => {
+                        result = result.major7().eleven();
+                    }
+                    "maj13" => {
+                        result = result.major7().thirteen();
+                    }
+                    _ => {
+                        unreachable!();
+                    }
+                },
                 Rule::minor => {
                     result = result.minor();
                 }
@@ -1147,7 +2187,7 @@ impl Parsable for Chord {
                     "sus2" => {
                         result = result.sus2();
                     }
-                    "sus4" => {
+                    "sus4" | "sus" => {
                         result = result.sus4();
                     }
                     "add2" => {
@@ -1159,6 +2199,9 @@ impl Parsable for Chord {
                     "add6" | "6" => {
                         result = result.add6();
                     }
+                    "6/9" => {
+                        result = result.add6().add9();
+                    }
                     "b5" | "♭5" => {
                         result = result.flat5();
                     }
@@ -1192,6 +2235,9 @@ impl Parsable for Chord {
                     "#13" | "♯13" => {
                         result = result.sharp13();
                     }
+                    "alt" => {
+                        result = result.altered();
+                    }
                     _ => {
                         unreachable!();
                     }
@@ -1201,6 +2247,11 @@ impl Parsable for Chord {
 
                     result = result.with_slash(note);
                 }
+                Rule::bass => {
+                    let octave = octave_str_to_octave(components.next().unwrap().as_str())?;
+
+                    result = result.with_bass_octave(octave);
+                }
                 Rule::at => {
                     let octave = octave_str_to_octave(components.next().unwrap().as_str())?;
 
@@ -1214,6 +2265,9 @@ impl Parsable for Chord {
                 Rule::bang => {
                     result = result.with_crunchy(true);
                 }
+                Rule::tilde => {
+                    result = result.simplify_enharmonics();
+                }
                 Rule::EOI => {}
                 _ => {
                     unreachable!();
@@ -1221,19 +2275,46 @@ impl Parsable for Chord {
             }
         }
 
+        // Extensions can be written in any order (e.g., `C13(♯11)` or `C(♯11)13`), so only the highest dominant
+        // degree is kept (a `13` implies the `7`, `9`, and `11`), and redundant extensions are removed.
+        let highest_degree = result.modifiers.iter().filter_map(|m| if let Modifier::Dominant(d) = m { Some(*d) } else { None }).max();
+
+        if let Some(highest_degree) = highest_degree {
+            result.modifiers.retain(|m| !matches!(m, Modifier::Dominant(d) if *d != highest_degree));
+        }
+
+        result.remove_redundancies();
+
         Ok(result)
     }
 }
 
-#[cfg(feature = "audio")]
-use super::base::{Playable, PlaybackHandle};
+impl FromStr for Chord {
+    type Err = Err;
 
-#[cfg(feature = "audio")]
-impl Playable for Chord {
-    
-    fn play(&self, delay: Duration, length: Duration, fade_in: Duration) -> Res<PlaybackHandle> {
-        use rodio::{source::SineWave, OutputStream, Sink, Source};
+    fn from_str(s: &str) -> Res<Self> {
+        Self::parse(s)
+    }
+}
+
+impl TryFrom<&str> for Chord {
+    type Error = Err;
+
+    fn try_from(value: &str) -> Res<Self> {
+        Self::parse(value)
+    }
+}
+
+impl TryFrom<String> for Chord {
+    type Error = Err;
+
+    fn try_from(value: String) -> Res<Self> {
+        Self::parse(&value)
+    }
+}
 
+impl Renderable for Chord {
+    fn render_with_options(&self, sink: &mut dyn OutputSink, delay: Duration, length: Duration, options: RenderOptions) -> Void {
         let chord_tones = self.chord();
 
         if length.as_secs_f32() <= chord_tones.len() as f32 * delay.as_secs_f32() {
@@ -1242,38 +2323,228 @@ impl Playable for Chord {
             ));
         }
 
-        let (stream, stream_handle) = OutputStream::try_default()?;
+        let tones = self.arpeggio_onsets(delay, ArpeggioDirection::Up).into_iter().map(|(n, d)| (n.frequency(), d)).collect::<Vec<_>>();
+        let samples = render_enveloped_tones(&tones, length, options.to_envelope(), sink.sample_rate(), options.waveform);
 
-        let mut sinks = vec![];
+        sink.write(&samples)
+    }
+}
 
-        for (k, n) in chord_tones.into_iter().enumerate() {
-            let sink = Sink::try_new(&stream_handle)?;
+impl Chord {
+    /// Returns the chord tones paired with their onsets, when arpeggiated with `delay` between successive notes in the given direction.
+    ///
+    /// With a `delay` of zero, every onset is zero (i.e., the chord is struck as a block).
+    pub fn arpeggio_onsets(&self, delay: Duration, direction: ArpeggioDirection) -> Vec<(Note, Duration)> {
+        let chord_tones = self.chord();
+        let count = chord_tones.len() as u32;
 
-            let d = delay * k as u32;
+        chord_tones
+            .into_iter()
+            .enumerate()
+            .map(|(k, n)| {
+                let step = match direction {
+                    ArpeggioDirection::Up => k as u32,
+                    ArpeggioDirection::Down => count - 1 - k as u32,
+                };
 
-            let source = SineWave::new(n.frequency()).take_duration(length - d).buffered().delay(d).fade_in(fade_in).amplify(0.20);
+                (n, delay * step)
+            })
+            .collect()
+    }
 
-            sink.append(source);
+    /// Plays the chord arpeggiated (or strummed), with `delay` between the onsets of successive notes in the given direction.
+    ///
+    /// Playing [`ArpeggioDirection::Up`] is the same as [`Playable::play`], and a `delay` of zero plays a block chord.
+    #[cfg(feature = "audio")]
+    #[must_use = "Dropping the PlayableResult will stop the playback."]
+    pub fn play_arpeggio(&self, delay: Duration, length: Duration, fade_in: Duration, direction: ArpeggioDirection) -> Res<PlaybackHandle> {
+        let onsets = self.arpeggio_onsets(delay, direction);
 
-            sinks.push(sink);
+        if length.as_secs_f32() <= onsets.len() as f32 * delay.as_secs_f32() {
+            return Err(anyhow::Error::msg(
+                "The delay is too long for the length of play (i.e., the number of chord tones times the delay is longer than the length).",
+            ));
         }
 
-        Ok(PlaybackHandle::new(stream, stream_handle, sinks))
+        let options = RenderOptions::with_fade_in(fade_in);
+        let mut sink = DeviceSink::try_default(options.sample_rate)?;
+
+        let tones = onsets.into_iter().map(|(n, d)| (n.frequency(), d)).collect::<Vec<_>>();
+        sink.write(&render_enveloped_tones(&tones, length, options.to_envelope(), options.sample_rate, options.waveform))?;
+
+        Ok(sink.into_playback_handle())
+    }
+
+    /// Renders the chord (as it would be played with the given [`RenderOptions`]) into the bytes of a 16-bit PCM WAV file.
+    ///
+    /// The file is mono (at the `sample_rate` of the options), and contains `(length + release) * sample_rate` (rounded) samples.
+    pub fn to_wav_bytes(&self, length: Duration, options: RenderOptions) -> Res<Vec<u8>> {
+        let mut sink = MemorySink::new(options.sample_rate);
+
+        self.render_with_options(&mut sink, Duration::ZERO, length, options)?;
+
+        Ok(encode_wav(sink.samples(), options.sample_rate))
+    }
+
+    /// Renders the chord (as it would be played with the given [`RenderOptions`]) into a 16-bit PCM WAV file at the given path.
+    #[cfg(feature = "audio")]
+    pub fn to_wav(&self, path: impl AsRef<std::path::Path>, length: Duration, options: RenderOptions) -> Void {
+        std::fs::write(path, self.to_wav_bytes(length, options)?)?;
+
+        Ok(())
+    }
+
+    /// Returns the bytes of a type-0 MIDI file containing the chord, held for `length_beats`.
+    #[cfg(feature = "midi")]
+    pub fn to_midi_bytes(&self, length_beats: f32, options: super::midi::MidiExportOptions) -> Res<Vec<u8>> {
+        super::midi::progression_to_midi(std::slice::from_ref(self), length_beats, options)
+    }
+
+    /// Writes the chord, held for `length_beats` at the given tempo (in beats per minute), to a type-0 MIDI file at the given path.
+    #[cfg(feature = "midi")]
+    pub fn to_midi_file(&self, path: impl AsRef<std::path::Path>, length_beats: f32, tempo: f32) -> Void {
+        super::midi::progression_to_midi_file(path, std::slice::from_ref(self), length_beats, super::midi::MidiExportOptions::with_tempo(tempo))
+    }
+
+    /// Reads the MIDI file at the given path, and returns the candidate chords at each onset (see [`super::midi::midi_to_chords`]).
+    #[cfg(feature = "midi")]
+    pub fn try_from_midi_file(path: impl AsRef<std::path::Path>) -> Res<Vec<(Duration, Vec<Self>)>> {
+        super::midi::midi_file_to_chords(path, super::midi::MidiImportOptions::default())
     }
 }
 
+#[cfg(feature = "audio")]
+use super::base::{DeviceSink, Playable, PlaybackHandle};
+
+#[cfg(feature = "audio")]
+impl Playable for Chord {}
+
 impl Default for Chord {
     fn default() -> Self {
         Chord::new(super::note::C)
     }
 }
 
+impl ChordBuilder {
+    /// Returns a new builder for a `C4` major triad (i.e., no modifiers or extensions).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the root note of the chord.
+    pub fn root(mut self, root: Note) -> Self {
+        self.root = root;
+        self
+    }
+
+    /// Adds a modifier to the chord.
+    pub fn modifier(mut self, modifier: Modifier) -> Self {
+        self.modifiers.push(modifier);
+        self
+    }
+
+    /// Adds an extension to the chord.
+    pub fn extension(mut self, extension: Extension) -> Self {
+        self.extensions.push(extension);
+        self
+    }
+
+    /// Sets the slash note of the chord.
+    pub fn slash(mut self, slash: Note) -> Self {
+        self.slash = Some(slash);
+        self
+    }
+
+    /// Sets the inversion of the chord.
+    pub fn inversion(mut self, inversion: u8) -> Self {
+        self.inversion = inversion;
+        self
+    }
+
+    /// Sets whether or not the chord is "crunchy".
+    pub fn crunchy(mut self, is_crunchy: bool) -> Self {
+        self.is_crunchy = is_crunchy;
+        self
+    }
+
+    /// Builds the [`Chord`].
+    pub fn build(&self) -> Chord {
+        let chord = Chord::new(self.root)
+            .with_modifiers(&self.modifiers)
+            .with_extensions(&self.extensions)
+            .with_inversion(self.inversion)
+            .with_crunchy(self.is_crunchy);
+
+        match self.slash {
+            Some(slash) => chord.with_slash(slash),
+            None => chord,
+        }
+    }
+}
+
+impl Default for ChordBuilder {
+    fn default() -> Self {
+        Self {
+            root: super::note::C,
+            slash: None,
+            modifiers: Vec::new(),
+            extensions: Vec::new(),
+            inversion: 0,
+            is_crunchy: false,
+        }
+    }
+}
+
+// Helpers.
+
+/// Returns the note for the (parsed) accidentals and degree of a Nashville number in the given key.
+fn nashville_note<'a>(parts: impl Iterator<Item = pest::iterators::Pair<'a, Rule>>, key: Note) -> Res<Note> {
+    let mut accidentals = Vec::new();
+    let mut note = key;
+
+    for part in parts {
+        match part.as_rule() {
+            Rule::roman_accidental => accidentals.push(part.as_str()),
+            Rule::nashville_degree => note = key + ScaleKind::Major.relative_scale()[part.as_str().parse::<usize>()? - 1],
+            _ => unreachable!(),
+        }
+    }
+
+    for accidental in accidentals {
+        note = match accidental {
+            "b" | "♭" => note - Interval::AugmentedUnison,
+            _ => note + Interval::AugmentedUnison,
+        };
+    }
+
+    Ok(note)
+}
+
+/// Returns the Nashville degree (e.g., `"4"`, or `"b7"`) of the note in the given key.
+fn nashville_degree(note: Note, key: Note) -> &'static str {
+    let semitones = (note.pitch().pitch_class_number() as i8 - key.pitch().pitch_class_number() as i8).rem_euclid(12);
+
+    ["1", "b2", "2", "b3", "3", "4", "#4", "5", "b6", "6", "b7", "7"][semitones as usize]
+}
+
+/// Returns the Roman numeral degree (e.g., `"IV"`, or `"bVII"`) of the note in the given major key.
+fn roman_degree(note: Note, key: Note) -> &'static str {
+    let semitones = (note.pitch().pitch_class_number() as i8 - key.pitch().pitch_class_number() as i8).rem_euclid(12);
+
+    ["I", "bII", "II", "bIII", "III", "IV", "#IV", "V", "bVI", "VI", "bVII", "VII"][semitones as usize]
+}
+
 // Tests.
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::core::{note::*, octave::HasOctave};
+    use crate::core::{
+        base::{Envelope, Waveform},
+        named_pitch::NamedPitch,
+        note::*,
+        octave::HasOctave,
+    };
     use pretty_assertions::assert_eq;
 
     #[test]
@@ -1282,13 +2553,22 @@ mod tests {
         assert_eq!(Chord::new(C).flat5().name(), "C(♭5)");
         assert_eq!(Chord::new(C).minor().augmented().name(), "Cm(♯5)");
         assert_eq!(Chord::new(C).with_octave(Octave::Six).precise_name(), "C@6");
+        assert_eq!(format!("{}", Chord::new(C).minor().seven().flat_five()), "Cm7(♭5)");
+        assert_eq!(format!("{}", Chord::new(C).with_octave(Octave::Six)), "C@6");
 
         assert_eq!(
-            format!("{}", Chord::new(C).minor().seven().flat_five()),
+            Chord::new(C).minor().seven().flat_five().summary(),
             "Cm7(♭5)\n   half diminished, locrian, minor seven flat five, seventh mode of major scale, major scale starting one half step up\n   C, D, E♭, F, G♭, A♭, B♭\n   C, E♭, G♭, B♭"
         );
     }
 
+    #[test]
+    fn test_chord_string() {
+        assert_eq!(Chord::new(C).chord_string(DisplayOptions::without_octave()), "C E G");
+        assert_eq!(Chord::new(C).chord_string(DisplayOptions::default()), "C4 E4 G4");
+        assert_eq!(Chord::new(C).minor().scale_string(DisplayOptions::without_octave()), "C D E♭ F G A♭ B♭");
+    }
+
     #[test]
     fn test_properties() {
         assert_eq!(Chord::new(C).seven().flat9().root(), C);
@@ -1302,12 +2582,75 @@ mod tests {
         assert_eq!(Chord::new(C).flat9().with_octave(Octave::Three).root().octave(), Octave::Three);
     }
 
+    #[test]
+    fn test_inversion_name() {
+        assert_eq!(Chord::new(C).inversion_name(), "root position");
+        assert_eq!(Chord::new(C).with_inversion(1).inversion_name(), "first inversion (6)");
+        assert_eq!(Chord::new(C).with_inversion(2).inversion_name(), "second inversion (6/4)");
+
+        assert_eq!(Chord::new(C).seven().inversion_name(), "root position (7)");
+        assert_eq!(Chord::new(C).seven().with_inversion(1).inversion_name(), "first inversion (6/5)");
+        assert_eq!(Chord::new(C).seven().with_inversion(2).inversion_name(), "second inversion (4/3)");
+        assert_eq!(Chord::new(C).seven().with_inversion(3).inversion_name(), "third inversion (2)");
+
+        assert_eq!(Chord::new(C).nine().with_inversion(4).inversion_name(), "fourth inversion");
+    }
+
+    #[test]
+    fn test_all_inversions() {
+        let inversions = Chord::new(C).all_inversions();
+
+        assert_eq!(inversions.len(), 3);
+        assert_eq!(inversions[0], Chord::new(C));
+        assert_eq!(inversions.iter().map(|c| c.inversion()).collect::<Vec<_>>(), vec![0, 1, 2]);
+        assert_eq!(inversions.iter().map(|c| c.precise_name()).collect::<Vec<_>>(), vec!["C", "C^1", "C^2"]);
+        assert_eq!(inversions[2].chord(), vec![G, CFive, EFive]);
+
+        let inversions = Chord::parse("Cm7@3").unwrap().all_inversions();
+
+        assert_eq!(inversions.len(), 4);
+        assert_eq!(inversions[0], Chord::parse("Cm7@3").unwrap());
+        assert_eq!(inversions[3].precise_name(), "Cm7@3^3");
+        assert_eq!(Chord::new(C).nine().all_inversions().len(), 5);
+
+        // The inversion of the original chord does not matter.
+        assert_eq!(Chord::new(C).with_inversion(2).all_inversions(), Chord::new(C).all_inversions());
+    }
+
+    #[test]
+    fn test_contains() {
+        let chord = Chord::new(C).seven();
+
+        assert!(chord.contains_note(&BFlat));
+        assert!(chord.contains_note(&ASharpTwo));
+        assert!(chord.contains_note(&EFive));
+        assert!(!chord.contains_note(&D));
+        assert!(chord.contains_pitch(Pitch::G));
+        assert!(!chord.contains_pitch(Pitch::A));
+
+        assert!(chord.contains_exact(&BFlat));
+        assert!(!chord.contains_exact(&ASharp));
+        assert!(!chord.contains_exact(&BFlatFive));
+
+        // The slash note counts as a chord tone.
+        let chord = Chord::parse("C/D").unwrap();
+
+        assert!(chord.contains_note(&D));
+        assert!(chord.contains_exact(&DThree));
+    }
+
     #[test]
     fn test_known_chords() {
         assert_eq!(Chord::new(C).known_chord(), KnownChord::Major);
         assert_eq!(Chord::new(C).minor().known_chord(), KnownChord::Minor);
-        assert_eq!(Chord::new(C).major7().known_chord(), KnownChord::Major7);
-        assert_eq!(Chord::new(C).minor().major7().known_chord(), KnownChord::MinorMajor7);
+        assert_eq!(Chord::new(C).major7().known_chord(), KnownChord::Major7(Degree::Seven));
+        assert_eq!(Chord::new(C).minor().major7().known_chord(), KnownChord::MinorMajor7(Degree::Seven));
+        assert_eq!(Chord::parse("Cmaj9").unwrap().known_chord(), KnownChord::Major7(Degree::Nine));
+        assert_eq!(KnownChord::Major7(Degree::Thirteen).name(), "maj13");
+        assert_eq!(KnownChord::MinorMajor7(Degree::Nine).name(), "m(maj9)");
+        assert_eq!(KnownChord::HalfDiminished(Degree::Nine).quality_name(), "m9");
+        assert_eq!(KnownChord::HalfDiminished(Degree::Nine).name(), "m9(♭5)");
+        assert_eq!(Chord::new(C).seven().flat9().flat5().sharp11().name(), "C7(♭5)(♭9)(♯11)");
         assert_eq!(Chord::new(C).minor().dominant(Degree::Seven).known_chord(), KnownChord::MinorDominant(Degree::Seven));
         assert_eq!(Chord::new(C).minor().eleven().known_chord(), KnownChord::MinorDominant(Degree::Eleven));
         assert_eq!(Chord::new(C).seven().known_chord(), KnownChord::Dominant(Degree::Seven));
@@ -1317,7 +2660,7 @@ mod tests {
         assert_eq!(Chord::new(C).dim().known_chord(), KnownChord::Diminished);
         assert_eq!(Chord::new(C).minor().seven().flat5().known_chord(), KnownChord::HalfDiminished(Degree::Seven));
         assert_eq!(Chord::new(C).augmented().known_chord(), KnownChord::Augmented);
-        assert_eq!(Chord::new(C).aug().major7().known_chord(), KnownChord::AugmentedMajor7);
+        assert_eq!(Chord::new(C).aug().major7().known_chord(), KnownChord::AugmentedMajor7(Degree::Seven));
         assert_eq!(Chord::new(C).augmented().seven().known_chord(), KnownChord::AugmentedDominant(Degree::Seven));
         assert_eq!(Chord::new(C).seven().sharp11().known_chord(), KnownChord::DominantSharp11(Degree::Seven));
         assert_eq!(Chord::new(C).seven().flat9().known_chord(), KnownChord::DominantFlat9(Degree::Seven));
@@ -1359,6 +2702,32 @@ mod tests {
         assert_eq!(Chord::new(DFlat).dim().scale(), vec![DFlat, EFlat, FFlat, GFlat, ADoubleFlat, BDoubleFlat, CDoubleFlatFive, CFive]);
     }
 
+    #[test]
+    fn test_altered() {
+        let chord = Chord::parse("C7alt").unwrap();
+
+        assert_eq!(chord, Chord::new(C).alt());
+        assert_eq!(chord, Chord::new(C).seven().altered());
+        assert_eq!(chord.known_chord(), KnownChord::Altered);
+        assert_eq!(chord.quality(), Some(ChordQuality::DominantSeventh));
+        assert_eq!(chord.name(), "C7alt");
+        assert_eq!(Chord::parse(&chord.name()).unwrap(), chord);
+
+        // Root, third, and seventh, with the ♭9, ♯9, ♯11, and ♭13 (and no fifth).
+        assert_eq!(chord.chord(), vec![C, E, BFlat, DFlatFive, DSharpFive, FSharpFive, AFlatFive]);
+        assert_eq!(chord.scale(), vec![C, DFlat, EFlat, FFlat, GFlat, AFlat, BFlat]);
+
+        assert_eq!(Chord::parse("G7alt").unwrap().chord(), vec![G, B, FFive, AFlatFive, ASharpFive, CSharpSix, EFlatSix]);
+        assert_eq!(Chord::parse("G7alt/B").unwrap().name(), "G7alt/B");
+        assert_eq!(Chord::parse("Galt").unwrap(), Chord::parse("G7alt").unwrap());
+
+        // The altered modifier trumps the fifth alterations (the altered scale already implies both).
+        assert_eq!(Chord::new(C).alt().augmented(), Chord::new(C).alt());
+        assert_eq!(Chord::new(C).alt().flat5(), Chord::new(C).alt());
+        assert_eq!(Chord::new(C).seven().augmented().alt(), Chord::new(C).alt());
+        assert_eq!(Chord::new(C).seven().flat5().alt().known_chord(), KnownChord::Altered);
+    }
+
     #[test]
     fn test_chords() {
         // Basic.
@@ -1433,6 +2802,643 @@ mod tests {
         assert_eq!(C.into_chord().flat5().aug().chord(), vec![C, E, GSharp]);
     }
 
+    #[test]
+    fn test_stacked_alterations() {
+        let cases = [
+            ("Cmaj7♯5", "C+(maj7)", vec![C, E, GSharp, B]),
+            ("C+maj7", "C+(maj7)", vec![C, E, GSharp, B]),
+            ("Cmaj7(#5)", "C+(maj7)", vec![C, E, GSharp, B]),
+            ("Cmaj7b5", "Cmaj7(♭5)", vec![C, E, GFlat, B]),
+            ("Cm7♭5", "Cm7(♭5)", vec![C, EFlat, GFlat, BFlat]),
+            ("Cm7#5", "Cm7(♯5)", vec![C, EFlat, GSharp, BFlat]),
+            ("C7♯5♭9", "C+7(♭9)", vec![C, E, GSharp, BFlat, DFlatFive]),
+            ("C7#5#9", "C+7(♯9)", vec![C, E, GSharp, BFlat, DSharpFive]),
+            ("C7b5b9", "C7(♭5)(♭9)", vec![C, E, GFlat, BFlat, DFlatFive]),
+            ("C7b9b5", "C7(♭5)(♭9)", vec![C, E, GFlat, BFlat, DFlatFive]),
+            ("C7b5#9", "C7(♭5)(♯9)", vec![C, E, GFlat, BFlat, DSharpFive]),
+            ("C7b5#11", "C7(♭5)(♯11)", vec![C, E, GFlat, BFlat, FSharpFive]),
+            ("Cmaj7#5b9", "C+(maj7)(♭9)", vec![C, E, GSharp, B, DFlatFive]),
+            ("Cmaj9#5", "C+(maj9)", vec![C, E, GSharp, B, DFive]),
+        ];
+
+        for (symbol, name, tones) in cases {
+            let chord = Chord::parse(symbol).unwrap();
+
+            assert_eq!(chord.name(), name, "{symbol}");
+            assert_eq!(chord.chord(), tones, "{symbol}");
+            assert_eq!(Chord::parse(&chord.name()).unwrap(), chord, "{symbol}");
+        }
+    }
+
+    #[test]
+    fn test_extension_order() {
+        let cases = [
+            (
+                vec!["C13(♯11)", "C13♯11", "C(♯11)13", "C7(♯11)(13)", "C13(♯11)(add9)"],
+                "C13(♯11)",
+                vec![C, E, G, BFlat, DFive, FSharpFive, AFive],
+            ),
+            (vec!["C13", "C7(13)", "C13(add9)(add11)", "C9 13", "C(add13)13"], "C13", vec![C, E, G, BFlat, DFive, FFive, AFive]),
+            (vec!["C9(♭5)", "C(♭5)9", "C7(♭5)9"], "C9(♭5)", vec![C, E, GFlat, BFlat, DFive]),
+            (vec!["Cm11", "Cm(add9)11", "Cm11(add11)"], "Cm11", vec![C, EFlat, G, BFlat, DFive, FFive]),
+        ];
+
+        for (symbols, name, tones) in cases {
+            for symbol in symbols {
+                let chord = Chord::parse(symbol).unwrap();
+
+                assert_eq!(chord.name(), name, "{symbol}");
+                assert_eq!(chord.chord(), tones, "{symbol}");
+            }
+        }
+
+        // Altered ninths replace the natural ninth implied by the dominant degree.
+        assert_eq!(Chord::parse("C13♭9").unwrap().chord(), vec![C, E, G, BFlat, DFlatFive, FFive, AFive]);
+    }
+
+    #[test]
+    fn test_closest_named_chord() {
+        assert_eq!(Chord::new(C).similarity(&Chord::new(C)), 1.0);
+        assert_eq!(Chord::new(C).similarity(&Chord::new(C).with_slash(E)), 1.0);
+        assert_eq!(Chord::new(C).similarity(&Chord::new(C).seven()), 0.75);
+        assert_eq!(Chord::new(C).similarity(&Chord::new(FSharp)), 0.0);
+
+        // Standard chords are their own closest chord.
+        assert_eq!(Chord::new(C).minor().seven().closest_named_chord(), (Chord::new(C).minor().seven(), 1.0));
+
+        // A non-standard tone set (C E G B A♭) is closest to Cmaj7 (although C E G A B is exactly Am9).
+        let (chord, score) = Chord::new(C).major7().flat13().closest_named_chord();
+        assert_eq!(chord, Chord::new(C).major7());
+        assert_eq!(score, 0.8);
+        assert_eq!(Chord::new(C).major7().add6().closest_named_chord().0.name(), "Am9");
+
+        // C E♭ F G B♭ is only missing the D from Cm11.
+        let (chord, score) = Chord::new(C).minor().seven().add4().closest_named_chord();
+        assert_eq!(chord.name(), "Cm11");
+        assert_eq!(score, 5.0 / 6.0);
+    }
+
+    #[test]
+    fn test_from_roman_numeral() {
+        let names = |numerals: &[&str], key: Note, mode: ScaleKind| numerals.iter().map(|n| Chord::from_roman_numeral(n, key, mode).unwrap().name()).collect::<Vec<_>>();
+
+        // Diatonic triads.
+        assert_eq!(names(&["I", "ii", "iii", "IV", "V", "vi", "vii°"], C, ScaleKind::Major), vec!["C", "Dm", "Em", "F", "G", "Am", "Bdim"]);
+        assert_eq!(names(&["i", "iio", "III", "iv", "v", "VI", "VII"], A, ScaleKind::Minor), vec!["Am", "Bdim", "C", "Dm", "Em", "F", "G"]);
+        assert_eq!(names(&["I", "IV", "V"], EFlat, ScaleKind::Major), vec!["E♭", "A♭", "B♭"]);
+
+        // Sevenths, and other suffixes.
+        assert_eq!(Chord::from_roman_numeral("V7", C, ScaleKind::Major).unwrap(), Chord::new(G).seven());
+        assert_eq!(
+            names(&["Imaj7", "ii7", "viiø7", "viiø", "Vsus4"], C, ScaleKind::Major),
+            vec!["Cmaj7", "Dm7", "Bm7(♭5)", "Bm7(♭5)", "G(sus4)"]
+        );
+
+        // Borrowed chords.
+        assert_eq!(Chord::from_roman_numeral("♭VI", C, ScaleKind::Major).unwrap(), Chord::new(AFlat));
+        assert_eq!(names(&["bVII", "bIII", "iv", "#iv°"], C, ScaleKind::Major), vec!["B♭", "E♭", "Fm", "F♯dim"]);
+
+        // Secondary dominants.
+        assert_eq!(names(&["V7/V", "V7/ii", "viio7/V", "V/V/V"], C, ScaleKind::Major), vec!["D7", "A7", "F♯dim", "A"]);
+
+        // The chord is built in the octave of the key.
+        assert_eq!(Chord::from_roman_numeral("IV", CFive, ScaleKind::Major).unwrap().root(), FFive);
+
+        assert!(Chord::from_roman_numeral("Vi", C, ScaleKind::Major).is_err());
+        assert!(Chord::from_roman_numeral("VIII", C, ScaleKind::Major).is_err());
+        assert!(Chord::from_roman_numeral("", C, ScaleKind::Major).is_err());
+    }
+
+    #[test]
+    fn test_add_chords() {
+        for (symbol, name, notes) in [
+            ("Cadd9", "C(add9)", vec![C, E, G, DFive]),
+            ("Cadd11", "C(add11)", vec![C, E, G, FFive]),
+            ("Cadd13", "C(add13)", vec![C, E, G, AFive]),
+            ("Cmadd9", "Cm(add9)", vec![C, EFlat, G, DFive]),
+        ] {
+            let chord = Chord::parse(symbol).unwrap();
+
+            // The added tone does not imply a seventh (or any of the intervening degrees).
+            assert!(chord.modifiers().iter().all(|m| !m.is_dominant()), "{symbol}");
+            assert_eq!(chord.chord(), notes, "{symbol}");
+            assert_eq!(chord.name(), name);
+
+            // Round trip through the name, and back from the notes.
+            assert_eq!(Chord::parse(&chord.name()).unwrap(), chord);
+            assert_eq!(Chord::try_from_notes(&notes).unwrap()[0], chord);
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let chords = [
+            Chord::new(C),
+            Chord::new(C).minor().seven().flat5(),
+            Chord::new(FSharp).seven().sharp9().add13().with_slash(AFlat).with_octave(Octave::Two),
+            Chord::new(D).major7().with_inversion(2).with_crunchy(true).with_bass_octave(Octave::One),
+        ];
+
+        for chord in chords {
+            let json = serde_json::to_string(&chord).unwrap();
+
+            assert_eq!(serde_json::from_str::<Chord>(&json).unwrap(), chord, "{json}");
+        }
+
+        let note = serde_json::to_string(&CSharpFive).unwrap();
+        assert_eq!(serde_json::from_str::<Note>(&note).unwrap(), CSharpFive);
+
+        let interval = serde_json::to_string(&Interval::MinorThirteenth).unwrap();
+        assert_eq!(serde_json::from_str::<Interval>(&interval).unwrap(), Interval::MinorThirteenth);
+    }
+
+    #[test]
+    fn test_nashville() {
+        assert_eq!(Chord::parse_nashville("4", G).unwrap(), Chord::new(CFive));
+        assert_eq!(Chord::new(CFive).to_nashville(G), "4");
+
+        assert_eq!(Chord::parse_nashville("5-", C).unwrap().name(), "Gm");
+        assert_eq!(Chord::parse_nashville("2m7", C).unwrap().name(), "Dm7");
+        assert_eq!(Chord::parse_nashville("7°", C).unwrap().name(), "Bdim");
+        assert_eq!(Chord::parse_nashville("3+", C).unwrap().name(), "E+");
+        assert_eq!(Chord::parse_nashville("b7", C).unwrap().name(), "B♭");
+        assert_eq!(Chord::parse_nashville("1/3", C).unwrap().name(), "C/E");
+        assert_eq!(Chord::parse_nashville("16/9", C).unwrap(), Chord::parse("C6/9").unwrap());
+
+        // Numbers round-trip.
+        for number in ["1", "4", "5-", "2-7", "7°", "3+", "b7", "5maj7", "#4-7(♭5)", "1/3", "57/b7"] {
+            assert_eq!(Chord::parse_nashville(number, D).unwrap().to_nashville(D), number);
+        }
+
+        assert!(Chord::parse_nashville("8", C).is_err());
+        assert!(Chord::parse_nashville("1/", C).is_err());
+    }
+
+    #[test]
+    fn test_relative_to() {
+        assert_eq!(Chord::new(G).seven().relative_to(C), "V7");
+        assert_eq!(Chord::new(A).minor().relative_to(C), "vi");
+        assert_eq!(Chord::parse("Dm7").unwrap().relative_to(C), "ii7");
+        assert_eq!(Chord::parse("Cmaj7").unwrap().relative_to(C), "Imaj7");
+        assert_eq!(Chord::parse("Bm7(♭5)").unwrap().relative_to(C), "viiø7");
+        assert_eq!(Chord::parse("D7").unwrap().relative_to(C), "V7/V");
+        assert_eq!(Chord::parse("C7").unwrap().relative_to(C), "V7/IV");
+        assert_eq!(Chord::parse("E").unwrap().relative_to(C), "V/vi");
+        assert_eq!(Chord::parse("B♭").unwrap().relative_to(C), "bVII");
+        assert_eq!(Chord::parse("Fm").unwrap().relative_to(C), "iv");
+        assert_eq!(Chord::parse("F♯").unwrap().relative_to(C), "#IV");
+        assert_eq!(Chord::parse("C/E").unwrap().relative_to(C), "I");
+
+        // Labels round-trip.
+        for name in ["G", "Am7", "Bdim", "E+", "Fmaj7", "D7", "A7(♭9)", "E♭", "A♭maj7", "Dm7(♭5)"] {
+            let chord = Chord::parse(name).unwrap();
+
+            assert_eq!(Chord::parse_roman(&chord.relative_to(C), C).unwrap().name(), chord.name());
+        }
+    }
+
+    #[test]
+    fn test_parse_roman() {
+        let progression = ["I", "vi", "ii", "V7"].iter().map(|n| Chord::parse_roman(n, C).unwrap()).collect::<Vec<_>>();
+
+        assert_eq!(progression, vec![Chord::new(C), Chord::new(A).minor(), Chord::new(D).minor(), Chord::new(G).seven()]);
+
+        assert_eq!(Chord::parse_roman("bVII7", C).unwrap().name(), "B♭7");
+        assert_eq!(Chord::parse_roman("bIII", C).unwrap().name(), "E♭");
+        assert_eq!(Chord::parse_roman("III+", C).unwrap().name(), "E+");
+        assert_eq!(Chord::parse_roman("vii°7", G).unwrap().name(), "F♯dim");
+        assert_eq!(Chord::parse_roman("V/V", C).unwrap().name(), "D");
+        assert_eq!(Chord::parse_roman("V7/vi", C).unwrap().name(), "E7");
+        assert_eq!(Chord::parse_roman("V7/V/V", F).unwrap().name(), "D7");
+
+        assert!(Chord::parse_roman("X", C).is_err());
+        assert!(Chord::parse_roman("V/", C).is_err());
+    }
+
+    #[test]
+    fn test_scale_kind() {
+        assert_eq!(Chord::new(C).scale_kind(), ScaleKind::Major);
+        assert_eq!(Chord::new(D).minor().seven().scale_kind(), ScaleKind::Dorian);
+        assert_eq!(Chord::new(G).seven().scale_kind(), ScaleKind::Mixolydian);
+        assert_eq!(Chord::new(B).half_diminished().scale_kind(), ScaleKind::LocrianNatural2);
+        assert_eq!(Chord::new(G).seven().sharp9().scale_kind(), ScaleKind::Altered);
+        assert_eq!(Chord::new(C).augmented().seven().scale_kind(), ScaleKind::WholeTone);
+
+        // The chord's scale is the scale kind built on the root.
+        let chord = Chord::new(EFlat).minor().major7();
+        assert_eq!(chord.scale(), chord.scale_kind().notes(chord.root()));
+
+        // Roman numerals can be read in any (heptatonic) scale.
+        assert_eq!(Chord::from_roman_numeral("IV", D, ScaleKind::Dorian).unwrap().name(), "G");
+        assert!(Chord::from_roman_numeral("vi", C, ScaleKind::MajorPentatonic).is_err());
+    }
+
+    #[test]
+    fn test_bass_octave() {
+        let chord = Chord::new(C).seven().with_slash(E);
+        let lowered = chord.clone().with_bass_octave(Octave::Two);
+
+        assert_eq!(chord.chord(), vec![EThree, C, E, G, BFlat]);
+        assert_eq!(lowered.chord(), vec![ETwo, C, E, G, BFlat]);
+
+        // The bass drops by an octave, and the upper structure stays put.
+        assert_eq!(lowered.chord()[0].frequency(), chord.chord()[0].frequency() / 2.0);
+        assert_eq!(lowered.chord()[1..], chord.chord()[1..]);
+
+        // Without a slash, the lowest chord tone is the bass.
+        assert_eq!(Chord::new(C).with_bass_octave(Octave::Two).chord(), vec![CTwo, E, G]);
+        assert_eq!(Chord::new(C).with_inversion(1).with_bass_octave(Octave::Three).chord(), vec![EThree, G, CFive]);
+
+        // The bass octave is part of the precise name (after the slash, if any), so it survives a round trip.
+        let inverted = Chord::new(C).with_inversion(1).with_bass_octave(Octave::Three).with_octave(Octave::Three);
+
+        assert_eq!(lowered.precise_name(), "C7/E_2");
+        assert_eq!(inverted.precise_name(), "C_3@3^1");
+        assert_eq!(Chord::parse(&lowered.precise_name()).unwrap(), lowered);
+        assert_eq!(Chord::parse(&inverted.precise_name()).unwrap(), inverted);
+        assert_eq!(Chord::parse("C7/E_2").unwrap().chord(), vec![ETwo, C, E, G, BFlat]);
+    }
+
+    #[test]
+    fn test_intervals() {
+        assert_eq!(Chord::new(C).intervals(), vec![Interval::PerfectUnison, Interval::MajorThird, Interval::PerfectFifth]);
+        assert_eq!(
+            Chord::new(C).seven().intervals(),
+            vec![Interval::PerfectUnison, Interval::MajorThird, Interval::PerfectFifth, Interval::MinorSeventh]
+        );
+        assert_eq!(
+            Chord::new(C).seven().flat5().sharp9().intervals(),
+            vec![
+                Interval::PerfectUnison,
+                Interval::MajorThird,
+                Interval::DiminishedFifth,
+                Interval::MinorSeventh,
+                Interval::AugmentedNinth
+            ]
+        );
+
+        // Intervals do not depend on the root, inversion, or slash.
+        assert_eq!(Chord::new(FSharp).seven().intervals(), Chord::new(C).seven().intervals());
+        assert_eq!(Chord::new(C).seven().with_inversion(2).with_slash(D).intervals(), Chord::new(C).seven().intervals());
+    }
+
+    #[test]
+    fn test_render() {
+        let mut sink = MemorySink::new(8_000);
+
+        Chord::new(C).render(&mut sink, Duration::ZERO, Duration::from_secs(1), Duration::ZERO).unwrap();
+
+        assert_eq!(sink.samples().len(), 8_000);
+        assert!(sink.samples().iter().any(|s| *s != 0.0));
+        assert!(sink.samples().iter().all(|s| s.abs() <= 0.6));
+
+        // A chord (with no delay) should be the sum of its rendered tones.
+        let mut expected = vec![0f32; 8_000];
+
+        for note in Chord::new(C).chord() {
+            let mut note_sink = MemorySink::new(8_000);
+            note.render(&mut note_sink, Duration::ZERO, Duration::from_secs(1), Duration::ZERO).unwrap();
+
+            for (e, s) in expected.iter_mut().zip(note_sink.samples()) {
+                *e += s;
+            }
+        }
+
+        assert_eq!(sink.samples(), expected.as_slice());
+
+        // The delay must fit within the length.
+        assert!(Chord::new(C).render(&mut sink, Duration::from_secs(1), Duration::from_secs(1), Duration::ZERO).is_err());
+    }
+
+    #[test]
+    fn test_wav() {
+        for sample_rate in [44_100, 48_000] {
+            let options = RenderOptions {
+                sample_rate,
+                ..RenderOptions::with_fade_in(Duration::from_millis(10))
+            };
+            let wav = Chord::new(C).to_wav_bytes(Duration::from_millis(500), options).unwrap();
+
+            let num_samples = (0.5 * sample_rate as f32).round() as usize;
+
+            assert_eq!(&wav[0..4], b"RIFF");
+            assert_eq!(&wav[8..16], b"WAVEfmt ");
+            assert_eq!(u32::from_le_bytes(wav[24..28].try_into().unwrap()), sample_rate);
+            assert_eq!(u16::from_le_bytes(wav[34..36].try_into().unwrap()), 16);
+            assert_eq!(u32::from_le_bytes(wav[40..44].try_into().unwrap()) as usize, 2 * num_samples);
+            assert_eq!(wav.len(), 44 + 2 * num_samples);
+
+            // The signal matches playback (including the fade in).
+            let mut sink = MemorySink::new(sample_rate);
+            Chord::new(C).render(&mut sink, Duration::ZERO, Duration::from_millis(500), Duration::from_millis(10)).unwrap();
+
+            let samples = wav[44..].chunks(2).map(|b| i16::from_le_bytes([b[0], b[1]])).collect::<Vec<_>>();
+            let expected = sink.samples().iter().map(|s| (s * i16::MAX as f32) as i16).collect::<Vec<_>>();
+
+            assert_eq!(samples, expected);
+            assert_eq!(samples[0], 0);
+        }
+    }
+
+    #[test]
+    fn test_waveforms() {
+        // Only the partials below the Nyquist frequency are used.
+        assert_eq!(Waveform::Sine.partials(100.0, 8_000), vec![(1.0, 1.0)]);
+        assert_eq!(Waveform::Saw.partials(100.0, 8_000).len(), 39);
+        assert_eq!(Waveform::Square.partials(100.0, 8_000).len(), 20);
+        assert!(Waveform::Square.partials(100.0, 8_000).iter().all(|(k, _)| k % 2.0 == 1.0));
+        assert_eq!(Waveform::Triangle.partials(3_000.0, 8_000).len(), 1);
+
+        // The default waveform is the (unchanged) sine rendering.
+        let mut sine = MemorySink::new(8_000);
+        let mut default = MemorySink::new(8_000);
+
+        Chord::new(C).render_with_options(&mut sine, Duration::ZERO, Duration::from_secs(1), RenderOptions::default()).unwrap();
+        Chord::new(C).render(&mut default, Duration::ZERO, Duration::from_secs(1), Duration::ZERO).unwrap();
+
+        assert_eq!(sine.samples(), default.samples());
+
+        // A square wave sits near its peak for most of each period.
+        let mut square = MemorySink::new(44_100);
+        Note::new(NamedPitch::A, Octave::Two)
+            .render_with_options(
+                &mut square,
+                Duration::ZERO,
+                Duration::from_secs(1),
+                RenderOptions {
+                    waveform: Waveform::Square,
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+
+        let near_peak = square.samples().iter().filter(|s| (s.abs() - 0.20).abs() < 0.02).count();
+        assert!(near_peak > 44_100 / 2, "{near_peak}");
+        assert!(square.samples().iter().all(|s| s.abs() <= 0.25));
+
+        // Different waveforms produce different signals.
+        let mut saw = MemorySink::new(8_000);
+        Chord::new(C)
+            .render_with_options(
+                &mut saw,
+                Duration::ZERO,
+                Duration::from_secs(1),
+                RenderOptions {
+                    waveform: Waveform::Saw,
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+
+        assert_ne!(saw.samples(), sine.samples());
+    }
+
+    #[test]
+    fn test_transpose() {
+        let chord = |symbol: &str| Chord::parse(symbol).unwrap();
+
+        assert_eq!(chord("Cm7").transpose(Interval::MinorThird).name(), "E♭m7");
+        assert_eq!(chord("C/E").transpose(Interval::MajorSecond).name(), "D/F♯");
+        assert_eq!(chord("C").transpose(Interval::PerfectFifth).chord(), vec![G, B, DFive]);
+
+        // Roots are respelled sensibly (e.g., not `F𝄪`, `B𝄫`, or `E♯`).
+        assert_eq!(chord("D♯").transpose(Interval::MajorThird).name(), "G");
+        assert_eq!(chord("G♭7").transpose(Interval::MinorThird).name(), "A7");
+        assert_eq!(chord("B").transpose(Interval::AugmentedFourth).name(), "F");
+
+        // The inversion and crunchiness are preserved.
+        let transposed = Chord::new(C).seven().with_inversion(1).with_crunchy(true).transpose(Interval::PerfectFourth);
+
+        assert_eq!(transposed.root(), F);
+        assert_eq!(transposed.inversion(), 1);
+        assert!(transposed.is_crunchy());
+
+        // The new root is kept as given, and the slash moves with it.
+        assert_eq!(chord("Cmaj7/G").transpose_to(EFlat).name(), "E♭maj7/B♭");
+        assert_eq!(chord("C/E").transpose_to(FSharp).name(), "F♯/A♯");
+        assert_eq!(chord("C/E").transpose_to(ESharp).name(), "E♯/A");
+        assert_eq!(chord("C").transpose_to(AThree).chord(), vec![AThree, CSharp, E]);
+    }
+
+    #[test]
+    fn test_quality() {
+        let quality = |symbol: &str| Chord::parse(symbol).unwrap().quality();
+
+        assert_eq!(quality("Dm7"), quality("Gm7"));
+        assert_eq!(quality("Dm7"), Some(ChordQuality::MinorSeventh));
+        assert_eq!(quality("Gm9/B"), Some(ChordQuality::MinorSeventh));
+        assert_eq!(quality("Am7@2^2"), Some(ChordQuality::MinorSeventh));
+
+        assert_eq!(quality("C"), Some(ChordQuality::Major));
+        assert_eq!(quality("Cm"), Some(ChordQuality::Minor));
+        assert_eq!(quality("Cm(♭5)"), Some(ChordQuality::Diminished));
+        assert_eq!(quality("C+"), Some(ChordQuality::Augmented));
+        assert_eq!(quality("Csus2"), Some(ChordQuality::Suspended2));
+        assert_eq!(quality("Csus4"), Some(ChordQuality::Suspended4));
+        assert_eq!(quality("C6"), Some(ChordQuality::MajorSixth));
+        assert_eq!(quality("Cm6"), Some(ChordQuality::MinorSixth));
+        assert_eq!(quality("Cmaj9"), Some(ChordQuality::MajorSeventh));
+        assert_eq!(quality("C13(♯11)"), Some(ChordQuality::DominantSeventh));
+        assert_eq!(quality("C7(♭9)"), Some(ChordQuality::DominantSeventh));
+        assert_eq!(quality("C7sus4"), Some(ChordQuality::SuspendedSeventh));
+        assert_eq!(quality("Cm(maj7)"), Some(ChordQuality::MinorMajorSeventh));
+        assert_eq!(quality("Cm7(♭5)"), Some(ChordQuality::HalfDiminishedSeventh));
+        assert_eq!(quality("Cdim"), Some(ChordQuality::DiminishedSeventh));
+        assert_eq!(quality("C+7"), Some(ChordQuality::AugmentedSeventh));
+        assert_eq!(quality("C+(maj7)"), Some(ChordQuality::AugmentedMajorSeventh));
+
+        // A major triad with a flat five is not one of the standard qualities.
+        assert_eq!(quality("C(♭5)"), None);
+        assert_eq!(quality("C7(♭5)"), Some(ChordQuality::DominantSeventh));
+
+        // Counting the minor seventh chords in a progression.
+        let progression = ["Dm7", "G7", "Cmaj7", "Am7", "Em7"].map(|s| Chord::parse(s).unwrap());
+
+        assert_eq!(progression.iter().filter(|c| c.quality() == Some(ChordQuality::MinorSeventh)).count(), 3);
+    }
+
+    #[test]
+    fn test_simplify_enharmonics() {
+        let chord = |symbol: &str| Chord::parse(symbol).unwrap();
+
+        // The double sharps are respelled, but the root is kept.
+        assert_eq!(chord("D♯+").chord(), vec![DSharp, FDoubleSharp, ADoubleSharp]);
+        assert_eq!(chord("D♯+").simplify_enharmonics().chord(), vec![DSharp, G, B]);
+        assert_eq!(chord("E♯").simplify_enharmonics().chord(), vec![ESharp, A, CFive]);
+        assert_eq!(chord("E♯").simplify_enharmonics().root(), ESharp);
+
+        // Conventional spellings are left alone.
+        assert_eq!(chord("Fm7").simplify_enharmonics(), chord("Fm7"));
+        assert_eq!(chord("D♭").simplify_enharmonics().chord(), vec![DFlat, F, AFlat]);
+
+        // The name reflects the simplification.
+        assert_eq!(chord("G♯/B♯").simplify_enharmonics().name(), "G♯/C");
+        assert_eq!(chord("G♯^1").precise_name(), "G♯^1");
+        assert_eq!(chord("G♯^1").simplify_enharmonics().precise_name(), "G♯^1~");
+        assert_eq!(chord("D♯+").simplify_enharmonics().precise_name(), "D♯+~");
+
+        // The simplification survives a round trip through the precise name.
+        for symbol in ["G♯^1", "D♯+", "G♯/B♯", "E♯m7", "Fm7"] {
+            let simplified = chord(symbol).simplify_enharmonics();
+
+            assert_eq!(chord(&simplified.precise_name()), simplified);
+        }
+
+        assert!(chord("D♯+").simplify_enharmonics().is_enharmonically_equal(&chord("D♯+")));
+    }
+
+    #[test]
+    fn test_common_tones() {
+        assert_eq!(Chord::new(C).common_tones(&Chord::new(A).minor()), vec![C, E]);
+        assert_eq!(Chord::new(C).common_tones(&Chord::new(FSharp)), vec![]);
+
+        // Octaves are ignored.
+        assert_eq!(Chord::new(C).common_tones(&Chord::new(E).minor().with_octave(Octave::Two)), vec![E, G]);
+
+        // Enharmonic spellings match, and the notes are spelled as they appear in `self`.
+        assert_eq!(Chord::new(A).common_tones(&Chord::new(DFlat)), vec![CSharpFive]);
+        assert_eq!(Chord::new(DFlat).common_tones(&Chord::new(A)), vec![DFlat]);
+        assert_eq!(Chord::new(C).seven().common_tones(&Chord::new(C).seven()), Chord::new(C).seven().chord());
+    }
+
+    #[test]
+    fn test_voice_leading_distance() {
+        let chord = |symbol: &str| Chord::parse(symbol).unwrap();
+
+        assert_eq!(chord("C").voice_leading_distance(&chord("C")), 0);
+        assert_eq!(chord("C").voice_leading_distance(&chord("Am")), 2);
+        assert_eq!(chord("C").voice_leading_distance(&chord("Em")), 1);
+        assert_eq!(chord("C").voice_leading_distance(&chord("F")), 3);
+        assert_eq!(chord("C").voice_leading_distance(&chord("F#")), 6);
+        assert!(chord("C").voice_leading_distance(&chord("Am")) < chord("C").voice_leading_distance(&chord("F#")));
+
+        // The distance is symmetric, and independent of octave and voicing.
+        assert_eq!(chord("G7").voice_leading_distance(&chord("C")), chord("C").voice_leading_distance(&chord("G7")));
+        assert_eq!(chord("C@2").voice_leading_distance(&chord("Am")), 2);
+        assert_eq!(chord("C").voice_leading_distance(&chord("C/E")), 0);
+
+        // Chords of different sizes double tones of the smaller chord (e.g., `G7` to `C`: `B` → `C`, `F` → `E`, and `D` → `C`).
+        assert_eq!(chord("G7").voice_leading_distance(&chord("C")), 4);
+    }
+
+    #[test]
+    fn test_arpeggio_onsets() {
+        let ms = Duration::from_millis;
+        let chord = Chord::new(C).seven();
+
+        assert_eq!(chord.arpeggio_onsets(ms(30), ArpeggioDirection::Up), vec![(C, ms(0)), (E, ms(30)), (G, ms(60)), (BFlat, ms(90))]);
+        assert_eq!(chord.arpeggio_onsets(ms(30), ArpeggioDirection::Down), vec![(C, ms(90)), (E, ms(60)), (G, ms(30)), (BFlat, ms(0))]);
+
+        // Without a delay, the chord is struck as a block (in either direction).
+        assert_eq!(
+            chord.arpeggio_onsets(Duration::ZERO, ArpeggioDirection::Down),
+            chord.arpeggio_onsets(Duration::ZERO, ArpeggioDirection::Up)
+        );
+        assert!(chord.arpeggio_onsets(Duration::ZERO, ArpeggioDirection::Down).iter().all(|(_, d)| d.is_zero()));
+    }
+
+    #[test]
+    fn test_envelope() {
+        let sample_rate = 8_000;
+        let length = Duration::from_secs(1);
+
+        // A `fade_in` is just an attack.
+        let mut faded = MemorySink::new(sample_rate);
+        let mut enveloped = MemorySink::new(sample_rate);
+
+        Chord::new(C).render(&mut faded, Duration::ZERO, length, Duration::from_millis(100)).unwrap();
+        Chord::new(C)
+            .render_with_options(
+                &mut enveloped,
+                Duration::ZERO,
+                length,
+                RenderOptions {
+                    envelope: Some(Duration::from_millis(100).into()),
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+
+        assert_eq!(faded.samples(), enveloped.samples());
+
+        // The release rings on past the length.
+        let envelope = Envelope::new(Duration::from_millis(100), Duration::from_millis(100), 0.5, Duration::from_millis(500));
+        let options = RenderOptions {
+            envelope: Some(envelope),
+            sample_rate,
+            ..Default::default()
+        };
+        let wav = Chord::new(C).to_wav_bytes(length, options).unwrap();
+        let samples = wav[44..].chunks(2).map(|b| i16::from_le_bytes([b[0], b[1]]) as f32 / i16::MAX as f32).collect::<Vec<_>>();
+
+        assert_eq!(samples.len(), 12_000);
+
+        // Each 100ms window of the tail is quieter than the last, and the last one is nearly silent.
+        let peaks = samples.chunks(800).map(|w| w.iter().fold(0f32, |max, s| max.max(s.abs()))).collect::<Vec<_>>();
+
+        assert!(peaks[10..].windows(2).all(|w| w[1] < w[0]), "{peaks:?}");
+        assert!(peaks[14] < 0.25 * peaks[9], "{peaks:?}");
+        assert!(samples[11_990..].iter().all(|s| s.abs() < 0.01));
+
+        // The attack ramps up, and the sustain holds at (about) half of the peak.
+        assert_eq!(envelope.level(400.0, 8_000.0, sample_rate), 0.5);
+        assert_eq!(envelope.level(1_200.0, 8_000.0, sample_rate), 0.75);
+        assert_eq!(envelope.level(4_000.0, 8_000.0, sample_rate), 0.5);
+        assert_eq!(envelope.level(10_000.0, 8_000.0, sample_rate), 0.25);
+        assert_eq!(envelope.level(12_000.0, 8_000.0, sample_rate), 0.0);
+    }
+
+    #[test]
+    fn test_extension_degrees() {
+        assert_eq!(Chord::parse("C9").unwrap().chord(), vec![C, E, G, BFlat, DFive]);
+        assert_eq!(Chord::parse("Cm9").unwrap().chord(), vec![C, EFlat, G, BFlat, DFive]);
+        assert_eq!(Chord::parse("Cmaj9").unwrap().chord(), vec![C, E, G, B, DFive]);
+        assert_eq!(Chord::parse("C11").unwrap().chord(), vec![C, E, G, BFlat, DFive, FFive]);
+        assert_eq!(Chord::parse("Cm11").unwrap().chord(), vec![C, EFlat, G, BFlat, DFive, FFive]);
+        assert_eq!(Chord::parse("Cmaj11").unwrap().chord(), vec![C, E, G, B, DFive, FFive]);
+        assert_eq!(Chord::parse("C13").unwrap().chord(), vec![C, E, G, BFlat, DFive, FFive, AFive]);
+        assert_eq!(Chord::parse("Cm13").unwrap().chord(), vec![C, EFlat, G, BFlat, DFive, FFive, AFive]);
+        assert_eq!(Chord::parse("Cmaj13").unwrap().chord(), vec![C, E, G, B, DFive, FFive, AFive]);
+        assert_eq!(Chord::parse("Cmmaj9").unwrap().chord(), vec![C, EFlat, G, B, DFive]);
+
+        assert_eq!(Chord::parse("Cmaj9").unwrap().name(), "Cmaj9");
+        assert_eq!(Chord::parse("Cmaj13").unwrap().name(), "Cmaj13");
+        assert_eq!(Chord::parse("Cmmaj9").unwrap().name(), "Cm(maj9)");
+        assert_eq!(Chord::parse("Cmaj7").unwrap().name(), "Cmaj7");
+    }
+
+    #[test]
+    fn test_slash_round_trip() {
+        // All named pitches from double flats to double sharps.
+        let basses = (0..35).map(|k| Note::new(NamedPitch::FDoubleFlat + k, Octave::Three)).collect::<Vec<_>>();
+        let mut basses = basses.iter().cycle();
+
+        for mod_set in known_modifier_sets() {
+            for mod_set2 in one_off_modifier_sets() {
+                for ext_set in likely_extension_sets() {
+                    for root in [C, FSharp, BFlatThree] {
+                        let chord = Chord::new(root)
+                            .with_modifiers(mod_set)
+                            .with_modifiers(mod_set2)
+                            .with_extensions(ext_set)
+                            .with_slash(*basses.next().unwrap());
+
+                        // Parsing drops redundant extensions (e.g., an `add9` on a `9` chord), which do not change the chord tones.
+                        let mut expected = chord.clone();
+                        expected.remove_redundancies();
+
+                        assert_eq!(Chord::parse(&chord.precise_name()).unwrap(), expected);
+                    }
+                }
+            }
+        }
+
+        assert_eq!(Chord::new(C).minor().major7().with_slash(DDoubleFlatThree).precise_name(), "Cm(maj7)/D𝄫");
+        assert_eq!(Chord::new(C).with_slash(EThree), Chord::new(C).with_slash(EFive));
+        assert_eq!(Chord::parse("Cm(maj7)/D𝄫").unwrap().chord(), vec![DDoubleFlatThree, C, EFlat, G, B]);
+    }
+
     #[test]
     fn test_parse() {
         assert_eq!(Chord::parse("C").unwrap().chord(), vec![C, E, G]);
@@ -1443,11 +3449,20 @@ mod tests {
         assert_eq!(Chord::parse("C7b9").unwrap().chord(), vec![C, E, G, BFlat, DFlatFive]);
         assert_eq!(Chord::parse("C7b9#11").unwrap().chord(), vec![C, E, G, BFlat, DFlatFive, FSharpFive]);
         assert_eq!(Chord::parse("C(add6)").unwrap().chord(), vec![C, E, G, A]);
+        assert_eq!(Chord::parse("C6").unwrap().chord(), vec![C, E, G, A]);
+        assert_eq!(Chord::parse("Cm6").unwrap().chord(), vec![C, EFlat, G, A]);
+        assert_eq!(Chord::parse("C6/9").unwrap().chord(), vec![C, E, G, A, DFive]);
+        assert_eq!(Chord::parse("Cm6/9").unwrap().chord(), vec![C, EFlat, G, A, DFive]);
+        assert_eq!(Chord::parse("C6/E").unwrap().chord(), vec![EThree, C, E, G, A]);
         assert_eq!(Chord::parse("Em(#5)").unwrap().chord(), vec![E, G, BSharp]);
         assert_eq!(Chord::parse("D+11").unwrap().chord(), vec![D, FSharp, ASharp, CFive, EFive, GFive]);
         assert_eq!(Chord::parse("Dm13b5").unwrap().chord(), vec![D, F, AFlat, CFive, EFive, GFive, BFive]);
         assert_eq!(Chord::parse("Dsus2").unwrap().chord(), vec![D, E, A]);
         assert_eq!(Chord::parse("Dsus4").unwrap().chord(), vec![D, G, A]);
+        assert_eq!(Chord::parse("Csus2").unwrap().chord(), vec![C, D, G]);
+        assert_eq!(Chord::parse("Csus4").unwrap().chord(), vec![C, F, G]);
+        assert_eq!(Chord::parse("Csus").unwrap().chord(), vec![C, F, G]);
+        assert_eq!(Chord::parse("C7sus").unwrap().chord(), vec![C, F, G, BFlat]);
         assert_eq!(Chord::parse("Dadd2").unwrap().chord(), vec![D, E, FSharp, A]);
         assert_eq!(Chord::parse("Dadd4").unwrap().chord(), vec![D, FSharp, G, A]);
         assert_eq!(Chord::parse("Dadd9").unwrap().chord(), vec![D, FSharp, A, EFive]);
@@ -1459,6 +3474,134 @@ mod tests {
         assert_eq!(Chord::parse("D(#13)").unwrap().chord(), vec![D, FSharp, A, BSharpFive]);
     }
 
+    #[test]
+    fn test_from_str() {
+        assert_eq!("Cmaj7".parse::<Chord>().unwrap(), Chord::new(C).major7());
+        assert_eq!(Chord::try_from("Dm7").unwrap(), Chord::new(D).minor().seven());
+        assert_eq!(Chord::try_from("G7/B".to_owned()).unwrap(), Chord::new(G).seven().with_slash(B));
+        assert!("Cmja7".parse::<Chord>().is_err());
+        assert!(Chord::try_from("X").is_err());
+    }
+
+    #[test]
+    fn test_parse_normalized() {
+        assert_eq!(Chord::parse("cmaj7").unwrap(), Chord::parse("Cmaj7").unwrap());
+        assert_eq!(Chord::parse("CMAJ7").unwrap(), Chord::parse("Cmaj7").unwrap());
+        assert_eq!(Chord::parse("CM7").unwrap(), Chord::parse("Cmaj7").unwrap());
+        assert_eq!(Chord::parse("CmM7").unwrap(), Chord::parse("Cm(maj7)").unwrap());
+        assert_eq!(Chord::parse("CDIM").unwrap(), Chord::parse("Cdim").unwrap());
+
+        // A lone `M` is major, and a lone `m` is minor.
+        assert_eq!(Chord::parse("CM").unwrap(), Chord::new(C));
+        assert_eq!(Chord::parse("CM/E").unwrap(), Chord::parse("C/E").unwrap());
+        assert_eq!(Chord::parse("Cm").unwrap(), Chord::new(C).minor());
+        assert_eq!(Chord::parse("c#m").unwrap(), Chord::parse("C♯m").unwrap());
+        assert_eq!(Chord::parse("ebm7/gb").unwrap(), Chord::parse("E♭m7/G♭").unwrap());
+
+        // A lower-case `b` directly after the note letter is a flat, but the quality is otherwise case-insensitive.
+        assert_eq!(Chord::parse("bbmaj7").unwrap().name(), "B♭maj7");
+        assert_eq!(Chord::parse("BMAJ7").unwrap().name(), "Bmaj7");
+    }
+
+    #[test]
+    fn test_chord_cache() {
+        let chord = Chord::new(C).seven();
+
+        assert_eq!(chord.chord(), vec![C, E, G, BFlat]);
+        assert_eq!(chord.chord(), vec![C, E, G, BFlat]);
+        assert_eq!(chord.scale(), Chord::new(C).seven().scale());
+
+        // Changing the chord (or a clone of it) never sees the cached notes.
+        assert_eq!(chord.clone().with_inversion(1).chord(), vec![E, G, BFlat, CFive]);
+        assert_eq!(chord.clone().with_slash(G).chord(), vec![GThree, C, E, G, BFlat]);
+        assert_eq!(chord.clone().with_octave(Octave::Five).chord(), vec![CFive, EFive, GFive, BFlatFive]);
+        assert_eq!(chord.clone().minor().chord(), vec![C, EFlat, G, BFlat]);
+        assert_eq!(chord.transpose(Interval::MajorSecond).chord(), vec![D, FSharp, A, CFive]);
+        assert_eq!(chord.transpose_to(F).scale(), Chord::new(F).seven().scale());
+
+        // The cache does not affect equality.
+        assert_eq!(chord, Chord::new(C).seven());
+    }
+
+    #[test]
+    fn test_is_enharmonically_equal() {
+        let c_sharp = Chord::parse("C#").unwrap();
+        let d_flat = Chord::parse("Db").unwrap();
+
+        assert_ne!(c_sharp, d_flat);
+        assert!(c_sharp.is_enharmonically_equal(&d_flat));
+        assert!(Chord::parse("F#m7").unwrap().is_enharmonically_equal(&Chord::parse("Gbm7").unwrap()));
+        assert!(Chord::parse("C#/E#").unwrap().is_enharmonically_equal(&Chord::parse("Db/F").unwrap()));
+
+        // The bass matters.
+        assert!(!c_sharp.is_enharmonically_equal(&Chord::parse("Db/F").unwrap()));
+        assert!(!c_sharp.is_enharmonically_equal(&Chord::parse("D").unwrap()));
+    }
+
+    #[test]
+    fn test_chord_ascending() {
+        let is_ascending = |notes: &[Note]| notes.windows(2).all(|w| w[0].frequency() < w[1].frequency());
+
+        let chord = Chord::parse("C13").unwrap();
+        assert_eq!(chord.chord_ascending(), chord.chord());
+        assert!(is_ascending(&chord.chord_ascending()));
+        assert_eq!(chord.chord_ascending().last().unwrap(), &AFive);
+
+        // Crunchy chords bring their extensions down, so enharmonically equal tones are bumped back up.
+        let chord = Chord::parse("C7b5#11!").unwrap();
+        assert!(!is_ascending(&chord.chord()));
+        assert!(is_ascending(&chord.chord_ascending()));
+        assert_eq!(chord.chord_ascending().len(), chord.chord().len());
+    }
+
+    #[test]
+    fn test_chord_builder() {
+        assert_eq!(ChordBuilder::new().build(), Chord::parse("C").unwrap());
+        assert_eq!(ChordBuilder::new().root(D).build().chord(), vec![D, FSharp, A]);
+
+        let chord = ChordBuilder::new()
+            .root(BFlat)
+            .modifier(Modifier::Minor)
+            .modifier(Modifier::Dominant(Degree::Nine))
+            .extension(Extension::Add13)
+            .slash(AFlat)
+            .build();
+        assert_eq!(chord, Chord::parse("Bbm9(add13)/Ab").unwrap());
+
+        let chord = ChordBuilder::new().modifier(Modifier::Major7).inversion(1).crunchy(true).build();
+        assert_eq!(chord, Chord::new(C).major7().with_inversion(1).with_crunchy(true));
+    }
+
+    #[test]
+    fn test_suggest() {
+        let suggestions = Chord::suggest("Cm");
+
+        for symbol in ["Cm", "Cm7", "Cmaj7", "Cm7b5", "Cm9"] {
+            assert!(suggestions.contains(&symbol.to_owned()), "{symbol}");
+        }
+
+        assert_eq!(suggestions[0], "Cm");
+        assert!(suggestions.iter().all(|s| s.starts_with("Cm") && Chord::parse(s).is_ok()));
+        assert_eq!(Chord::suggest("f#maj")[0], "F#maj7");
+        assert!(Chord::suggest("Cx").is_empty());
+        assert!(Chord::suggest("").is_empty());
+    }
+
+    #[test]
+    fn test_parse_with_suggestions() {
+        assert_eq!(Chord::parse_with_suggestions("Cmaj7").unwrap().chord(), vec![C, E, G, B]);
+
+        let error = Chord::parse_with_suggestions("Cmja7").unwrap_err();
+        assert_eq!(error.suggestions.first().unwrap(), "Cmaj7");
+
+        let error = Chord::parse_with_suggestions("bbsus5").unwrap_err();
+        assert_eq!(error.suggestions.first().unwrap(), "Bbsus4");
+
+        for symbol in crate::core::parser::KNOWN_CHORD_SYMBOLS {
+            assert!(Chord::parse(&format!("C{}", symbol)).is_ok(), "`C{}` should parse", symbol);
+        }
+    }
+
     #[test]
     fn test_guess() {
         assert_eq!(
@@ -1477,6 +3620,121 @@ mod tests {
         assert_eq!(Chord::try_from_notes(&[C, EFlat, GFlat, A]).unwrap().first().unwrap().chord(), Chord::parse("Cdim").unwrap().chord());
     }
 
+    #[test]
+    fn test_guess_detecting_inversion() {
+        // Inversions are detected from the lowest note, and shown with their bass note.
+        let candidate = Chord::try_from_notes_detecting_inversion(&[E, G, CFive]).unwrap().into_iter().next().unwrap();
+
+        assert_eq!(candidate.inversion(), 1);
+        assert_eq!(candidate.precise_name(), "C^1");
+        assert_eq!(candidate.bass_name(), "C/E");
+        assert_eq!(Chord::parse(&candidate.precise_name()).unwrap(), candidate);
+        assert_eq!(Chord::try_from_notes_detecting_inversion(&[G, CFive, EFive]).unwrap().first().unwrap().bass_name(), "C/G");
+
+        // Root position chords, and explicit slashes, are named as usual.
+        assert_eq!(Chord::new(C).bass_name(), "C");
+        assert_eq!(Chord::new(C).with_slash(E).with_inversion(1).bass_name(), "C/E");
+    }
+
+    #[test]
+    fn test_guess_from_notes_str() {
+        let guess = |notes: &str| Chord::try_from_notes_str(notes).unwrap().first().unwrap().name();
+
+        assert_eq!(guess("C E G"), "C");
+        assert_eq!(guess("C, E, G"), "C");
+        assert_eq!(guess("C-E-G"), "C");
+        assert_eq!(guess("D,F  A-C5"), "Dm7");
+        assert!(Chord::try_from_notes_str("E3 / G3 / C4").unwrap().iter().any(|c| c.bass_name() == "C/E"));
+        assert!(Chord::try_from_notes_str("C, E").is_err());
+        assert!(Chord::try_from_notes_str("C, E, X").is_err());
+    }
+
+    #[test]
+    fn test_guess_preserving_bass_octave() {
+        let candidate = Chord::try_from_notes_preserving_bass_octave(&[CThree, GThree, EFour]).unwrap().into_iter().next().unwrap();
+
+        assert_eq!(candidate.precise_name(), "C@3");
+        assert_eq!(candidate.chord().first().unwrap().frequency(), CThree.frequency());
+
+        let candidate = Chord::try_from_notes_preserving_bass_octave(&[EThree, CFour, GFive])
+            .unwrap()
+            .into_iter()
+            .find(|c| c.root().named_pitch() == NamedPitch::C)
+            .unwrap();
+
+        assert_eq!(candidate.precise_name(), "C@3^1");
+        assert_eq!(candidate.chord(), vec![EThree, GThree, CFour]);
+        assert_eq!(candidate.chord().first().unwrap().frequency(), EThree.frequency());
+
+        assert!(Chord::try_from_notes_preserving_bass_octave(&[]).is_err());
+        assert!(Chord::try_from_notes_preserving_bass_octave(&[CThree, CFour, EFour]).is_err());
+    }
+
+    #[test]
+    fn test_guess_with_confidence() {
+        let candidates = Chord::try_from_notes_with_confidence(&[C, E, G]).unwrap();
+
+        assert_eq!(candidates[0].0.name(), "C");
+        assert_eq!(candidates[0].1, 1.0);
+        assert!(candidates.windows(2).all(|w| w[0].1 >= w[1].1));
+        assert!(candidates.iter().all(|(_, score)| *score >= 0.5 * 0.9));
+
+        // The threshold applies before the bass penalty (e.g., `Em` shares half of the pitch classes).
+        assert_eq!(candidates.iter().find(|(c, _)| c.name() == "Em").unwrap().1, 0.45);
+
+        // Extra notes are penalized.
+        let candidates = Chord::try_from_notes_with_confidence(&[C, E, G, B]).unwrap();
+
+        assert_eq!(candidates[0].0.name(), "Cmaj7");
+        assert_eq!(candidates.iter().find(|(c, _)| c.name() == "C").unwrap().1, 0.75);
+
+        // Missing notes are penalized.
+        let candidates = Chord::try_from_notes_with_confidence(&[C, E, BFlat]).unwrap();
+
+        assert_eq!(candidates.iter().find(|(c, _)| c.name() == "C7").unwrap().1, 0.75);
+
+        // A chord that is not voiced from the bass is penalized, but the matching inversion is not.
+        let candidates = Chord::try_from_notes_with_confidence(&[E, G, CFive]).unwrap();
+
+        assert_eq!(candidates.iter().find(|(c, _)| c.name() == "C").unwrap().1, 0.9);
+        assert_eq!(candidates[0].1, 1.0);
+        assert!(candidates.iter().any(|(c, score)| c.root().pitch() == Pitch::C && c.chord()[0].pitch() == Pitch::E && *score == 1.0));
+
+        assert!(Chord::try_from_notes_with_confidence(&[]).is_err());
+    }
+
+    #[test]
+    fn test_guess_from_pitch_classes() {
+        let candidates = Chord::try_from_pitch_classes(&[Pitch::G, Pitch::C, Pitch::E]).unwrap();
+
+        assert_eq!(candidates[0].name(), "C");
+        assert_eq!(candidates[0].root().octave(), Octave::default());
+
+        // Any of the pitch classes can be the root.
+        let candidates = Chord::try_from_pitch_classes(&[Pitch::C, Pitch::E, Pitch::A]).unwrap();
+        let names = candidates.iter().map(|c| c.name()).collect::<Vec<_>>();
+
+        assert!(names.contains(&"Am".to_owned()));
+        assert_eq!(names.iter().collect::<HashSet<_>>().len(), names.len());
+
+        let candidates = Chord::try_from_pitch_classes(&[Pitch::G, Pitch::B, Pitch::D, Pitch::F]).unwrap();
+        assert_eq!(candidates[0].name(), "G7");
+
+        assert!(Chord::try_from_pitch_classes(&[Pitch::C, Pitch::E, Pitch::C]).is_err());
+    }
+
+    #[test]
+    fn test_walking_bass_notes() {
+        let line = Chord::new(C).seven().walking_bass_notes(8);
+
+        assert_eq!(line.len(), 8);
+        assert_eq!(line[0], CTwo);
+        assert_eq!(line, vec![CTwo, ETwo, GTwo, BOne, CTwo, BFlatOne, GOne, DFlatTwo]);
+
+        assert_eq!(Chord::new(FSharp).minor().walking_bass_notes(3), vec![FSharpTwo, ATwo, CSharpThree]);
+        assert_eq!(Chord::new(C).walking_bass_notes(0), vec![]);
+    }
+
     #[test]
     #[should_panic(expected = "Must have at least three notes to guess a chord.")]
     fn test_chord_from_notes_failure() {
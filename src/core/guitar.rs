@@ -0,0 +1,231 @@
+//! A module for finding guitar fretboard voicings of a [`Chord`].
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{
+    base::{HasName, Res},
+    chord::{Chord, HasChord},
+    note::{ATwo, BThree, DThree, EFour, ETwo, GThree, HasMidiNumber, Note},
+};
+
+// Constants.
+
+/// The standard guitar tuning (`E A D G B E`), from the lowest string to the highest.
+pub const STANDARD_TUNING: [Note; 6] = [ETwo, ATwo, DThree, GThree, BThree, EFour];
+
+/// The highest fret that is considered when finding voicings.
+pub const MAX_FRET: u8 = 15;
+
+/// The maximum number of fingers available to fret a voicing (where a barre at the lowest fret counts as one finger).
+pub const MAX_FINGERS: usize = 4;
+
+// Struct.
+
+/// A guitar voicing, which assigns a fret (or `None`, for a muted string) to each string, from the lowest string to the highest.
+#[derive(PartialEq, Eq, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GuitarVoicing {
+    /// The fret of each string (`0` is an open string, and `None` is a muted string).
+    frets: Vec<Option<u8>>,
+}
+
+// Impls.
+
+impl GuitarVoicing {
+    /// Returns a new voicing with the given frets (from the lowest string to the highest).
+    pub fn new(frets: Vec<Option<u8>>) -> Self {
+        Self { frets }
+    }
+
+    /// Returns the frets (from the lowest string to the highest).
+    pub fn frets(&self) -> &[Option<u8>] {
+        &self.frets
+    }
+
+    /// Returns the position of the voicing, which is the lowest fretted (i.e., non-open) fret, or `0` if every sounded string is open.
+    pub fn position(&self) -> u8 {
+        self.fretted().min().unwrap_or_default()
+    }
+
+    /// Returns the number of frets spanned by the fretted (i.e., non-open) strings.
+    pub fn span(&self) -> u8 {
+        match (self.fretted().min(), self.fretted().max()) {
+            (Some(min), Some(max)) => max - min + 1,
+            _ => 0,
+        }
+    }
+
+    /// Returns the number of fingers needed to fret the voicing, where a barre at the lowest fret counts as one finger.
+    pub fn fingers(&self) -> usize {
+        let position = self.position();
+
+        let barre = self.fretted().any(|f| f == position) as usize;
+        let others = self.fretted().filter(|f| *f != position).count();
+
+        barre + others
+    }
+
+    /// Returns the sounded notes (from the lowest string to the highest) when the voicing is played in the given tuning.
+    pub fn notes(&self, tuning: &[Note]) -> Res<Vec<Note>> {
+        self.frets
+            .iter()
+            .zip(tuning)
+            .filter_map(|(fret, string)| fret.map(|fret| Note::from_midi_number(string.to_midi_number() + fret)))
+            .collect()
+    }
+
+    /// Returns the frets of the strings that are fretted (i.e., neither open nor muted).
+    fn fretted(&self) -> impl Iterator<Item = u8> + '_ {
+        self.frets.iter().flatten().copied().filter(|f| *f > 0)
+    }
+}
+
+impl HasName for GuitarVoicing {
+    /// Returns the conventional name of the voicing (e.g., `x32010`), where muted strings are `x`.
+    ///
+    /// If any fret is above `9`, the frets are separated by dashes (e.g., `x-10-12-12-12-10`).
+    fn name(&self) -> String {
+        let frets = self.frets.iter().map(|f| f.map(|f| f.to_string()).unwrap_or_else(|| "x".to_owned())).collect::<Vec<_>>();
+
+        if self.frets.iter().flatten().any(|f| *f > 9) {
+            frets.join("-")
+        } else {
+            frets.concat()
+        }
+    }
+}
+
+impl Chord {
+    /// Returns the playable voicings of the chord on a fretted instrument with the given tuning (from the lowest string to the highest),
+    /// such as [`STANDARD_TUNING`].
+    ///
+    /// In each voicing, every tone of the chord is sounded, no other pitches are sounded, and the lowest sounded note is the bass of
+    /// the chord (i.e., the root, or the slash / inversion note).  Muted strings may only be the lowest strings, at least three strings
+    /// are sounded, the fretted strings span at most `max_fret_span` frets, and at most [`MAX_FINGERS`] fingers are needed.
+    ///
+    /// The voicings are ranked by lowest position (and then by highest fret, and then by the most sounded strings).
+    pub fn guitar_voicings(&self, tuning: &[Note], max_fret_span: u8) -> Vec<GuitarVoicing> {
+        let notes = self.chord();
+
+        let Some(bass) = notes.first() else {
+            return Vec::new();
+        };
+
+        let bass_class = bass.to_midi_number() % 12;
+        let mut classes = notes.iter().map(|n| n.to_midi_number() % 12).collect::<Vec<_>>();
+        classes.sort();
+        classes.dedup();
+
+        let strings = tuning.iter().map(|n| n.to_midi_number()).collect::<Vec<_>>();
+
+        let mut result = Vec::new();
+        let mut frets = Vec::with_capacity(strings.len());
+
+        find_voicings(&strings, &classes, max_fret_span, &mut frets, &mut result);
+
+        result.retain(|voicing: &GuitarVoicing| {
+            let sounded = voicing.frets.iter().zip(&strings).filter_map(|(fret, string)| fret.map(|f| string + f)).collect::<Vec<_>>();
+
+            let mut sounded_classes = sounded.iter().map(|n| n % 12).collect::<Vec<_>>();
+            sounded_classes.sort();
+            sounded_classes.dedup();
+
+            sounded.len() >= 3.min(strings.len())
+                && sounded_classes == classes
+                && sounded.iter().min().map(|n| n % 12) == Some(bass_class)
+                && voicing.span() <= max_fret_span
+                && voicing.fingers() <= MAX_FINGERS
+        });
+
+        result.sort_by_key(|v| {
+            (
+                v.position(),
+                v.fretted().max().unwrap_or_default(),
+                std::cmp::Reverse(v.frets.iter().flatten().count()),
+                v.frets.clone(),
+            )
+        });
+
+        result
+    }
+}
+
+/// Recursively assigns a fret (or a mute) to each remaining string, pruning any assignment that sounds a pitch outside of the chord,
+/// spans too many frets, or mutes a string above a sounded string.
+fn find_voicings(strings: &[u8], classes: &[u8], max_fret_span: u8, frets: &mut Vec<Option<u8>>, result: &mut Vec<GuitarVoicing>) {
+    let string = frets.len();
+
+    if string == strings.len() {
+        result.push(GuitarVoicing::new(frets.clone()));
+        return;
+    }
+
+    let fretted = frets.iter().flatten().copied().filter(|f| *f > 0);
+    let (min, max) = (fretted.clone().min(), fretted.max());
+
+    // Strings may only be muted below the first sounded string.
+    if frets.iter().all(Option::is_none) {
+        frets.push(None);
+        find_voicings(strings, classes, max_fret_span, frets, result);
+        frets.pop();
+    }
+
+    for fret in 0..=MAX_FRET {
+        if !classes.contains(&((strings[string] + fret) % 12)) {
+            continue;
+        }
+
+        if fret > 0 {
+            let low = min.map(|m| m.min(fret)).unwrap_or(fret);
+            let high = max.map(|m| m.max(fret)).unwrap_or(fret);
+
+            if high - low + 1 > max_fret_span {
+                continue;
+            }
+        }
+
+        frets.push(Some(fret));
+        find_voicings(strings, classes, max_fret_span, frets, result);
+        frets.pop();
+    }
+}
+
+// Tests.
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::base::Parsable;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_guitar_voicings() {
+        let voicings = Chord::parse("C").unwrap().guitar_voicings(&STANDARD_TUNING, 4);
+
+        assert_eq!(voicings[0].name(), "x32010");
+        assert_eq!(voicings[0].position(), 1);
+        assert_eq!(voicings[0].span(), 3);
+        assert_eq!(
+            voicings[0].notes(&STANDARD_TUNING).unwrap().iter().map(|n| n.to_midi_number()).collect::<Vec<_>>(),
+            vec![48, 52, 55, 60, 64]
+        );
+        assert!(voicings.iter().any(|v| v.name() == "x35553"));
+        assert!(voicings.windows(2).all(|w| w[0].position() <= w[1].position()));
+        assert!(voicings.iter().all(|v| v.span() <= 4 && v.fingers() <= MAX_FINGERS));
+
+        let voicings = Chord::parse("G").unwrap().guitar_voicings(&STANDARD_TUNING, 4);
+        assert_eq!(voicings[0].name(), "320003");
+
+        // The bass note of a slash chord is the lowest sounded note.
+        let voicings = Chord::parse("D/F#").unwrap().guitar_voicings(&STANDARD_TUNING, 4);
+        assert_eq!(voicings[0].name(), "200232");
+
+        // Smaller spans are more restrictive.
+        let voicings = Chord::parse("C").unwrap().guitar_voicings(&STANDARD_TUNING, 2);
+        assert!(voicings.iter().all(|v| v.span() <= 2));
+        assert!(!voicings.iter().any(|v| v.name() == "x32010"));
+
+        assert_eq!(GuitarVoicing::new(vec![None, Some(10), Some(12), Some(12), Some(12), Some(10)]).name(), "x-10-12-12-12-10");
+    }
+}
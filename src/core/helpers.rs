@@ -1,5 +1,54 @@
 //! Helper functions.
 
+use core::time::Duration;
+
+#[cfg(not(feature = "std"))]
+use alloc::{vec, vec::Vec};
+
+use crate::core::base::{Envelope, Waveform};
+
+// Traits.
+
+/// The floating point functions that `std` provides, implemented with `libm` (for the `no_std` feature).
+#[cfg(not(feature = "std"))]
+pub(crate) trait Float {
+    /// Returns the base 2 logarithm.
+    fn log2(self) -> Self;
+    /// Returns the base 10 logarithm.
+    fn log10(self) -> Self;
+    /// Raises to a floating point power.
+    fn powf(self, n: Self) -> Self;
+    /// Returns the sine (in radians).
+    fn sin(self) -> Self;
+    /// Rounds to the nearest integer (away from zero at halfway).
+    fn round(self) -> Self;
+}
+
+#[cfg(not(feature = "std"))]
+impl Float for f32 {
+    fn log2(self) -> f32 {
+        libm::log2f(self)
+    }
+
+    fn log10(self) -> f32 {
+        libm::log10f(self)
+    }
+
+    fn powf(self, n: f32) -> f32 {
+        libm::powf(self, n)
+    }
+
+    fn sin(self) -> f32 {
+        libm::sinf(self)
+    }
+
+    fn round(self) -> f32 {
+        libm::roundf(self)
+    }
+}
+
+// Helpers.
+
 /// Converts a frequency to a mel.
 pub fn mel(f: f32) -> f32 {
     2595f32 * (1f32 + f / 700f32).log10()
@@ -9,3 +58,73 @@ pub fn mel(f: f32) -> f32 {
 pub fn inv_mel(m: f32) -> f32 {
     700f32 * (10f32.powf(m / 2595f32) - 1f32)
 }
+
+/// Returns the distance, in cents, from the `reference` frequency to the `frequency` (positive when `frequency` is higher).
+pub fn cents(reference: f32, frequency: f32) -> f32 {
+    1200f32 * (frequency / reference).log2()
+}
+
+/// Renders a set of tones with the given [`Waveform`], shaped by the given [`Envelope`], into a buffer of (mono) samples.
+///
+/// Each tone is a `(frequency, start)` pair, and is the sum of the waveform's partials, scaled by `0.20` (so that a few
+/// simultaneous tones do not clip).  Every tone is released at `length`, so the buffer is extended by the envelope's `release`.
+pub fn render_enveloped_tones(tones: &[(f32, Duration)], length: Duration, envelope: Envelope, sample_rate: u32, waveform: Waveform) -> Vec<f32> {
+    let length_samples = length.as_secs_f32() * sample_rate as f32;
+    let num_samples = ((length + envelope.release).as_secs_f32() * sample_rate as f32).round() as usize;
+    let mut buffer = vec![0f32; num_samples];
+
+    for (frequency, start) in tones {
+        let start_index = (start.as_secs_f32() * sample_rate as f32) as usize;
+        let held = length_samples - start_index as f32;
+        let partials = waveform.partials(*frequency, sample_rate);
+
+        for (k, sample) in buffer.iter_mut().enumerate().skip(start_index) {
+            let n = (k - start_index) as f32;
+            let t = n / sample_rate as f32;
+
+            let fade = envelope.level(n, held, sample_rate);
+            let value = partials
+                .iter()
+                .map(|(harmonic, amplitude)| amplitude * (2f32 * core::f32::consts::PI * harmonic * frequency * t).sin())
+                .sum::<f32>();
+
+            *sample += 0.20 * fade * value;
+        }
+    }
+
+    buffer
+}
+
+/// Encodes (mono) samples in the range `[-1.0, 1.0]` as a 16-bit PCM WAV file.
+///
+/// Samples outside of the range are clipped.
+pub fn encode_wav(samples: &[f32], sample_rate: u32) -> Vec<u8> {
+    let data_size = 2 * samples.len() as u32;
+    let mut wav = Vec::with_capacity(44 + data_size as usize);
+
+    // RIFF header.
+    wav.extend_from_slice(b"RIFF");
+    wav.extend_from_slice(&(36 + data_size).to_le_bytes());
+    wav.extend_from_slice(b"WAVE");
+
+    // Format chunk (PCM, mono, 16-bit).
+    wav.extend_from_slice(b"fmt ");
+    wav.extend_from_slice(&16u32.to_le_bytes());
+    wav.extend_from_slice(&1u16.to_le_bytes());
+    wav.extend_from_slice(&1u16.to_le_bytes());
+    wav.extend_from_slice(&sample_rate.to_le_bytes());
+    wav.extend_from_slice(&(2 * sample_rate).to_le_bytes());
+    wav.extend_from_slice(&2u16.to_le_bytes());
+    wav.extend_from_slice(&16u16.to_le_bytes());
+
+    // Data chunk.
+    wav.extend_from_slice(b"data");
+    wav.extend_from_slice(&data_size.to_le_bytes());
+
+    for sample in samples {
+        let value = (sample.clamp(-1.0, 1.0) * i16::MAX as f32) as i16;
+        wav.extend_from_slice(&value.to_le_bytes());
+    }
+
+    wav
+}
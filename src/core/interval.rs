@@ -1,6 +1,6 @@
 //! A module for working with intervals.
 
-use std::fmt::{Display, Error, Formatter};
+use core::fmt::{Display, Error, Formatter};
 
 #[cfg(feature = "wasm")]
 use wasm_bindgen::prelude::*;
@@ -8,7 +8,13 @@ use wasm_bindgen::prelude::*;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::core::octave::{HasOctave, Octave};
+use crate::core::{
+    base::HasStaticName,
+    octave::{HasOctave, Octave},
+};
+
+#[cfg(feature = "std")]
+use crate::core::base::{Err, Parsable, Res};
 
 // Traits.
 
@@ -30,6 +36,28 @@ pub trait CanReduceFrame {
     fn reduce_frame(self) -> Self;
 }
 
+/// A trait for types that can be classified by their consonance (most likely an interval).
+///
+/// The classification follows common-practice counterpoint, where the interval's spelling matters (e.g., a
+/// major third is an imperfect consonance, but the enharmonically equivalent diminished fourth is a dissonance).
+pub trait HasConsonance {
+    /// Returns whether or not the type is a perfect consonance (unison, fourth, fifth, or octave, and their compounds).
+    fn is_perfect_consonance(&self) -> bool;
+
+    /// Returns whether or not the type is an imperfect consonance (major / minor thirds and sixths, and their compounds).
+    fn is_imperfect_consonance(&self) -> bool;
+
+    /// Returns whether or not the type is consonant (either a perfect or an imperfect consonance).
+    fn is_consonant(&self) -> bool {
+        self.is_perfect_consonance() || self.is_imperfect_consonance()
+    }
+
+    /// Returns whether or not the type is dissonant (seconds, sevenths, and all augmented / diminished intervals, including the tritone).
+    fn is_dissonant(&self) -> bool {
+        !self.is_consonant()
+    }
+}
+
 // Enum.
 
 /// An enum representing the interval between two notes.
@@ -225,6 +253,39 @@ impl HasEnharmonicDistance for Interval {
     }
 }
 
+impl HasConsonance for Interval {
+    fn is_perfect_consonance(&self) -> bool {
+        matches!(
+            self,
+            Interval::PerfectUnison
+                | Interval::PerfectFourth
+                | Interval::PerfectFifth
+                | Interval::PerfectOctave
+                | Interval::PerfectEleventh
+                | Interval::PerfectOctaveAndPerfectFifth
+                | Interval::TwoPerfectOctaves
+                | Interval::TwoPerfectOctavesAndPerfectFifth
+                | Interval::ThreePerfectOctaves
+                | Interval::ThreePerfectOctavesAndPerfectFifth
+        )
+    }
+
+    fn is_imperfect_consonance(&self) -> bool {
+        matches!(
+            self,
+            Interval::MinorThird
+                | Interval::MajorThird
+                | Interval::MinorSixth
+                | Interval::MajorSixth
+                | Interval::MinorThirteenth
+                | Interval::MajorThirteenth
+                | Interval::TwoPerfectOctavesAndMajorThird
+                | Interval::ThreePerfectOctavesAndMajorThird
+                | Interval::ThreePerfectOctavesAndMinorSixth
+        )
+    }
+}
+
 impl HasOctave for Interval {
     fn octave(&self) -> Octave {
         match self {
@@ -296,77 +357,197 @@ impl HasOctave for Interval {
     }
 }
 
-impl Display for Interval {
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+impl HasStaticName for Interval {
+    fn static_name(&self) -> &'static str {
         match self {
-            Interval::PerfectUnison => write!(f, "PerfectUnison"),
-            Interval::DiminishedSecond => write!(f, "DiminishedSecond"),
+            Interval::PerfectUnison => "Perfect Unison",
+            Interval::DiminishedSecond => "Diminished Second",
 
-            Interval::AugmentedUnison => write!(f, "AugmentedUnison"),
-            Interval::MinorSecond => write!(f, "MinorSecond"),
+            Interval::AugmentedUnison => "Augmented Unison",
+            Interval::MinorSecond => "Minor Second",
 
-            Interval::MajorSecond => write!(f, "MajorSecond"),
-            Interval::DiminishedThird => write!(f, "DiminishedThird"),
+            Interval::MajorSecond => "Major Second",
+            Interval::DiminishedThird => "Diminished Third",
 
-            Interval::AugmentedSecond => write!(f, "AugmentedSecond"),
-            Interval::MinorThird => write!(f, "MinorThird"),
+            Interval::AugmentedSecond => "Augmented Second",
+            Interval::MinorThird => "Minor Third",
 
-            Interval::MajorThird => write!(f, "MajorThird"),
-            Interval::DiminishedFourth => write!(f, "DiminishedFourth"),
+            Interval::MajorThird => "Major Third",
+            Interval::DiminishedFourth => "Diminished Fourth",
 
-            Interval::AugmentedThird => write!(f, "AugmentedThird"),
-            Interval::PerfectFourth => write!(f, "PerfectFourth"),
+            Interval::AugmentedThird => "Augmented Third",
+            Interval::PerfectFourth => "Perfect Fourth",
 
-            Interval::AugmentedFourth => write!(f, "AugmentedFourth"),
-            Interval::DiminishedFifth => write!(f, "DiminishedFifth"),
+            Interval::AugmentedFourth => "Augmented Fourth",
+            Interval::DiminishedFifth => "Diminished Fifth",
 
-            Interval::PerfectFifth => write!(f, "PerfectFifth"),
-            Interval::DiminishedSixth => write!(f, "DiminishedSixth"),
+            Interval::PerfectFifth => "Perfect Fifth",
+            Interval::DiminishedSixth => "Diminished Sixth",
 
-            Interval::AugmentedFifth => write!(f, "AugmentedFifth"),
-            Interval::MinorSixth => write!(f, "MinorSixth"),
+            Interval::AugmentedFifth => "Augmented Fifth",
+            Interval::MinorSixth => "Minor Sixth",
 
-            Interval::MajorSixth => write!(f, "MajorSixth"),
-            Interval::DiminishedSeventh => write!(f, "DiminishedSeventh"),
+            Interval::MajorSixth => "Major Sixth",
+            Interval::DiminishedSeventh => "Diminished Seventh",
 
-            Interval::AugmentedSixth => write!(f, "AugmentedSixth"),
-            Interval::MinorSeventh => write!(f, "MinorSeventh"),
+            Interval::AugmentedSixth => "Augmented Sixth",
+            Interval::MinorSeventh => "Minor Seventh",
 
-            Interval::MajorSeventh => write!(f, "MajorSeventh"),
-            Interval::DiminishedOctave => write!(f, "DiminishedOctave"),
+            Interval::MajorSeventh => "Major Seventh",
+            Interval::DiminishedOctave => "Diminished Octave",
 
-            Interval::AugmentedSeventh => write!(f, "AugmentedSeventh"),
-            Interval::PerfectOctave => write!(f, "PerfectOctave"),
+            Interval::AugmentedSeventh => "Augmented Seventh",
+            Interval::PerfectOctave => "Perfect Octave",
 
-            Interval::MinorNinth => write!(f, "MinorNinth"),
-            Interval::MajorNinth => write!(f, "MajorNinth"),
-            Interval::AugmentedNinth => write!(f, "AugmentedNinth"),
+            Interval::MinorNinth => "Minor Ninth",
+            Interval::MajorNinth => "Major Ninth",
+            Interval::AugmentedNinth => "Augmented Ninth",
 
-            Interval::DiminishedEleventh => write!(f, "DiminishedEleventh"),
-            Interval::PerfectEleventh => write!(f, "PerfectEleventh"),
-            Interval::AugmentedEleventh => write!(f, "AugmentedEleventh"),
+            Interval::DiminishedEleventh => "Diminished Eleventh",
+            Interval::PerfectEleventh => "Perfect Eleventh",
+            Interval::AugmentedEleventh => "Augmented Eleventh",
 
-            Interval::MinorThirteenth => write!(f, "MinorThirteenth"),
-            Interval::MajorThirteenth => write!(f, "MajorThirteenth"),
-            Interval::AugmentedThirteenth => write!(f, "AugmentedThirteenth"),
+            Interval::MinorThirteenth => "Minor Thirteenth",
+            Interval::MajorThirteenth => "Major Thirteenth",
+            Interval::AugmentedThirteenth => "Augmented Thirteenth",
 
-            Interval::PerfectOctaveAndPerfectFifth => write!(f, "PerfectOctaveAndPerfectFifth"),
-            Interval::TwoPerfectOctaves => write!(f, "TwoPerfectOctaves"),
-            Interval::TwoPerfectOctavesAndMajorThird => write!(f, "TwoPerfectOctavesAndMajorThird"),
-            Interval::TwoPerfectOctavesAndPerfectFifth => write!(f, "TwoPerfectOctavesAndPerfectFifth"),
-            Interval::TwoPerfectOctavesAndMinorSeventh => write!(f, "TwoPerfectOctavesAndMinorSeventh"),
-            Interval::ThreePerfectOctaves => write!(f, "ThreePerfectOctaves"),
-            Interval::ThreePerfectOctavesAndMajorSecond => write!(f, "ThreePerfectOctavesAndMajorSecond"),
-            Interval::ThreePerfectOctavesAndMajorThird => write!(f, "ThreePerfectOctavesAndMajorThird"),
-            Interval::ThreePerfectOctavesAndAugmentedFourth => write!(f, "ThreePerfectOctavesAndAugmentedFourth"),
-            Interval::ThreePerfectOctavesAndPerfectFifth => write!(f, "ThreePerfectOctavesAndPerfectFifth"),
-            Interval::ThreePerfectOctavesAndMinorSixth => write!(f, "ThreePerfectOctavesAndMinorSixth"),
-            Interval::ThreePerfectOctavesAndMinorSeventh => write!(f, "ThreePerfectOctavesAndMinorSeventh"),
-            Interval::ThreePerfectOctavesAndMajorSeventh => write!(f, "ThreePerfectOctavesAndMajorSeventh"),
+            Interval::PerfectOctaveAndPerfectFifth => "Perfect Octave And Perfect Fifth",
+            Interval::TwoPerfectOctaves => "Two Perfect Octaves",
+            Interval::TwoPerfectOctavesAndMajorThird => "Two Perfect Octaves And Major Third",
+            Interval::TwoPerfectOctavesAndPerfectFifth => "Two Perfect Octaves And Perfect Fifth",
+            Interval::TwoPerfectOctavesAndMinorSeventh => "Two Perfect Octaves And Minor Seventh",
+            Interval::ThreePerfectOctaves => "Three Perfect Octaves",
+            Interval::ThreePerfectOctavesAndMajorSecond => "Three Perfect Octaves And Major Second",
+            Interval::ThreePerfectOctavesAndMajorThird => "Three Perfect Octaves And Major Third",
+            Interval::ThreePerfectOctavesAndAugmentedFourth => "Three Perfect Octaves And Augmented Fourth",
+            Interval::ThreePerfectOctavesAndPerfectFifth => "Three Perfect Octaves And Perfect Fifth",
+            Interval::ThreePerfectOctavesAndMinorSixth => "Three Perfect Octaves And Minor Sixth",
+            Interval::ThreePerfectOctavesAndMinorSeventh => "Three Perfect Octaves And Minor Seventh",
+            Interval::ThreePerfectOctavesAndMajorSeventh => "Three Perfect Octaves And Major Seventh",
         }
     }
 }
 
+impl Interval {
+    /// Returns every known [`Interval`] (e.g., for listing them in a UI), ordered by size.
+    pub fn all() -> &'static [Interval] {
+        &ALL_INTERVALS
+    }
+
+    /// Returns the number of semitones spanned by the interval (e.g., `4` for a major third).
+    pub fn semitones(&self) -> u8 {
+        (self.enharmonic_distance() as i16 * 7).rem_euclid(12) as u8 + 12 * self.octave() as u8
+    }
+
+    /// Returns the canonical [`Interval`] that spans the given number of semitones, if there is one.
+    ///
+    /// Within an octave, the most common spelling is chosen (e.g., `3` is a minor third, and `6` is an augmented fourth); beyond
+    /// an octave, the first known interval of that size is chosen (e.g., `14` is a major ninth).  Negative sizes return `None`.
+    pub fn from_semitones(semitones: i8) -> Option<Interval> {
+        let interval = match semitones {
+            0 => Interval::PerfectUnison,
+            1 => Interval::MinorSecond,
+            2 => Interval::MajorSecond,
+            3 => Interval::MinorThird,
+            4 => Interval::MajorThird,
+            5 => Interval::PerfectFourth,
+            6 => Interval::AugmentedFourth,
+            7 => Interval::PerfectFifth,
+            8 => Interval::MinorSixth,
+            9 => Interval::MajorSixth,
+            10 => Interval::MinorSeventh,
+            11 => Interval::MajorSeventh,
+            12 => Interval::PerfectOctave,
+            13.. => return ALL_INTERVALS.iter().find(|i| i.semitones() as i8 == semitones).copied(),
+            _ => return None,
+        };
+
+        Some(interval)
+    }
+
+    /// Returns the inversion of the interval (i.e., the interval that, stacked on top of it, spans an octave; e.g., a major third
+    /// inverts to a minor sixth, and an augmented fourth inverts to a diminished fifth).
+    ///
+    /// Compound intervals are inverted by their simple interval (e.g., a major ninth inverts to a minor seventh), and the unison and
+    /// octave invert to each other.
+    pub fn inverted(&self) -> Interval {
+        match self {
+            Interval::PerfectUnison => return Interval::PerfectOctave,
+            _ if self.enharmonic_distance() == 0 => return Interval::PerfectUnison,
+            _ => {}
+        }
+
+        // Within an octave, every enharmonic distance (from `-12` to `12`) belongs to exactly one interval.
+        let distance = -self.enharmonic_distance();
+
+        ALL_INTERVALS
+            .iter()
+            .take_while(|i| **i != Interval::PerfectOctave)
+            .find(|i| i.enharmonic_distance() == distance)
+            .copied()
+            .unwrap_or(Interval::PerfectUnison)
+    }
+
+    /// Returns the number of letter names (minus one) spanned by the interval, within an octave (e.g., `2` for any kind of third).
+    #[cfg(feature = "std")]
+    fn letter_steps(&self) -> u8 {
+        (self.enharmonic_distance() as i16 * 4).rem_euclid(7) as u8
+    }
+}
+
+impl Display for Interval {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        write!(f, "{}", self.static_name())
+    }
+}
+
+#[cfg(feature = "std")]
+impl Parsable for Interval {
+    /// Parses an interval from its short name (e.g., `P5`, `m3`, `M9`, `A4`, or `d7`), or its full name (e.g., `Perfect Fifth`).
+    fn parse(symbol: &str) -> Res<Self>
+    where
+        Self: Sized,
+    {
+        let symbol = symbol.trim();
+        let error = || Err::msg(format!("Please use a valid interval (e.g., `P5`, `m3`, `M9`, or `Perfect Fifth`), rather than `{symbol}`."));
+
+        let full_name = symbol.to_ascii_lowercase().replace([' ', '-', '_'], "");
+
+        if let Some(interval) = ALL_INTERVALS.iter().find(|i| i.static_name().to_ascii_lowercase().replace(' ', "") == full_name) {
+            return Ok(*interval);
+        }
+
+        let mut chars = symbol.chars();
+        let quality = chars.next().ok_or_else(error)?;
+        let number = chars.as_str().parse::<u8>().map_err(|_| error())?;
+
+        if number == 0 {
+            return Err(error());
+        }
+
+        let steps = (number - 1) % 7;
+        let octaves = (number - 1) / 7;
+        let is_perfect = matches!(steps, 0 | 3 | 4);
+
+        let adjustment: i16 = match (quality, is_perfect) {
+            ('P', true) | ('M', false) => 0,
+            ('m', false) => -1,
+            ('A', _) => 1,
+            ('d', true) => -1,
+            ('d', false) => -2,
+            _ => return Err(error()),
+        };
+
+        let semitones = [0, 2, 4, 5, 7, 9, 11][steps as usize] + adjustment + 12 * octaves as i16;
+
+        ALL_INTERVALS
+            .iter()
+            .find(|i| i.letter_steps() == steps && i.semitones() as i16 == semitones)
+            .copied()
+            .ok_or_else(error)
+    }
+}
+
 // Statics.
 
 /// All known [`Interval`]s.
@@ -437,3 +618,127 @@ pub static PRIMARY_HARMONIC_SERIES: [Interval; 13] = [
     Interval::ThreePerfectOctavesAndMinorSeventh,
     Interval::ThreePerfectOctavesAndMajorSeventh,
 ];
+
+// Tests.
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_consonance() {
+        let perfect = ALL_INTERVALS.iter().filter(|i| i.is_perfect_consonance()).copied().collect::<Vec<_>>();
+        let imperfect = ALL_INTERVALS.iter().filter(|i| i.is_imperfect_consonance()).copied().collect::<Vec<_>>();
+
+        assert_eq!(&perfect[..4], &[Interval::PerfectUnison, Interval::PerfectFourth, Interval::PerfectFifth, Interval::PerfectOctave]);
+        assert_eq!(&imperfect[..4], &[Interval::MinorThird, Interval::MajorThird, Interval::MinorSixth, Interval::MajorSixth]);
+
+        // Seconds, sevenths, and the tritone are dissonant.
+        for interval in [
+            Interval::MinorSecond,
+            Interval::MajorSecond,
+            Interval::MinorSeventh,
+            Interval::MajorSeventh,
+            Interval::AugmentedFourth,
+            Interval::DiminishedFifth,
+            Interval::MinorNinth,
+            Interval::MajorNinth,
+        ] {
+            assert!(interval.is_dissonant(), "{interval} should be dissonant");
+        }
+
+        // Spelling matters: altered intervals are dissonant, even when they are enharmonic to a consonance.
+        assert!(Interval::DiminishedFourth.is_dissonant());
+        assert!(Interval::AugmentedFifth.is_dissonant());
+        assert!(Interval::MajorThird.is_consonant());
+        assert!(Interval::MinorSixth.is_consonant());
+
+        // Compound intervals keep the classification of their simple intervals.
+        assert!(Interval::PerfectEleventh.is_perfect_consonance());
+        assert!(Interval::MajorThirteenth.is_imperfect_consonance());
+        assert!(Interval::AugmentedEleventh.is_dissonant());
+
+        // Every interval is exactly one of perfect, imperfect, or dissonant.
+        for interval in ALL_INTERVALS {
+            let count = [interval.is_perfect_consonance(), interval.is_imperfect_consonance(), interval.is_dissonant()]
+                .iter()
+                .filter(|b| **b)
+                .count();
+
+            assert_eq!(count, 1, "{interval}");
+        }
+    }
+
+    #[test]
+    fn test_all_and_semitones() {
+        assert_eq!(Interval::all().len(), 48);
+        assert_eq!(Interval::all()[0], Interval::PerfectUnison);
+
+        // Every interval has a distinct, human name.
+        let mut names = Interval::all().iter().map(|i| i.static_name()).collect::<Vec<_>>();
+        names.sort();
+        names.dedup();
+        assert_eq!(names.len(), 48);
+        assert_eq!(Interval::MajorThird.static_name(), "Major Third");
+        assert_eq!(format!("{}", Interval::PerfectFifth), "Perfect Fifth");
+        assert_eq!(Interval::ThreePerfectOctavesAndMajorSeventh.static_name(), "Three Perfect Octaves And Major Seventh");
+
+        assert_eq!(Interval::DiminishedOctave.semitones(), 11);
+        assert_eq!(Interval::AugmentedSeventh.semitones(), 12);
+        assert_eq!(Interval::MajorThirteenth.semitones(), 21);
+        assert_eq!(Interval::TwoPerfectOctavesAndMinorSeventh.semitones(), 34);
+
+        assert_eq!(Interval::from_semitones(0), Some(Interval::PerfectUnison));
+        assert_eq!(Interval::from_semitones(3), Some(Interval::MinorThird));
+        assert_eq!(Interval::from_semitones(7), Some(Interval::PerfectFifth));
+        assert_eq!(Interval::from_semitones(14), Some(Interval::MajorNinth));
+        assert_eq!(Interval::from_semitones(19), Some(Interval::PerfectOctaveAndPerfectFifth));
+        assert_eq!(Interval::from_semitones(23), None);
+        assert_eq!(Interval::from_semitones(-1), None);
+
+        // Sizes always round-trip.
+        for n in 0..=12 {
+            assert_eq!(Interval::from_semitones(n).unwrap().semitones() as i8, n);
+        }
+    }
+
+    #[test]
+    fn test_inverted() {
+        assert_eq!(Interval::MajorThird.inverted(), Interval::MinorSixth);
+        assert_eq!(Interval::PerfectFifth.inverted(), Interval::PerfectFourth);
+        assert_eq!(Interval::AugmentedFourth.inverted(), Interval::DiminishedFifth);
+        assert_eq!(Interval::AugmentedSeventh.inverted(), Interval::DiminishedSecond);
+        assert_eq!(Interval::PerfectUnison.inverted(), Interval::PerfectOctave);
+        assert_eq!(Interval::PerfectOctave.inverted(), Interval::PerfectUnison);
+        assert_eq!(Interval::MajorNinth.inverted(), Interval::MinorSeventh);
+
+        // Inverting a simple interval twice returns the original, and the two always span an octave.
+        for interval in ALL_INTERVALS.iter().take_while(|i| **i != Interval::PerfectOctave).skip(1) {
+            assert_eq!(interval.inverted().inverted(), *interval);
+            assert_eq!(interval.semitones() + interval.inverted().semitones(), 12, "{interval}");
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(Interval::parse("P5").unwrap(), Interval::PerfectFifth);
+        assert_eq!(Interval::parse("m3").unwrap(), Interval::MinorThird);
+        assert_eq!(Interval::parse("M3").unwrap(), Interval::MajorThird);
+        assert_eq!(Interval::parse("A4").unwrap(), Interval::AugmentedFourth);
+        assert_eq!(Interval::parse("d5").unwrap(), Interval::DiminishedFifth);
+        assert_eq!(Interval::parse("d7").unwrap(), Interval::DiminishedSeventh);
+        assert_eq!(Interval::parse("P8").unwrap(), Interval::PerfectOctave);
+        assert_eq!(Interval::parse("M9").unwrap(), Interval::MajorNinth);
+        assert_eq!(Interval::parse("A11").unwrap(), Interval::AugmentedEleventh);
+        assert_eq!(Interval::parse("P12").unwrap(), Interval::PerfectOctaveAndPerfectFifth);
+        assert_eq!(Interval::parse("perfect fifth").unwrap(), Interval::PerfectFifth);
+        assert_eq!(Interval::parse(" Two Perfect Octaves ").unwrap(), Interval::TwoPerfectOctaves);
+
+        assert!(Interval::parse("P3").is_err());
+        assert!(Interval::parse("M5").is_err());
+        assert!(Interval::parse("P0").is_err());
+        assert!(Interval::parse("M10").is_err());
+        assert!(Interval::parse("").is_err());
+    }
+}
@@ -3,7 +3,8 @@
 use crate::core::{
     base::{HasDescription, HasName, HasStaticName},
     interval::Interval,
-    modifier::Degree,
+    modifier::{Degree, Modifier},
+    scale::HasScaleKind,
 };
 
 #[cfg(feature = "serde")]
@@ -44,20 +45,20 @@ pub enum KnownChord {
     Major,
     /// A minor chord.
     Minor,
-    /// A major 7 chord.
-    Major7,
+    /// A major 7 chord with degree (e.g., `maj9`).
+    Major7(Degree),
     /// A dominant chord.
     Dominant(Degree),
-    /// A minor major 7 chord.
-    MinorMajor7,
+    /// A minor major 7 chord with degree (e.g., `m(maj9)`).
+    MinorMajor7(Degree),
     /// A minor dominant chord with degree.
     MinorDominant(Degree),
     /// A dominant sharp 11 chord with degree.
     DominantSharp11(Degree),
     /// An augmented chord.
     Augmented,
-    /// An augmented major 7 chord.
-    AugmentedMajor7,
+    /// An augmented major 7 chord with degree (e.g., `+(maj9)`).
+    AugmentedMajor7(Degree),
     /// An augmented chord.
     AugmentedDominant(Degree),
     /// A half diminished chord.
@@ -68,6 +69,52 @@ pub enum KnownChord {
     DominantFlat9(Degree),
     /// A dominant sharp 9 chord.
     DominantSharp9(Degree),
+    /// An altered dominant chord (i.e., a dominant 7 with ♭9, ♯9, ♯11, and ♭13, and no fifth).
+    Altered,
+}
+
+/// An enum representing the quality of a chord, independent of its root, voicing, tensions, and alterations.
+///
+/// Chords with a higher dominant degree share the quality of their seventh chord (e.g., `Dm9` and `Gm7` are both
+/// [`ChordQuality::MinorSeventh`]).
+#[derive(PartialEq, Eq, Copy, Clone, Hash, Debug, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(u8)]
+pub enum ChordQuality {
+    /// A major triad.
+    Major,
+    /// A minor triad.
+    Minor,
+    /// A diminished triad.
+    Diminished,
+    /// An augmented triad.
+    Augmented,
+    /// A triad with a major second in place of the third.
+    Suspended2,
+    /// A triad with a perfect fourth in place of the third.
+    Suspended4,
+    /// A major triad with an added sixth.
+    MajorSixth,
+    /// A minor triad with an added sixth.
+    MinorSixth,
+    /// A major seventh chord.
+    MajorSeventh,
+    /// A dominant seventh chord.
+    DominantSeventh,
+    /// A dominant seventh chord with a suspended third.
+    SuspendedSeventh,
+    /// A minor seventh chord.
+    MinorSeventh,
+    /// A minor major seventh chord.
+    MinorMajorSeventh,
+    /// A half diminished (minor seven flat five) chord.
+    HalfDiminishedSeventh,
+    /// A fully diminished seventh chord.
+    DiminishedSeventh,
+    /// An augmented (dominant) seventh chord.
+    AugmentedSeventh,
+    /// An augmented major seventh chord.
+    AugmentedMajorSeventh,
 }
 
 // Impls.
@@ -78,154 +125,26 @@ impl HasDescription for KnownChord {
             KnownChord::Unknown => unreachable!(),
             KnownChord::Major => "major",
             KnownChord::Minor => "minor",
-            KnownChord::Major7 => "major 7, ionian, first mode of major scale",
+            KnownChord::Major7(_) => "major 7, ionian, first mode of major scale",
             KnownChord::Dominant(_) => "dominant, mixolydian, fifth mode of major scale, major with flat seven",
-            KnownChord::MinorMajor7 => "minor major 7, melodic minor, major with flat third",
+            KnownChord::MinorMajor7(_) => "minor major 7, melodic minor, major with flat third",
             KnownChord::MinorDominant(_) => "minor 7, dorian, second mode of major scale, major with flat third and flat seven",
             KnownChord::DominantSharp11(_) => "dominant sharp 11, lydian dominant, lyxian, major with sharp four and flat seven",
             KnownChord::Augmented => "augmented, major with sharp five",
-            KnownChord::AugmentedMajor7 => "augmented major 7, major with sharp four and five, third mode of melodic minor",
+            KnownChord::AugmentedMajor7(_) => "augmented major 7, major with sharp four and five, third mode of melodic minor",
             KnownChord::AugmentedDominant(_) => "augmented dominant, whole tone",
             KnownChord::HalfDiminished(_) => "half diminished, locrian, minor seven flat five, seventh mode of major scale, major scale starting one half step up",
             KnownChord::Diminished => "fully diminished (whole first), diminished seventh, whole/half/whole diminished",
             KnownChord::DominantFlat9(_) => "dominant flat 9, fully diminished (half first), half/whole/half diminished",
             KnownChord::DominantSharp9(_) => "dominant sharp 9, altered, altered dominant, super locrian, diminished whole tone, seventh mode of a melodic minor scale, melodic minor up a half step",
+            KnownChord::Altered => "altered dominant, altered, super locrian, diminished whole tone, seventh mode of a melodic minor scale, dominant with all of the altered tensions",
         }
     }
 }
 
 impl HasRelativeScale for KnownChord {
     fn relative_scale(&self) -> Vec<Interval> {
-        match self {
-            KnownChord::Unknown => unreachable!(),
-            KnownChord::Major => vec![
-                Interval::PerfectUnison,
-                Interval::MajorSecond,
-                Interval::MajorThird,
-                Interval::PerfectFourth,
-                Interval::PerfectFifth,
-                Interval::MajorSixth,
-                Interval::MajorSeventh,
-            ],
-            KnownChord::Minor => vec![
-                Interval::PerfectUnison,
-                Interval::MajorSecond,
-                Interval::MinorThird,
-                Interval::PerfectFourth,
-                Interval::PerfectFifth,
-                Interval::MinorSixth,
-                Interval::MinorSeventh,
-            ],
-            KnownChord::Major7 => vec![
-                Interval::PerfectUnison,
-                Interval::MajorSecond,
-                Interval::MajorThird,
-                Interval::PerfectFourth,
-                Interval::PerfectFifth,
-                Interval::MajorSixth,
-                Interval::MajorSeventh,
-            ],
-            KnownChord::Dominant(_) => vec![
-                Interval::PerfectUnison,
-                Interval::MajorSecond,
-                Interval::MajorThird,
-                Interval::PerfectFourth,
-                Interval::PerfectFifth,
-                Interval::MajorSixth,
-                Interval::MinorSeventh,
-            ],
-            KnownChord::MinorMajor7 => vec![
-                Interval::PerfectUnison,
-                Interval::MajorSecond,
-                Interval::MinorThird,
-                Interval::PerfectFourth,
-                Interval::PerfectFifth,
-                Interval::MajorSixth,
-                Interval::MajorSeventh,
-            ],
-            KnownChord::MinorDominant(_) => vec![
-                Interval::PerfectUnison,
-                Interval::MajorSecond,
-                Interval::MinorThird,
-                Interval::PerfectFourth,
-                Interval::PerfectFifth,
-                Interval::MajorSixth,
-                Interval::MinorSeventh,
-            ],
-            KnownChord::DominantSharp11(_) => vec![
-                Interval::PerfectUnison,
-                Interval::MajorSecond,
-                Interval::MajorThird,
-                Interval::AugmentedFourth,
-                Interval::PerfectFifth,
-                Interval::MajorSixth,
-                Interval::MinorSeventh,
-            ],
-            KnownChord::Augmented => vec![
-                Interval::PerfectUnison,
-                Interval::MajorSecond,
-                Interval::MajorThird,
-                Interval::PerfectFourth,
-                Interval::AugmentedFifth,
-                Interval::MajorSixth,
-                Interval::MajorSeventh,
-            ],
-            KnownChord::AugmentedMajor7 => vec![
-                Interval::PerfectUnison,
-                Interval::MajorSecond,
-                Interval::MajorThird,
-                Interval::AugmentedFourth,
-                Interval::AugmentedFifth,
-                Interval::MajorSixth,
-                Interval::MajorSeventh,
-            ],
-            KnownChord::AugmentedDominant(_) => vec![
-                Interval::PerfectUnison,
-                Interval::MajorSecond,
-                Interval::MajorThird,
-                Interval::AugmentedFourth,
-                Interval::AugmentedFifth,
-                Interval::AugmentedSixth,
-            ],
-            KnownChord::HalfDiminished(_) => vec![
-                Interval::PerfectUnison,
-                Interval::MajorSecond,
-                Interval::MinorThird,
-                Interval::PerfectFourth,
-                Interval::DiminishedFifth,
-                Interval::MinorSixth,
-                Interval::MinorSeventh,
-            ],
-            KnownChord::Diminished => vec![
-                Interval::PerfectUnison,
-                Interval::MajorSecond,
-                Interval::MinorThird,
-                Interval::PerfectFourth,
-                Interval::DiminishedFifth,
-                Interval::MinorSixth,
-                Interval::DiminishedSeventh,
-                Interval::MajorSeventh,
-            ],
-            KnownChord::DominantFlat9(_) => vec![
-                Interval::PerfectUnison,
-                Interval::MinorSecond,
-                Interval::MinorThird,
-                Interval::MajorThird,
-                Interval::AugmentedFourth,
-                Interval::PerfectFifth,
-                Interval::MajorSixth,
-                Interval::MinorSeventh,
-            ],
-            KnownChord::DominantSharp9(_) => vec![
-                Interval::PerfectUnison,
-                Interval::MinorSecond,
-                Interval::MinorThird,
-                Interval::DiminishedFourth,
-                Interval::DiminishedFifth,
-                Interval::MinorSixth,
-                Interval::MinorSeventh,
-            ],
-        }
+        self.scale_kind().relative_scale()
     }
 }
 
@@ -235,9 +154,9 @@ impl HasRelativeChord for KnownChord {
             KnownChord::Unknown => unreachable!(),
             KnownChord::Major => vec![Interval::PerfectUnison, Interval::MajorThird, Interval::PerfectFifth],
             KnownChord::Minor => vec![Interval::PerfectUnison, Interval::MinorThird, Interval::PerfectFifth],
-            KnownChord::Major7 => vec![Interval::PerfectUnison, Interval::MajorThird, Interval::PerfectFifth, Interval::MajorSeventh],
+            KnownChord::Major7(_) => vec![Interval::PerfectUnison, Interval::MajorThird, Interval::PerfectFifth, Interval::MajorSeventh],
             KnownChord::Dominant(_) => vec![Interval::PerfectUnison, Interval::MajorThird, Interval::PerfectFifth, Interval::MinorSeventh],
-            KnownChord::MinorMajor7 => vec![Interval::PerfectUnison, Interval::MinorThird, Interval::PerfectFifth, Interval::MajorSeventh],
+            KnownChord::MinorMajor7(_) => vec![Interval::PerfectUnison, Interval::MinorThird, Interval::PerfectFifth, Interval::MajorSeventh],
             KnownChord::MinorDominant(_) => vec![Interval::PerfectUnison, Interval::MinorThird, Interval::PerfectFifth, Interval::MinorSeventh],
             KnownChord::DominantSharp11(_) => vec![
                 Interval::PerfectUnison,
@@ -247,34 +166,89 @@ impl HasRelativeChord for KnownChord {
                 Interval::AugmentedEleventh,
             ],
             KnownChord::Augmented => vec![Interval::PerfectUnison, Interval::MajorThird, Interval::AugmentedFifth],
-            KnownChord::AugmentedMajor7 => vec![Interval::PerfectUnison, Interval::MajorThird, Interval::AugmentedFifth, Interval::MajorSeventh],
+            KnownChord::AugmentedMajor7(_) => vec![Interval::PerfectUnison, Interval::MajorThird, Interval::AugmentedFifth, Interval::MajorSeventh],
             KnownChord::AugmentedDominant(_) => vec![Interval::PerfectUnison, Interval::MajorThird, Interval::AugmentedFifth, Interval::MinorSeventh],
             KnownChord::HalfDiminished(_) => vec![Interval::PerfectUnison, Interval::MinorThird, Interval::DiminishedFifth, Interval::MinorSeventh],
             KnownChord::Diminished => vec![Interval::PerfectUnison, Interval::MinorThird, Interval::DiminishedFifth, Interval::DiminishedSeventh],
             KnownChord::DominantFlat9(_) => vec![Interval::PerfectUnison, Interval::MajorThird, Interval::PerfectFifth, Interval::MinorSeventh, Interval::MinorNinth],
             KnownChord::DominantSharp9(_) => vec![Interval::PerfectUnison, Interval::MajorThird, Interval::PerfectFifth, Interval::MinorSeventh, Interval::AugmentedNinth],
+            KnownChord::Altered => vec![
+                Interval::PerfectUnison,
+                Interval::MajorThird,
+                Interval::MinorSeventh,
+                Interval::MinorNinth,
+                Interval::AugmentedNinth,
+                Interval::AugmentedEleventh,
+                Interval::MinorThirteenth,
+            ],
         }
     }
 }
 
-impl HasName for KnownChord {
-    fn name(&self) -> String {
+impl HasStaticName for ChordQuality {
+    fn static_name(&self) -> &'static str {
+        match self {
+            ChordQuality::Major => "major",
+            ChordQuality::Minor => "minor",
+            ChordQuality::Diminished => "diminished",
+            ChordQuality::Augmented => "augmented",
+            ChordQuality::Suspended2 => "suspended second",
+            ChordQuality::Suspended4 => "suspended fourth",
+            ChordQuality::MajorSixth => "major sixth",
+            ChordQuality::MinorSixth => "minor sixth",
+            ChordQuality::MajorSeventh => "major seventh",
+            ChordQuality::DominantSeventh => "dominant seventh",
+            ChordQuality::SuspendedSeventh => "suspended seventh",
+            ChordQuality::MinorSeventh => "minor seventh",
+            ChordQuality::MinorMajorSeventh => "minor major seventh",
+            ChordQuality::HalfDiminishedSeventh => "half diminished seventh",
+            ChordQuality::DiminishedSeventh => "diminished seventh",
+            ChordQuality::AugmentedSeventh => "augmented seventh",
+            ChordQuality::AugmentedMajorSeventh => "augmented major seventh",
+        }
+    }
+}
+
+impl KnownChord {
+    /// Returns the name of the known chord's quality, without the alterations that it implies (e.g., `m7` for a
+    /// half diminished chord, rather than `m7(♭5)`).
+    pub fn quality_name(&self) -> String {
         match self {
             KnownChord::Unknown => unreachable!(),
             KnownChord::Major => "".to_owned(),
             KnownChord::Minor => "m".to_owned(),
-            KnownChord::Major7 => "maj7".to_owned(),
-            KnownChord::Dominant(d) => d.static_name().to_owned(),
-            KnownChord::MinorMajor7 => "m(maj7)".to_owned(),
-            KnownChord::MinorDominant(d) => format!("m{}", d.static_name()),
-            KnownChord::DominantSharp11(d) => format!("{}(♯11)", d.static_name()),
+            KnownChord::Major7(d) => format!("maj{}", d.static_name()),
+            KnownChord::Dominant(d) | KnownChord::DominantSharp11(d) | KnownChord::DominantFlat9(d) | KnownChord::DominantSharp9(d) => d.static_name().to_owned(),
+            KnownChord::MinorMajor7(d) => format!("m(maj{})", d.static_name()),
+            KnownChord::MinorDominant(d) | KnownChord::HalfDiminished(d) => format!("m{}", d.static_name()),
             KnownChord::Augmented => "+".to_owned(),
-            KnownChord::AugmentedMajor7 => "+(maj7)".to_owned(),
+            KnownChord::AugmentedMajor7(d) => format!("+(maj{})", d.static_name()),
             KnownChord::AugmentedDominant(d) => format!("+{}", d.static_name()),
-            KnownChord::HalfDiminished(d) => format!("m{}(♭5)", d.static_name()),
             KnownChord::Diminished => "dim".to_owned(),
-            KnownChord::DominantFlat9(d) => format!("{}(♭9)", d.static_name()),
-            KnownChord::DominantSharp9(d) => format!("{}(♯9)", d.static_name()),
+            KnownChord::Altered => "7alt".to_owned(),
+        }
+    }
+
+    /// Returns the alterations that the known chord implies, in the order that they are named (e.g., `♭5` for a half diminished chord).
+    pub fn alterations(&self) -> Vec<Modifier> {
+        match self {
+            KnownChord::HalfDiminished(_) => vec![Modifier::Flat5],
+            KnownChord::DominantFlat9(_) => vec![Modifier::Flat9],
+            KnownChord::DominantSharp9(_) => vec![Modifier::Sharp9],
+            KnownChord::DominantSharp11(_) => vec![Modifier::Sharp11],
+            _ => vec![],
         }
     }
 }
+
+impl HasName for KnownChord {
+    fn name(&self) -> String {
+        let mut name = self.quality_name();
+
+        for alteration in self.alterations() {
+            name.push_str(&format!("({})", alteration.static_name()));
+        }
+
+        name
+    }
+}
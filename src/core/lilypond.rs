@@ -0,0 +1,195 @@
+//! A module for rendering chords to [LilyPond](https://lilypond.org/) `\chordmode` syntax.
+
+use crate::core::{
+    base::HasStaticName,
+    chord::{Chord, HasChord, HasDomninantDegree, HasExtensions, HasInversion, HasModifiers, HasRoot, HasSlash},
+    known_chord::HasRelativeChord,
+    modifier::{Extension, Modifier},
+    note::Note,
+    pitch::HasPitch,
+};
+
+// Impls.
+
+impl Chord {
+    /// Returns the chord in LilyPond `\chordmode` syntax (e.g., `c:maj7`, `d:m7.5-`, or `c:maj7/e`).
+    ///
+    /// The chord's modifiers and extensions are mapped onto LilyPond's chord modifiers (e.g., `m`, `maj`, `dim7`, `sus4`), and
+    /// alterations / additions are written as steps (e.g., `.9-`, `.11+`, or `5.9` for an `add9`).  Slash chords and inversions
+    /// are written with their bass note (using `/+` when the bass is not a chord tone).  Octaves are not written.
+    pub fn to_lilypond(&self) -> String {
+        let modifiers = self.modifiers();
+        let extensions = self.extensions();
+
+        let degree = self.dominant_degree();
+        let number = degree.map(|d| d.static_name()).unwrap_or("7");
+
+        let is_minor = modifiers.contains(&Modifier::Minor);
+        let is_major7 = modifiers.contains(&Modifier::Major7);
+        let is_six = degree.is_none() && !is_major7 && extensions.contains(&Extension::Add6);
+        let is_altered = degree.is_some() && modifiers.contains(&Modifier::Altered);
+
+        let mut quality = if modifiers.contains(&Modifier::Diminished) {
+            "dim7".to_owned()
+        } else {
+            match (is_minor, is_major7, degree.is_some()) {
+                (true, true, _) if number == "7" => "m7+".to_owned(),
+                (true, true, _) => format!("m{number}.7+"),
+                (true, false, true) => format!("m{number}"),
+                (false, true, _) => format!("maj{number}"),
+                (false, false, true) => number.to_owned(),
+                (true, false, false) if is_six => "m6".to_owned(),
+                (false, false, false) if is_six => "6".to_owned(),
+                (true, false, false) => "m".to_owned(),
+                (false, false, false) => String::new(),
+            }
+        };
+
+        let mut steps = Vec::new();
+
+        if modifiers.contains(&Modifier::Augmented5) {
+            match quality.as_str() {
+                "" => quality.push_str("aug"),
+                "7" => quality = "aug7".to_owned(),
+                _ => steps.push(".5+"),
+            }
+        }
+
+        if modifiers.contains(&Modifier::Flat5) {
+            steps.push(".5-");
+        }
+
+        for (modifier, step) in [(Modifier::Flat9, ".9-"), (Modifier::Sharp9, ".9+"), (Modifier::Sharp11, ".11+")] {
+            if modifiers.contains(&modifier) || is_altered {
+                steps.push(step);
+            }
+        }
+
+        for (extension, step) in [
+            (Extension::Add2, ".2"),
+            (Extension::Add4, ".4"),
+            (Extension::Add6, ".6"),
+            (Extension::Add9, ".9"),
+            (Extension::Flat11, ".11-"),
+            (Extension::Add11, ".11"),
+            (Extension::Flat13, ".13-"),
+            (Extension::Sharp13, ".13+"),
+            (Extension::Add13, ".13"),
+        ] {
+            if (extensions.contains(&extension) || (extension == Extension::Flat13 && is_altered)) && !(extension == Extension::Add6 && is_six) {
+                steps.push(step);
+            }
+        }
+
+        for (extension, sus) in [(Extension::Sus2, "sus2"), (Extension::Sus4, "sus4")] {
+            if extensions.contains(&extension) {
+                quality.push_str(sus);
+            }
+        }
+
+        // Steps need a chord to apply to, so plain (or minor) triads are written as `5` (or `m5`) first.
+        if !steps.is_empty() && (quality.is_empty() || quality == "m") {
+            quality.push('5');
+        }
+
+        let mut result = lilypond_pitch(&self.root());
+
+        if !quality.is_empty() || !steps.is_empty() {
+            result.push(':');
+            result.push_str(&quality);
+            result.push_str(&steps.concat());
+
+            // Altered chords have no fifth.
+            if is_altered {
+                result.push_str("^5");
+            }
+        }
+
+        if self.slash() != self.root() || self.inversion() > 0 {
+            if let Some(bass) = self.chord().first() {
+                let is_chord_tone = self.relative_chord().into_iter().any(|i| (self.root() + i).pitch() == bass.pitch());
+
+                result.push_str(if is_chord_tone { "/" } else { "/+" });
+                result.push_str(&lilypond_pitch(bass));
+            }
+        }
+
+        result
+    }
+}
+
+// Helpers.
+
+/// Returns the (Dutch, i.e., default) LilyPond name of the note's pitch, without an octave (e.g., `cis`, `bes`, or `as`).
+fn lilypond_pitch(note: &Note) -> String {
+    let mut chars = note.static_name().chars();
+    let letter = chars.next().unwrap_or('C').to_ascii_lowercase();
+
+    let accidentals = chars
+        .map(|c| match c {
+            '♯' => "is",
+            '𝄪' => "isis",
+            '♭' => "es",
+            '𝄫' => "eses",
+            _ => "",
+        })
+        .collect::<String>();
+
+    let name = format!("{letter}{accidentals}");
+
+    // The vowels are elided for flats of `E` and `A` (e.g., `es`, rather than `ees`).
+    match letter {
+        'e' | 'a' if accidentals.starts_with("es") => format!("{letter}{}", &accidentals[1..]),
+        _ => name,
+    }
+}
+
+// Tests.
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{base::Parsable, chord::Chordable, note::*};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_lilypond() {
+        let cases = [
+            ("C", "c"),
+            ("Cm", "c:m"),
+            ("Cmaj7", "c:maj7"),
+            ("C7", "c:7"),
+            ("Cm7", "c:m7"),
+            ("Dm7b5", "d:m7.5-"),
+            ("Cdim", "c:dim7"),
+            ("C+", "c:aug"),
+            ("C+7", "c:aug7"),
+            ("Cm(maj7)", "c:m7+"),
+            ("Cmaj9", "c:maj9"),
+            ("C13", "c:13"),
+            ("C7b9", "c:7.9-"),
+            ("C7#9#11", "c:7.9+.11+"),
+            ("C7alt", "c:7.9-.9+.11+.13-^5"),
+            ("Csus4", "c:sus4"),
+            ("C7sus4", "c:7sus4"),
+            ("Cadd9", "c:5.9"),
+            ("C6", "c:6"),
+            ("Cm6", "c:m6"),
+            ("C6/9", "c:6.9"),
+            ("Bbmaj7", "bes:maj7"),
+            ("Ebm", "es:m"),
+            ("Abm", "as:m"),
+            ("F#m7", "fis:m7"),
+            ("Cmaj7/E", "c:maj7/e"),
+            ("C/F", "c/+f"),
+        ];
+
+        for (symbol, expected) in cases {
+            assert_eq!(Chord::parse(symbol).unwrap().to_lilypond(), expected, "{symbol}");
+        }
+
+        // Inversions are written with their bass note.
+        assert_eq!(Chord::new(C).major7().with_inversion(1).to_lilypond(), "c:maj7/e");
+        assert_eq!(Chord::new(EFlat).with_inversion(2).to_lilypond(), "es/bes");
+    }
+}
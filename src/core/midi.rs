@@ -0,0 +1,435 @@
+//! Standard MIDI file export (of chords and progressions), and import (of the chords detected over time).
+
+use std::{collections::BTreeSet, path::Path, time::Duration};
+
+use midly::{
+    num::{u15, u24, u28, u4, u7},
+    Format, Header, MetaMessage, MidiMessage, Smf, Timing, TrackEvent, TrackEventKind,
+};
+
+use crate::core::{
+    base::{Res, Void},
+    chord::{Chord, HasChord},
+    note::{HasMidiNumber, Note},
+};
+
+/// The number of ticks per beat (quarter note) used in exported MIDI files.
+pub const TICKS_PER_BEAT: u16 = 480;
+
+/// The (0-based) channel reserved for percussion (i.e., channel 10).
+pub const PERCUSSION_CHANNEL: u8 = 9;
+
+/// The tempo assumed until a MIDI file sets one (i.e., 120 beats per minute), in microseconds per beat.
+const DEFAULT_MICROSECONDS_PER_BEAT: u32 = 500_000;
+
+// Struct.
+
+/// Options that control how chords are written to a MIDI file.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MidiExportOptions {
+    /// The tempo, in beats (quarter notes) per minute.
+    pub tempo: f32,
+    /// The velocity of every note (in the range `[1, 127]`).
+    pub velocity: u8,
+}
+
+impl MidiExportOptions {
+    /// Returns the default options with the given tempo.
+    pub fn with_tempo(tempo: f32) -> Self {
+        Self { tempo, ..Default::default() }
+    }
+}
+
+impl Default for MidiExportOptions {
+    fn default() -> Self {
+        Self { tempo: 120.0, velocity: 100 }
+    }
+}
+
+/// Options that control how the notes in a MIDI file are grouped into chords.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MidiImportOptions {
+    /// Note-on events within this window of the first note-on of a group are considered simultaneous.
+    pub grouping_window: Duration,
+    /// Whether or not to ignore the percussion channel (channel 10).
+    pub ignore_percussion: bool,
+}
+
+impl Default for MidiImportOptions {
+    fn default() -> Self {
+        Self {
+            grouping_window: Duration::from_millis(30),
+            ignore_percussion: true,
+        }
+    }
+}
+
+// Export.
+
+/// Lays the chords out sequentially (each one lasting `length_beats`), and returns the bytes of a type-0 MIDI file.
+///
+/// Every chord tone gets a note-on event at the start of its chord, and a note-off event at the end of it.  Chord tones
+/// above `G9` (i.e., outside of the MIDI key range) are an error.
+pub fn progression_to_midi(chords: &[Chord], length_beats: f32, options: MidiExportOptions) -> Res<Vec<u8>> {
+    if length_beats <= 0.0 {
+        return Err(anyhow::Error::msg("The length (in beats) must be greater than zero."));
+    }
+
+    if options.tempo <= 0.0 {
+        return Err(anyhow::Error::msg("The tempo must be greater than zero."));
+    }
+
+    if !(1..=127).contains(&options.velocity) {
+        return Err(anyhow::Error::msg("The velocity must be between 1 and 127."));
+    }
+
+    let length_ticks = (length_beats * TICKS_PER_BEAT as f32).round() as u32;
+    let microseconds_per_beat = (60_000_000.0 / options.tempo).round() as u32;
+
+    let note_event = |key: u8, on: bool| TrackEventKind::Midi {
+        channel: u4::new(0),
+        message: if on {
+            MidiMessage::NoteOn {
+                key: u7::new(key),
+                vel: u7::new(options.velocity),
+            }
+        } else {
+            MidiMessage::NoteOff { key: u7::new(key), vel: u7::new(0) }
+        },
+    };
+
+    // Gather the events at their absolute times (note-offs sort before note-ons at the same time, so repeated notes retrigger).
+    let mut events = Vec::new();
+
+    for (k, chord) in chords.iter().enumerate() {
+        let start = k as u32 * length_ticks;
+
+        for note in chord.chord() {
+            let key = u8::try_from(note.semitones_from_c_zero() + 12)
+                .ok()
+                .filter(|key| *key <= 127)
+                .ok_or_else(|| anyhow::Error::msg(format!("The note {note} is outside of the MIDI key range (0 - 127).")))?;
+
+            events.push((start, 1, note_event(key, true)));
+            events.push((start + length_ticks, 0, note_event(key, false)));
+        }
+    }
+
+    events.sort_by_key(|(time, order, _)| (*time, *order));
+
+    // Convert to delta times.
+    let mut track = vec![TrackEvent {
+        delta: u28::new(0),
+        kind: TrackEventKind::Meta(MetaMessage::Tempo(u24::new(microseconds_per_beat))),
+    }];
+
+    let mut last_time = 0;
+
+    for (time, _, kind) in events {
+        track.push(TrackEvent { delta: u28::new(time - last_time), kind });
+        last_time = time;
+    }
+
+    track.push(TrackEvent {
+        delta: u28::new(0),
+        kind: TrackEventKind::Meta(MetaMessage::EndOfTrack),
+    });
+
+    let mut smf = Smf::new(Header::new(Format::SingleTrack, Timing::Metrical(u15::new(TICKS_PER_BEAT))));
+    smf.tracks.push(track);
+
+    let mut bytes = Vec::new();
+    smf.write_std(&mut bytes)?;
+
+    Ok(bytes)
+}
+
+/// Lays the chords out sequentially (each one lasting `length_beats`), and writes them to a type-0 MIDI file at the given path.
+pub fn progression_to_midi_file(path: impl AsRef<Path>, chords: &[Chord], length_beats: f32, options: MidiExportOptions) -> Void {
+    std::fs::write(path, progression_to_midi(chords, length_beats, options)?)?;
+
+    Ok(())
+}
+
+// Import.
+
+/// Reads the bytes of a MIDI file, and returns the candidate chords at each onset.
+///
+/// The notes of each group are guessed with [`Chord::try_from_notes_preserving_bass_octave`] (rather than [`Chord::try_from_notes`]),
+/// since the voicings in MIDI files are often spread across octaves.
+///
+/// Note-on events are grouped by time (using the `grouping_window`), and each group is combined with the notes that are
+/// still sounding from earlier groups (e.g., a held bass note).  Groups with no candidate chords are omitted.  The events
+/// of every track are merged, and tempo changes are respected.
+pub fn midi_to_chords(bytes: &[u8], options: MidiImportOptions) -> Res<Vec<(Duration, Vec<Chord>)>> {
+    let smf = Smf::parse(bytes)?;
+
+    // Gather the `(tick, channel, key, is note-on)` note events and the tempo changes of every track.
+    let mut note_events = Vec::new();
+    let mut tempos = Vec::new();
+
+    for track in &smf.tracks {
+        let mut tick = 0u64;
+
+        for event in track {
+            tick += event.delta.as_int() as u64;
+
+            match event.kind {
+                TrackEventKind::Midi { channel, message } => {
+                    let channel = channel.as_int();
+
+                    if options.ignore_percussion && channel == PERCUSSION_CHANNEL {
+                        continue;
+                    }
+
+                    match message {
+                        MidiMessage::NoteOn { key, vel } => note_events.push((tick, channel, key.as_int(), vel.as_int() > 0)),
+                        MidiMessage::NoteOff { key, .. } => note_events.push((tick, channel, key.as_int(), false)),
+                        _ => {}
+                    }
+                }
+                TrackEventKind::Meta(MetaMessage::Tempo(microseconds_per_beat)) => tempos.push((tick, microseconds_per_beat.as_int())),
+                _ => {}
+            }
+        }
+    }
+
+    // Note-offs sort before note-ons at the same time.
+    note_events.sort_by_key(|(tick, _, _, is_on)| (*tick, *is_on));
+    tempos.sort_by_key(|(tick, _)| *tick);
+
+    let to_time = |tick: u64| match smf.header.timing {
+        Timing::Metrical(ticks_per_beat) => {
+            let ticks_per_beat = ticks_per_beat.as_int() as f64;
+            let mut seconds = 0f64;
+            let mut last_tick = 0;
+            let mut microseconds_per_beat = DEFAULT_MICROSECONDS_PER_BEAT;
+
+            for (tempo_tick, tempo) in tempos.iter().take_while(|(tempo_tick, _)| *tempo_tick < tick) {
+                seconds += (tempo_tick - last_tick) as f64 * microseconds_per_beat as f64 / 1_000_000.0 / ticks_per_beat;
+                last_tick = *tempo_tick;
+                microseconds_per_beat = *tempo;
+            }
+
+            seconds += (tick - last_tick) as f64 * microseconds_per_beat as f64 / 1_000_000.0 / ticks_per_beat;
+
+            Duration::from_secs_f64(seconds)
+        }
+        Timing::Timecode(fps, ticks_per_frame) => Duration::from_secs_f64(tick as f64 / (fps.as_f32() as f64 * ticks_per_frame as f64)),
+    };
+
+    let events = note_events.into_iter().map(|(tick, channel, key, is_on)| (to_time(tick), channel, key, is_on)).collect::<Vec<_>>();
+
+    // Group the note-ons, keeping track of the sounding notes (by channel and key).
+    let mut sounding = BTreeSet::new();
+    let mut result = Vec::new();
+    let mut k = 0;
+
+    while k < events.len() {
+        let (start, channel, key, is_on) = events[k];
+
+        if !is_on {
+            sounding.remove(&(channel, key));
+            k += 1;
+
+            continue;
+        }
+
+        // Notes that are struck (and even released) within the window belong to the group.
+        let mut struck = BTreeSet::new();
+
+        while k < events.len() && events[k].0 <= start + options.grouping_window {
+            let (_, channel, key, is_on) = events[k];
+
+            if is_on {
+                sounding.insert((channel, key));
+                struck.insert(key);
+            } else {
+                sounding.remove(&(channel, key));
+            }
+
+            k += 1;
+        }
+
+        let keys = sounding.iter().map(|(_, key)| *key).chain(struck).collect::<BTreeSet<_>>();
+        let notes = keys.into_iter().filter_map(|key| Note::from_midi_number(key).ok()).collect::<Vec<_>>();
+
+        if let Ok(candidates) = Chord::try_from_notes_preserving_bass_octave(&notes) {
+            if !candidates.is_empty() {
+                result.push((start, candidates));
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+/// Reads the MIDI file at the given path, and returns the candidate chords at each onset (see [`midi_to_chords`]).
+pub fn midi_file_to_chords(path: impl AsRef<Path>, options: MidiImportOptions) -> Res<Vec<(Duration, Vec<Chord>)>> {
+    midi_to_chords(&std::fs::read(path)?, options)
+}
+
+// Tests.
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{
+        base::{HasName, Parsable},
+        chord::Chordable,
+        note::*,
+        octave::Octave,
+    };
+    use pretty_assertions::assert_eq;
+
+    /// Parses the bytes, and returns the `(absolute time, key, is note-on)` note events.
+    fn note_events(bytes: &[u8]) -> Vec<(u32, u8, bool)> {
+        let smf = Smf::parse(bytes).unwrap();
+        let mut time = 0;
+
+        assert_eq!(smf.header.format, Format::SingleTrack);
+        assert_eq!(smf.tracks.len(), 1);
+
+        smf.tracks[0]
+            .iter()
+            .filter_map(|event| {
+                time += event.delta.as_int();
+
+                match event.kind {
+                    TrackEventKind::Midi {
+                        message: MidiMessage::NoteOn { key, .. }, ..
+                    } => Some((time, key.as_int(), true)),
+                    TrackEventKind::Midi {
+                        message: MidiMessage::NoteOff { key, .. },
+                        ..
+                    } => Some((time, key.as_int(), false)),
+                    _ => None,
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_chord_to_midi() {
+        let bytes = Chord::new(C).to_midi_bytes(2.0, MidiExportOptions { tempo: 90.0, velocity: 80 }).unwrap();
+        let smf = Smf::parse(&bytes).unwrap();
+
+        assert_eq!(smf.header.timing, Timing::Metrical(u15::new(TICKS_PER_BEAT)));
+        assert_eq!(smf.tracks[0][0].kind, TrackEventKind::Meta(MetaMessage::Tempo(u24::new(666_667))));
+        assert!(smf.tracks[0]
+            .iter()
+            .any(|e| matches!(e.kind, TrackEventKind::Midi { message: MidiMessage::NoteOn { vel, .. }, .. } if vel == 80)));
+
+        assert_eq!(
+            note_events(&bytes),
+            vec![(0, 60, true), (0, 64, true), (0, 67, true), (960, 60, false), (960, 64, false), (960, 67, false)]
+        );
+
+        assert!(Chord::new(C).to_midi_bytes(0.0, MidiExportOptions::default()).is_err());
+        assert!(Chord::new(C).to_midi_bytes(1.0, MidiExportOptions { tempo: 120.0, velocity: 0 }).is_err());
+
+        // Keys above `G9` are out of range (rather than wrapping or saturating).
+        assert!(Chord::new(C).with_octave(Octave::Nine).to_midi_bytes(1.0, MidiExportOptions::default()).is_ok());
+        assert!(Chord::new(C).seven().with_octave(Octave::Nine).to_midi_bytes(1.0, MidiExportOptions::default()).is_err());
+    }
+
+    #[test]
+    fn test_progression_to_midi() {
+        let chords = [Chord::new(C), Chord::parse("G7").unwrap(), Chord::new(C).minor()];
+        let events = note_events(&progression_to_midi(&chords, 1.0, MidiExportOptions::with_tempo(100.0)).unwrap());
+
+        // The chords are laid out back to back, and the shared `G` is released before it is struck again.
+        assert_eq!(events.len(), 20);
+        assert_eq!(
+            &events[3..11],
+            &[
+                (480, 60, false),
+                (480, 64, false),
+                (480, 67, false),
+                (480, 67, true),
+                (480, 71, true),
+                (480, 74, true),
+                (480, 77, true),
+                (960, 67, false),
+            ]
+        );
+        assert_eq!(events.last(), Some(&(1440, 67, false)));
+    }
+
+    #[test]
+    fn test_midi_to_chords() {
+        // A round trip through the exporter.
+        let chords = [Chord::new(C), Chord::parse("G7").unwrap(), Chord::new(C).minor()];
+        let bytes = progression_to_midi(&chords, 2.0, MidiExportOptions::with_tempo(60.0)).unwrap();
+        let imported = midi_to_chords(&bytes, MidiImportOptions::default()).unwrap();
+
+        assert_eq!(imported.iter().map(|(time, _)| time.as_secs()).collect::<Vec<_>>(), vec![0, 2, 4]);
+        assert_eq!(imported.iter().map(|(_, candidates)| candidates[0].name()).collect::<Vec<_>>(), vec!["C", "G7", "Cm"]);
+    }
+
+    #[test]
+    fn test_midi_to_chords_grouping() {
+        let event = |delta: u32, channel: u8, key: u8, is_on: bool| TrackEvent {
+            delta: u28::new(delta),
+            kind: TrackEventKind::Midi {
+                channel: u4::new(channel),
+                message: if is_on {
+                    MidiMessage::NoteOn { key: u7::new(key), vel: u7::new(100) }
+                } else {
+                    MidiMessage::NoteOff { key: u7::new(key), vel: u7::new(0) }
+                },
+            },
+        };
+
+        // A held bass `C3`, a slightly "late" `E4` and `G4`, a snare on the percussion channel, and then `F4` and `A4` over the held bass.
+        let track = vec![
+            event(0, 0, 48, true),
+            event(0, PERCUSSION_CHANNEL, 38, true),
+            event(10, 0, 64, true),
+            event(10, 0, 67, true),
+            event(460, 0, 64, false),
+            event(0, 0, 67, false),
+            event(0, PERCUSSION_CHANNEL, 38, false),
+            event(0, 0, 65, true),
+            event(0, 0, 69, true),
+            event(480, 0, 48, false),
+            event(0, 0, 65, false),
+            event(0, 0, 69, false),
+            TrackEvent {
+                delta: u28::new(0),
+                kind: TrackEventKind::Meta(MetaMessage::EndOfTrack),
+            },
+        ];
+
+        let mut smf = Smf::new(Header::new(Format::SingleTrack, Timing::Metrical(u15::new(TICKS_PER_BEAT))));
+        smf.tracks.push(track);
+
+        let mut bytes = Vec::new();
+        smf.write_std(&mut bytes).unwrap();
+
+        let imported = midi_to_chords(&bytes, MidiImportOptions::default()).unwrap();
+
+        assert_eq!(imported.len(), 2);
+        assert_eq!(imported[0].0, Duration::ZERO);
+        assert_eq!(imported[0].1[0].name(), "C");
+        assert_eq!(imported[1].0, Duration::from_millis(500));
+        assert_eq!(imported[1].1[0].name(), "F");
+
+        // Without grouping, the "late" notes are separate onsets (and the first one is not a chord).
+        let ungrouped = MidiImportOptions {
+            grouping_window: Duration::ZERO,
+            ..Default::default()
+        };
+
+        assert_eq!(midi_to_chords(&bytes, ungrouped).unwrap().len(), 2);
+        assert_eq!(midi_to_chords(&bytes, ungrouped).unwrap()[0].0, Duration::from_secs_f64(20.0 / 960.0));
+
+        // With the percussion channel, the snare is part of the first group.
+        let with_percussion = MidiImportOptions {
+            ignore_percussion: false,
+            ..Default::default()
+        };
+
+        assert_ne!(midi_to_chords(&bytes, with_percussion).unwrap()[0].1[0].name(), "C");
+    }
+}
@@ -1,14 +1,48 @@
 //! Core types and functions for the `kord` crate.
+//!
+//! Without the `std` feature (i.e., with the `no_std` feature), only the note, pitch, interval, and octave math is available.
 
+#[cfg(feature = "std")]
+pub mod abc;
+#[cfg(feature = "std")]
+pub mod analyzer;
 pub mod base;
+#[cfg(feature = "std")]
 pub mod chord;
+#[cfg(feature = "std")]
+pub mod guitar;
 pub mod helpers;
 pub mod interval;
+#[cfg(feature = "std")]
 pub mod known_chord;
+#[cfg(feature = "std")]
+pub mod lilypond;
+#[cfg(feature = "midi")]
+pub mod midi;
+#[cfg(feature = "std")]
 pub mod modifier;
 pub mod named_pitch;
 pub mod note;
+#[cfg(feature = "std")]
+pub mod note_mask;
 pub mod octave;
+#[cfg(feature = "std")]
 #[allow(missing_docs)]
 pub mod parser;
+#[cfg(feature = "std")]
+pub mod piano;
 pub mod pitch;
+#[cfg(feature = "std")]
+pub mod pitch_class_set;
+#[cfg(feature = "std")]
+pub mod poly_chord;
+#[cfg(feature = "random")]
+pub mod random;
+#[cfg(feature = "std")]
+pub mod recognizer;
+#[cfg(feature = "std")]
+pub mod scale;
+#[cfg(feature = "std")]
+pub mod tuning;
+#[cfg(feature = "std")]
+pub mod voicing;
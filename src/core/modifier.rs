@@ -68,6 +68,9 @@ pub enum Modifier {
 
     /// Diminished modifier.
     Diminished,
+
+    /// Altered modifier (i.e., a dominant with all of the altered tensions: ♭9, ♯9, ♯11, and ♭13).
+    Altered,
 }
 
 /// An enum representing the extension of a chord.
@@ -145,6 +148,8 @@ impl HasStaticName for Modifier {
 
             Modifier::Sharp11 => "♯11",
 
+            Modifier::Altered => "alt",
+
             Modifier::Diminished => "°",
         }
     }
@@ -192,7 +197,7 @@ pub fn likely_extension_sets() -> &'static [Vec<Extension>] {
 
 // Statics.
 
-static KNOWN_MODIFIER_SETS: Lazy<[Vec<Modifier>; 35]> = Lazy::new(|| {
+static KNOWN_MODIFIER_SETS: Lazy<[Vec<Modifier>; 36]> = Lazy::new(|| {
     [
         vec![],
         vec![Modifier::Minor],
@@ -229,6 +234,7 @@ static KNOWN_MODIFIER_SETS: Lazy<[Vec<Modifier>; 35]> = Lazy::new(|| {
         vec![Modifier::Sharp9, Modifier::Dominant(Degree::Nine)],
         vec![Modifier::Sharp9, Modifier::Dominant(Degree::Eleven)],
         vec![Modifier::Sharp9, Modifier::Dominant(Degree::Thirteen)],
+        vec![Modifier::Altered, Modifier::Dominant(Degree::Seven)],
     ]
 });
 
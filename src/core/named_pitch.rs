@@ -1,6 +1,9 @@
 //! A module for working with named pitches.
 
-use std::ops::{Add, Sub};
+use core::{
+    fmt::{self, Display, Formatter},
+    ops::{Add, Sub},
+};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -212,6 +215,12 @@ impl HasLetter for NamedPitch {
     }
 }
 
+impl Display for NamedPitch {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.static_name())
+    }
+}
+
 impl HasStaticName for NamedPitch {
     
     fn static_name(&self) -> &'static str {
@@ -458,6 +467,12 @@ mod tests {
         assert_eq!(NamedPitch::A.named_pitch(), NamedPitch::A);
     }
 
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", NamedPitch::FSharp), "F♯");
+        assert_eq!(format!("{}", NamedPitch::BDoubleFlat), "B𝄫");
+    }
+
     #[test]
     fn test_pitch_conversion() {
         assert_eq!(NamedPitch::from(Pitch::C), NamedPitch::C);
@@ -5,24 +5,46 @@
 #![allow(dead_code)]
 #![allow(non_upper_case_globals)]
 
-use std::{
+use core::{
     cmp::Ordering,
     fmt::{self, Display, Formatter},
     ops::{Add, AddAssign, Sub},
 };
 
+#[cfg(not(feature = "std"))]
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+
 use crate::core::{
-    base::{HasName, HasStaticName, Parsable, Res},
-    chord::Chord,
+    base::{DisplayOptions, HasName, HasStaticName, Res},
     interval::{HasEnharmonicDistance, Interval, PRIMARY_HARMONIC_SERIES},
     named_pitch::{HasNamedPitch, NamedPitch},
-    octave::{HasOctave, Octave, ALL_OCTAVES},
-    parser::{note_str_to_note, octave_str_to_octave, ChordParser, Rule},
-    pitch::{HasBaseFrequency, HasFrequency, HasPitch, Pitch, ALL_PITCHES},
+    octave::{HasOctave, Octave},
+    pitch::{HasBaseFrequency, HasFrequency, HasPitch, Pitch},
 };
-use once_cell::sync::Lazy;
 use paste::paste;
+
+#[cfg(feature = "std")]
+use crate::core::{
+    base::{Err, Parsable},
+    chord::Chord,
+    helpers::cents,
+    octave::ALL_OCTAVES,
+    parser::{normalize_symbol, note_str_to_note, octave_str_to_octave, ChordParser, Rule},
+    pitch::ALL_PITCHES,
+};
+#[cfg(feature = "std")]
+use once_cell::sync::Lazy;
+#[cfg(feature = "std")]
 use pest::Parser;
+#[cfg(feature = "std")]
+use std::str::FromStr;
+
+#[cfg(not(feature = "std"))]
+use crate::core::helpers::Float;
 
 use super::interval::ALL_INTERVALS;
 
@@ -108,6 +130,7 @@ macro_rules! define_octave {
 // Traits.
 
 /// A trait for types that can be converted into a [`Chord`].
+#[cfg(feature = "std")]
 pub trait IntoChord {
     /// Converts this type into a [`Chord`] (usually a [`Note`]).
     fn into_chord(self) -> Chord;
@@ -148,6 +171,21 @@ pub trait HasNoteId {
         Self: Sized;
 }
 
+/// A trait which allows for converting a note to and from a MIDI note number (where `C4` is `60`, and `A4` is `69`).
+pub trait HasMidiNumber {
+    /// Returns the MIDI note number of the note.
+    ///
+    /// Notes above `G9` (which are out of the MIDI range) saturate at `127`.
+    fn to_midi_number(&self) -> u8;
+
+    /// Returns the note for the given MIDI note number, using naturals and sharps.
+    ///
+    /// Numbers below `12` (i.e., octave `-1`) cannot be represented, and numbers above `127` are out of the MIDI range.
+    fn from_midi_number(number: u8) -> Res<Self>
+    where
+        Self: Sized;
+}
+
 /// A trait which allows for converting a note to the same octave, but using universal [`Pitch`]es.
 ///
 /// Essentially, this would convert an F#4 into a Gb4, since [`Pitch`]es prefer the flats.
@@ -156,6 +194,22 @@ pub trait ToUniversal {
     fn to_universal(self) -> Self;
 }
 
+/// A trait which allows for respelling a note enharmonically (e.g., `B♯3` as `C4`, or `D♭4` as `C♯4`).
+pub trait HasEnharmonics {
+    /// Returns the other spellings of the note (with up to a double sharp or double flat), from flattest to sharpest.
+    ///
+    /// The octave follows the pitch, so the equivalents of `C4` are `B♯3` and `D𝄫4`.
+    fn enharmonic_equivalents(&self) -> Vec<Self>
+    where
+        Self: Sized;
+
+    /// Respells the note with as few accidentals as possible, preferring sharps (e.g., `D♭` becomes `C♯`, and `B♯` becomes `C`).
+    fn respell_prefer_sharps(&self) -> Self;
+
+    /// Respells the note with as few accidentals as possible, preferring flats (e.g., `C♯` becomes `D♭`, and `B♯` becomes `C`).
+    fn respell_prefer_flats(&self) -> Self;
+}
+
 // Struct.
 
 /// A note type.
@@ -184,6 +238,86 @@ impl Note {
     pub fn new(pitch: NamedPitch, octave: Octave) -> Self {
         Self { named_pitch: pitch, octave }
     }
+
+    /// Returns the number of semitones between `C0` and the [`Note`] (accounting for notes like `B♯`, which cross an octave).
+    ///
+    /// Unlike [`HasMidiNumber::to_midi_number`], this does not saturate at the top of the MIDI range.
+    pub(crate) fn semitones_from_c_zero(&self) -> i16 {
+        12 * (self.octave as i16 + octave_wrap(self.named_pitch)) + self.pitch() as i16
+    }
+
+    /// Returns the name of the [`Note`] without the octave (e.g., `C♯` rather than `C♯4`).
+    pub fn name_without_octave(&self) -> String {
+        self.static_name().to_string()
+    }
+
+    /// Returns the name of the [`Note`] using the provided [`DisplayOptions`].
+    pub fn name_with_options(&self, options: DisplayOptions) -> String {
+        if options.show_octave {
+            self.name()
+        } else {
+            self.name_without_octave()
+        }
+    }
+
+    /// Returns the [`Note`] moved by the given number of octaves (e.g., `C4` up `2` octaves is `C6`).
+    ///
+    /// Returns an error if the new octave would be below `0` or above `9`.
+    pub fn transpose_octaves(&self, octaves: i8) -> Res<Note> {
+        Ok(self.with_octave(self.octave.transpose(octaves)?))
+    }
+
+    /// Returns the difference, in cents, between the frequency of the [`Note`] and the frequency of the `other` [`Note`]
+    /// (i.e., `1200 * log2(self / other)`), which is positive when the [`Note`] is higher.
+    pub fn cents_from(&self, other: &Note) -> f32 {
+        1200.0 * (self.frequency() / other.frequency()).log2()
+    }
+
+    /// Returns the equal-tempered [`Note`] (spelled with universal [`Pitch`]es) whose frequency is closest to `frequency`.
+    ///
+    /// The search covers octaves `0` through `9`, so very low (or non-positive) frequencies snap to `C0`, and very high
+    /// frequencies snap to `B9`.  The frequency of every note maps back to that note (or its universal spelling).
+    #[cfg(feature = "std")]
+    pub fn nearest_to_frequency(frequency: f32) -> Note {
+        if frequency.is_nan() || frequency <= 0.0 {
+            return CZero;
+        }
+
+        ALL_PITCH_NOTES_WITH_FREQUENCY
+            .iter()
+            .filter(|(note, _)| note.octave <= Octave::Nine)
+            .min_by(|(_, a), (_, b)| cents(*a, frequency).abs().total_cmp(&cents(*b, frequency).abs()))
+            .map(|(note, _)| *note)
+            .unwrap()
+    }
+
+    /// Returns the first `n` harmonics of the [`Note`] as `(frequency, nearest note)` pairs.
+    ///
+    /// Each harmonic is a true integer multiple of the fundamental (the first harmonic is the [`Note`]'s own frequency),
+    /// snapped to the closest [`Note`] (spelled with universal [`Pitch`]es).  Use [`Note::harmonic_series_with_cents`] to
+    /// also get how far each harmonic is from its nearest note.
+    #[cfg(feature = "std")]
+    pub fn harmonic_series(&self, n: usize) -> Vec<(f32, Note)> {
+        self.harmonic_series_with_cents(n).into_iter().map(|(frequency, note, _)| (frequency, note)).collect()
+    }
+
+    /// Returns the first `n` harmonics of the [`Note`] as `(frequency, nearest note, cents deviation)` triples.
+    ///
+    /// The deviation is the distance, in cents, from the nearest note to the harmonic (positive when the harmonic is sharp of
+    /// the note).  For example, the seventh harmonic is about a third of a semitone flat of the nearest equal-tempered note.
+    #[cfg(feature = "std")]
+    pub fn harmonic_series_with_cents(&self, n: usize) -> Vec<(f32, Note, f32)> {
+        let fundamental = self.frequency();
+
+        (1..=n)
+            .map(|k| {
+                let frequency = fundamental * k as f32;
+                let (note, note_frequency) = nearest_note(frequency);
+
+                (frequency, note, cents(note_frequency, frequency))
+            })
+            .collect()
+    }
 }
 
 impl Note {
@@ -199,12 +333,36 @@ impl Note {
         get_notes_from_microphone(length_in_seconds).await
     }
 
-    /// Attempts to use the provided to identify the notes in the audio data.
+    /// Attempts to identify the notes in the audio data, which was recorded over `length_in_seconds`
+    /// (see [`AnalysisConfig::from_recording`](crate::analyze::base::AnalysisConfig::from_recording)).
     #[cfg(feature = "analyze_base")]
     pub fn try_from_audio(data: &[f32], length_in_seconds: u8) -> Res<Vec<Note>> {
-        use crate::analyze::base::get_notes_from_audio_data;
+        use crate::analyze::base::AnalysisConfig;
+
+        Note::try_from_audio_with_config(data, &AnalysisConfig::from_recording(data.len(), length_in_seconds))
+    }
+
+    /// Attempts to identify the notes in the audio data, which is described by the provided [`AnalysisConfig`](crate::analyze::base::AnalysisConfig).
+    ///
+    /// Unlike [`Note::try_from_audio`], this allows the sample rate (and frequency space size) to be specified explicitly.
+    #[cfg(feature = "analyze_base")]
+    pub fn try_from_audio_with_config(data: &[f32], config: &crate::analyze::base::AnalysisConfig) -> Res<Vec<Note>> {
+        use crate::analyze::base::{get_notes_from_audio_data_with_config, AnalyzeOptions};
+
+        get_notes_from_audio_data_with_config(data, config, &AnalyzeOptions::default())
+    }
+
+    /// Attempts to identify the notes in each of the provided audio clips.
+    ///
+    /// With the `analyze_parallel` feature, the clips are processed in parallel.
+    #[cfg(feature = "analyze_base")]
+    pub fn try_from_audio_batch<T>(clips: &[T], length_in_seconds: u8) -> Res<Vec<Vec<Note>>>
+    where
+        T: AsRef<[f32]> + Sync,
+    {
+        use crate::analyze::base::get_notes_from_audio_data_batch;
 
-        get_notes_from_audio_data(data, length_in_seconds)
+        get_notes_from_audio_data_batch(clips, length_in_seconds)
     }
 
     /// Attempts to use the default microphone to listen to audio for the specified time
@@ -228,6 +386,23 @@ impl Note {
 
         infer(data, length_in_seconds)
     }
+
+    /// Attempts to use the provided to identify the notes in the audio data using ML, where a note is detected if the
+    /// model's probability for it is at least `threshold` (which is `0.5` for [`Note::try_from_audio_ml`]).
+    #[cfg(all(feature = "ml_infer", feature = "analyze_base"))]
+    pub fn try_from_audio_ml_with_threshold(data: &[f32], length_in_seconds: u8, threshold: f32) -> Res<Vec<Self>> {
+        use crate::ml::infer::infer_with_threshold;
+
+        infer_with_threshold(data, length_in_seconds, threshold)
+    }
+
+    /// Attempts to use the provided audio data to infer the probability of every note using ML, sorted by descending probability.
+    #[cfg(all(feature = "ml_infer", feature = "analyze_base"))]
+    pub fn try_from_audio_ml_probabilities(data: &[f32], length_in_seconds: u8) -> Res<Vec<(Self, f32)>> {
+        use crate::ml::infer::infer_probabilities_from_audio;
+
+        infer_probabilities_from_audio(data, length_in_seconds)
+    }
 }
 
 impl HasPitch for Note {
@@ -279,18 +454,21 @@ impl HasFrequency for Note {
     }
 }
 
+#[cfg(feature = "std")]
 impl IntoChord for Note {
     fn into_chord(self) -> Chord {
         Chord::new(self)
     }
 }
 
+#[cfg(feature = "std")]
 impl Parsable for Note {
     fn parse(input: &str) -> Res<Self>
     where
         Self: Sized,
     {
-        let root = ChordParser::parse(Rule::note_with_octave, input)?.next().unwrap();
+        let input = normalize_symbol(input);
+        let root = ChordParser::parse(Rule::note_with_octave, &input)?.next().unwrap();
 
         assert_eq!(Rule::note_with_octave, root.as_rule());
 
@@ -314,6 +492,47 @@ impl Parsable for Note {
     }
 }
 
+#[cfg(feature = "std")]
+impl FromStr for Note {
+    type Err = Err;
+
+    fn from_str(s: &str) -> Res<Self> {
+        Self::parse(s)
+    }
+}
+
+#[cfg(feature = "std")]
+impl TryFrom<&str> for Note {
+    type Error = Err;
+
+    fn try_from(value: &str) -> Res<Self> {
+        Self::parse(value)
+    }
+}
+
+#[cfg(feature = "std")]
+impl TryFrom<String> for Note {
+    type Error = Err;
+
+    fn try_from(value: String) -> Res<Self> {
+        Self::parse(&value)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Note {
+    /// Parses a list of [`Note`]s (e.g., `"C E G"`, `"C, E, G"`, or `"C-E-G"`).
+    ///
+    /// The notes may be separated by any mix of whitespace, commas, hyphens, and slashes.
+    pub fn parse_list(input: &str) -> Res<Vec<Note>> {
+        input
+            .split(|c: char| c.is_whitespace() || matches!(c, ',' | '-' | '/'))
+            .filter(|s| !s.is_empty())
+            .map(Note::parse)
+            .collect()
+    }
+}
+
 impl NoteRecreator for Note {
     fn with_named_pitch(self, named_pitch: NamedPitch) -> Self {
         Self::new(named_pitch, self.octave)
@@ -382,12 +601,83 @@ impl HasNoteId for Note {
     }
 }
 
+impl HasMidiNumber for Note {
+    fn to_midi_number(&self) -> u8 {
+        (self.semitones_from_c_zero() + 12).clamp(0, 127) as u8
+    }
+
+    fn from_midi_number(number: u8) -> Res<Self> {
+        if number > 127 {
+            return Err(anyhow::Error::msg("MIDI note numbers must be in the range 0 - 127."));
+        }
+
+        if number < 12 {
+            return Err(anyhow::Error::msg("MIDI note numbers below 12 (octave -1) cannot be represented."));
+        }
+
+        let octave = Octave::try_from(number / 12 - 1).map_err(anyhow::Error::msg)?;
+        let pitch = Pitch::try_from(number % 12).map_err(anyhow::Error::msg)?;
+
+        let named_pitch = match pitch {
+            Pitch::DFlat => NamedPitch::CSharp,
+            Pitch::EFlat => NamedPitch::DSharp,
+            Pitch::GFlat => NamedPitch::FSharp,
+            Pitch::AFlat => NamedPitch::GSharp,
+            Pitch::BFlat => NamedPitch::ASharp,
+            _ => NamedPitch::from(pitch),
+        };
+
+        Ok(Self::new(named_pitch, octave))
+    }
+}
+
 impl ToUniversal for Note {
     fn to_universal(self) -> Note {
         self.with_named_pitch(NamedPitch::from(self.pitch()))
     }
 }
 
+impl HasEnharmonics for Note {
+    fn enharmonic_equivalents(&self) -> Vec<Note> {
+        // Named pitches are ordered by fifths, so twelve steps in either direction is the same pitch.
+        let index = self.named_pitch as i8;
+        let octave = self.octave as i16 + octave_wrap(self.named_pitch);
+
+        [-24i8, -12, 12, 24]
+            .into_iter()
+            .filter(|shift| (NamedPitch::FDoubleFlat as i8..=NamedPitch::BDoubleSharp as i8).contains(&(index + shift)))
+            .filter_map(|shift| {
+                let named_pitch = self.named_pitch + shift;
+                let octave = u8::try_from(octave - octave_wrap(named_pitch)).ok()?;
+
+                Some(Note::new(named_pitch, Octave::try_from(octave).ok()?))
+            })
+            .collect()
+    }
+
+    fn respell_prefer_sharps(&self) -> Note {
+        core::iter::once(*self)
+            .chain(self.enharmonic_equivalents())
+            .min_by_key(|n| {
+                let accidentals = accidentals(n.named_pitch);
+
+                (accidentals.abs(), -accidentals)
+            })
+            .unwrap_or(*self)
+    }
+
+    fn respell_prefer_flats(&self) -> Note {
+        core::iter::once(*self)
+            .chain(self.enharmonic_equivalents())
+            .min_by_key(|n| {
+                let accidentals = accidentals(n.named_pitch);
+
+                (accidentals.abs(), accidentals)
+            })
+            .unwrap_or(*self)
+    }
+}
+
 impl Sub for Note {
     type Output = Interval;
 
@@ -504,6 +794,33 @@ impl Ord for Note {
     }
 }
 
+// Helpers.
+
+/// Returns the [`Note`] (with universal [`Pitch`]es) whose frequency is closest to `frequency`, along with that note's frequency.
+///
+/// Frequencies outside of the supported octaves snap to the lowest or highest note.
+#[cfg(feature = "std")]
+fn nearest_note(frequency: f32) -> (Note, f32) {
+    *ALL_PITCH_NOTES_WITH_FREQUENCY
+        .iter()
+        .min_by(|(_, a), (_, b)| cents(*a, frequency).abs().total_cmp(&cents(*b, frequency).abs()))
+        .unwrap()
+}
+
+/// Returns the octave offset of a named pitch that "wraps" into the adjacent octave (e.g., `B♯3` is the same pitch as `C4`).
+fn octave_wrap(named_pitch: NamedPitch) -> i16 {
+    match named_pitch {
+        NamedPitch::ATripleSharp | NamedPitch::BTripleSharp | NamedPitch::BDoubleSharp | NamedPitch::BSharp => 1,
+        NamedPitch::DTripleFlat | NamedPitch::CTripleFlat | NamedPitch::CDoubleFlat | NamedPitch::CFlat => -1,
+        _ => 0,
+    }
+}
+
+/// Returns the number of accidentals of a named pitch (negative for flats, and positive for sharps).
+fn accidentals(named_pitch: NamedPitch) -> i8 {
+    named_pitch as i8 / 7 - 3
+}
+
 // Define octaves.
 
 define_octave!(Zero, Octave::Zero);
@@ -628,6 +945,7 @@ pub const BTripleSharp: Note = BTripleSharpFour;
 // Statics.
 
 /// All the notes in all octaves.
+#[cfg(feature = "std")]
 pub static ALL_PITCH_NOTES: Lazy<[Note; 192]> = Lazy::new(|| {
     let mut all_notes = Vec::with_capacity(132);
 
@@ -644,6 +962,7 @@ pub static ALL_PITCH_NOTES: Lazy<[Note; 192]> = Lazy::new(|| {
 });
 
 /// All the notes in all octaves with their frequency.
+#[cfg(feature = "std")]
 pub static ALL_PITCH_NOTES_WITH_FREQUENCY: Lazy<[(Note, f32); 192]> = Lazy::new(|| {
     let mut all_notes = Vec::with_capacity(132);
 
@@ -665,6 +984,13 @@ mod tests {
     fn test_text() {
         assert_eq!(CFlat.static_name(), "C♭");
         assert_eq!(C.to_string(), "C4");
+
+        assert_eq!(CSharpFive.name(), "C♯5");
+        assert_eq!(format!("{}", CSharpFive), "C♯5");
+        assert_eq!(CSharpFive.name_without_octave(), "C♯");
+        assert_eq!(BFlatThree.name_without_octave(), "B♭");
+        assert_eq!(E.name_with_options(DisplayOptions::default()), "E4");
+        assert_eq!(E.name_with_options(DisplayOptions::without_octave()), "E");
     }
 
     #[test]
@@ -758,6 +1084,23 @@ mod tests {
         assert_eq!(C - D, Interval::MajorSecond);
         assert_eq!(D - C, Interval::MajorSecond);
         assert_eq!(C - E, Interval::MajorThird);
+        assert_eq!(G - C, Interval::PerfectFifth);
+        assert_eq!(C - G, Interval::PerfectFifth);
+        assert_eq!(CFive - G, Interval::PerfectFourth);
+        assert_eq!(FSharp - C, Interval::AugmentedFourth);
+        assert_eq!(GFlat - C, Interval::DiminishedFifth);
+
+        // Subtracting the interval undoes the addition.
+        for interval in [Interval::MinorThird, Interval::PerfectFifth, Interval::MajorSeventh, Interval::MajorNinth] {
+            assert_eq!((C + interval) - interval, C);
+            assert_eq!((C + interval) - C, interval);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Octave underflow.")]
+    fn test_sub_interval_underflow() {
+        let _ = CZero - Interval::MinorSecond;
     }
 
     #[test]
@@ -766,6 +1109,42 @@ mod tests {
         assert_eq!(Note::parse("C#").unwrap(), CSharp);
         assert_eq!(Note::parse("Bb3").unwrap(), BFlatThree);
         assert_eq!(Note::parse("D#7").unwrap(), DSharpSeven);
+
+        // Note letters are case-insensitive, and ASCII / Unicode accidentals are equivalent.
+        assert_eq!(Note::parse("bb3").unwrap(), BFlatThree);
+        assert_eq!(Note::parse(" d♯7 ").unwrap(), DSharpSeven);
+    }
+
+    #[test]
+    fn test_parse_triple_accidentals() {
+        assert_eq!(Note::parse("C###").unwrap(), CTripleSharp);
+        assert_eq!(Note::parse("Fbbb2").unwrap(), FTripleFlatTwo);
+
+        for letter in ["A", "B", "C", "D", "E", "F", "G"] {
+            for (sharps, flats) in [("###", "bbb"), ("♯𝄪", "♭𝄫")] {
+                assert_eq!(Note::parse(&format!("{letter}{sharps}")).unwrap().static_name(), format!("{letter}♯𝄪"));
+                assert_eq!(Note::parse(&format!("{letter}{flats}")).unwrap().static_name(), format!("{letter}♭𝄫"));
+            }
+        }
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("C#".parse::<Note>().unwrap(), CSharp);
+        assert_eq!(Note::try_from("Bb3").unwrap(), BFlatThree);
+        assert_eq!(Note::try_from("D7".to_owned()).unwrap(), DSeven);
+        assert!("H".parse::<Note>().is_err());
+        assert!(Note::try_from("").is_err());
+    }
+
+    #[test]
+    fn test_parse_list() {
+        assert_eq!(Note::parse_list("C E G").unwrap(), vec![C, E, G]);
+        assert_eq!(Note::parse_list("C, E, G").unwrap(), vec![C, E, G]);
+        assert_eq!(Note::parse_list("C-E-G").unwrap(), vec![C, E, G]);
+        assert_eq!(Note::parse_list(" Bb3 /D,  F-  A ").unwrap(), vec![BFlatThree, D, F, A]);
+        assert_eq!(Note::parse_list("").unwrap(), vec![]);
+        assert!(Note::parse_list("C, H, G").is_err());
     }
 
     #[test]
@@ -783,6 +1162,69 @@ mod tests {
         assert_eq!(BDoubleSharpFive.with_named_pitch(NamedPitch::A).frequency(), AFive.frequency());
     }
 
+    #[test]
+    fn test_transpose_octaves() {
+        assert_eq!(C.transpose_octaves(2).unwrap(), CSix);
+        assert_eq!(BFlatThree.transpose_octaves(-2).unwrap(), BFlatOne);
+        assert_eq!(C.with_octave(Octave::Nine).transpose_octaves(0).unwrap(), CNine);
+        assert!(CNine.transpose_octaves(1).is_err());
+        assert!(CZero.transpose_octaves(-1).is_err());
+    }
+
+    #[test]
+    fn test_harmonic_series() {
+        let series = AZero.harmonic_series(8);
+
+        assert_eq!(series.len(), 8);
+        assert_eq!(series.iter().map(|(_, n)| *n).collect::<Vec<_>>(), vec![AZero, AOne, ETwo, ATwo, DFlatThree, EThree, GThree, AThree]);
+        assert_eq!(series[2].0, 82.5);
+
+        let series = A.harmonic_series_with_cents(7);
+
+        // The seventh harmonic is roughly 31 cents flat of the nearest equal-tempered note.
+        let (frequency, note, deviation) = series[6];
+        assert_eq!(frequency, 3080.0);
+        assert_eq!(note, GSeven);
+        assert!((deviation + 31.2).abs() < 1.0, "{deviation}");
+
+        // The octaves are exact.
+        assert_eq!(series[0], (440.0, A, 0.0));
+        assert_eq!(series[3].1, ASix);
+        assert!(series[3].2.abs() < 0.01);
+
+        assert!(A.harmonic_series(0).is_empty());
+    }
+
+    #[test]
+    fn test_nearest_to_frequency() {
+        assert_eq!(Note::nearest_to_frequency(440.0), A);
+        assert_eq!(Note::nearest_to_frequency(C.frequency()), C);
+        assert_eq!(Note::nearest_to_frequency(445.0), A);
+        assert_eq!(Note::nearest_to_frequency(460.0), BFlat);
+        assert_eq!(Note::nearest_to_frequency(CSharp.frequency()), DFlat);
+
+        // Every note (in octaves 0 through 9) maps back to itself.
+        for (note, frequency) in ALL_PITCH_NOTES_WITH_FREQUENCY.iter().filter(|(n, _)| n.octave() <= Octave::Nine) {
+            assert_eq!(Note::nearest_to_frequency(*frequency), *note);
+        }
+
+        // The extremes clamp to the lowest and highest notes.
+        assert_eq!(Note::nearest_to_frequency(1.0), CZero);
+        assert_eq!(Note::nearest_to_frequency(0.0), CZero);
+        assert_eq!(Note::nearest_to_frequency(-440.0), CZero);
+        assert_eq!(Note::nearest_to_frequency(f32::NAN), CZero);
+        assert_eq!(Note::nearest_to_frequency(1_000_000.0), BNine);
+    }
+
+    #[test]
+    fn test_cents_from() {
+        // The base frequencies are rounded, so equal-tempered steps are only accurate to about a cent.
+        assert_eq!(A.cents_from(&A), 0.0);
+        assert!((ASharp.cents_from(&A) - 100.0).abs() < 1.0);
+        assert!((A.cents_from(&AFive) + 1200.0).abs() < 0.01);
+        assert_eq!(BSharp.cents_from(&CFive), 0.0);
+    }
+
     #[test]
     fn test_harmonics() {
         assert_eq!(
@@ -821,6 +1263,60 @@ mod tests {
         assert_eq!(Note::from_id_mask(1 << 13 | 1 << 48).unwrap(), vec![DFlatOne, CFour]);
     }
 
+    #[test]
+    fn test_midi_number() {
+        assert_eq!(C.to_midi_number(), 60);
+        assert_eq!(A.to_midi_number(), 69);
+        assert_eq!(CZero.to_midi_number(), 12);
+        assert_eq!(AZero.to_midi_number(), 21);
+        assert_eq!(CEight.to_midi_number(), 108);
+        assert_eq!(GNine.to_midi_number(), 127);
+
+        // Enharmonic spellings that cross the octave boundary.
+        assert_eq!(BSharpThree.to_midi_number(), 60);
+        assert_eq!(CFlat.to_midi_number(), 59);
+        assert_eq!(Note::new(NamedPitch::A, Octave::Ten).to_midi_number(), 127);
+
+        assert_eq!(Note::from_midi_number(60).unwrap(), C);
+        assert_eq!(Note::from_midi_number(61).unwrap(), CSharp);
+        assert_eq!(Note::from_midi_number(70).unwrap(), ASharp);
+        assert_eq!(Note::from_midi_number(12).unwrap(), CZero);
+        assert_eq!(Note::from_midi_number(127).unwrap(), GNine);
+
+        // Every representable number round trips.
+        for number in 12..=127 {
+            assert_eq!(Note::from_midi_number(number).unwrap().to_midi_number(), number);
+        }
+
+        assert!(Note::from_midi_number(11).is_err());
+        assert!(Note::from_midi_number(128).is_err());
+    }
+
+    #[test]
+    fn test_enharmonics() {
+        assert_eq!(C.enharmonic_equivalents(), vec![DDoubleFlat, BSharpThree]);
+        assert_eq!(DFlat.enharmonic_equivalents(), vec![CSharp, BDoubleSharpThree]);
+        assert_eq!(GSharp.enharmonic_equivalents(), vec![AFlat]);
+        assert_eq!(E.enharmonic_equivalents(), vec![FFlat, DDoubleSharp]);
+        assert_eq!(CFlat.enharmonic_equivalents(), vec![BThree, ADoubleSharpThree]);
+
+        // Equivalent spellings are the same pitch.
+        for note in [C, DFlat, E, CFlat, BSharp, FDoubleSharp] {
+            for equivalent in note.enharmonic_equivalents() {
+                assert_eq!(equivalent.to_midi_number(), note.to_midi_number());
+                assert_eq!(equivalent.frequency(), note.frequency());
+            }
+        }
+
+        assert_eq!(DFlat.respell_prefer_sharps(), CSharp);
+        assert_eq!(CSharp.respell_prefer_flats(), DFlat);
+        assert_eq!(BSharpThree.respell_prefer_sharps(), C);
+        assert_eq!(BSharpThree.respell_prefer_flats(), C);
+        assert_eq!(EDoubleFlat.respell_prefer_sharps(), D);
+        assert_eq!(FDoubleSharp.respell_prefer_flats(), G);
+        assert_eq!(C.respell_prefer_sharps(), C);
+    }
+
     #[test]
     fn test_universal() {
         assert_eq!(FSharpFive.to_universal(), Note::parse("Gb5").unwrap());
@@ -0,0 +1,215 @@
+//! A module that contains the [`NoteMask`] type, which is a compact set of [`Note`]s.
+
+use std::ops::{BitAnd, BitOr};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{
+    base::Res,
+    note::{HasNoteId, Note},
+    octave::HasOctave,
+    pitch::HasPitch,
+};
+
+// Struct.
+
+/// A compact set of [`Note`]s, backed by a [`u128`] (the same representation as [`HasNoteId::id_mask`]).
+///
+/// Bit `12 * octave + pitch` is set when the note is in the set, where `pitch` is the index of the note's
+/// [`Pitch`](crate::core::pitch::Pitch) (`C` is `0`, `D♭` is `1`, ..., `B` is `11`).  So, bit `0` is `C0`,
+/// bit `11` is `B0`, bit `48` is `C4`, and bit `127` is `G10`.  Notes above `G10` cannot be represented.
+///
+/// Since the mask stores pitches, enharmonic notes share a bit (e.g., `C♯4` and `D♭4`), and notes
+/// read back from the mask use the "universal" (flat-preferring) spelling.
+#[derive(PartialEq, Eq, Copy, Clone, Hash, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct NoteMask(u128);
+
+// Impls.
+
+impl NoteMask {
+    /// Returns a new, empty [`NoteMask`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a new [`NoteMask`] containing the given notes.
+    pub fn from_notes(notes: &[Note]) -> Self {
+        let mut mask = Self::new();
+
+        for note in notes {
+            mask.insert(*note);
+        }
+
+        mask
+    }
+
+    /// Returns the notes in the mask, from lowest to highest.
+    pub fn to_notes(&self) -> Vec<Note> {
+        self.iter().collect()
+    }
+
+    /// Returns the underlying bits of the mask.
+    pub fn bits(&self) -> u128 {
+        self.0
+    }
+
+    /// Adds a note to the mask, and returns whether or not it was newly added.
+    ///
+    /// Notes that cannot be represented (above `G10`) are ignored.
+    pub fn insert(&mut self, note: Note) -> bool {
+        match Self::bit(note) {
+            Some(bit) => {
+                let is_new = self.0 & bit == 0;
+                self.0 |= bit;
+
+                is_new
+            }
+            None => false,
+        }
+    }
+
+    /// Removes a note from the mask, and returns whether or not it was present.
+    pub fn remove(&mut self, note: Note) -> bool {
+        match Self::bit(note) {
+            Some(bit) => {
+                let was_present = self.0 & bit != 0;
+                self.0 &= !bit;
+
+                was_present
+            }
+            None => false,
+        }
+    }
+
+    /// Returns whether or not the mask contains the note (or an enharmonic equivalent of it).
+    pub fn contains(&self, note: Note) -> bool {
+        Self::bit(note).map(|bit| self.0 & bit != 0).unwrap_or(false)
+    }
+
+    /// Returns the number of notes in the mask.
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    /// Returns whether or not the mask is empty.
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns an iterator over the notes in the mask, from lowest to highest.
+    pub fn iter(&self) -> impl Iterator<Item = Note> {
+        let bits = self.0;
+
+        (0..128u32).filter(move |shift| bits & (1 << shift) != 0).filter_map(|shift| Note::from_id(1 << shift).ok())
+    }
+
+    /// Returns the bit for the note, if it can be represented.
+    fn bit(note: Note) -> Option<u128> {
+        let shift = 12 * note.octave() as u32 + note.pitch() as u32;
+
+        1u128.checked_shl(shift)
+    }
+}
+
+impl From<u128> for NoteMask {
+    fn from(bits: u128) -> Self {
+        Self(bits)
+    }
+}
+
+impl From<NoteMask> for u128 {
+    fn from(mask: NoteMask) -> Self {
+        mask.0
+    }
+}
+
+impl TryFrom<NoteMask> for Vec<Note> {
+    type Error = crate::core::base::Err;
+
+    fn try_from(mask: NoteMask) -> Res<Self> {
+        Note::from_id_mask(mask.0)
+    }
+}
+
+impl FromIterator<Note> for NoteMask {
+    fn from_iter<T: IntoIterator<Item = Note>>(iter: T) -> Self {
+        let mut mask = Self::new();
+
+        for note in iter {
+            mask.insert(note);
+        }
+
+        mask
+    }
+}
+
+impl BitOr for NoteMask {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl BitAnd for NoteMask {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        Self(self.0 & rhs.0)
+    }
+}
+
+// Tests.
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::note::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_round_trip() {
+        let notes = vec![CTwo, E, G, BFlat, DFive];
+        let mask = NoteMask::from_notes(&notes);
+
+        assert_eq!(mask.len(), 5);
+        assert_eq!(mask.to_notes(), notes);
+        assert_eq!(mask.bits(), Note::id_mask(&notes));
+        assert_eq!(NoteMask::from(Note::id_mask(&notes)), mask);
+        assert_eq!(Vec::<Note>::try_from(mask).unwrap(), notes);
+        assert_eq!(notes.iter().copied().collect::<NoteMask>(), mask);
+
+        // Enharmonic notes share a bit, and are read back with the universal spelling.
+        assert_eq!(NoteMask::from_notes(&[CSharp, DFlat]).to_notes(), vec![DFlat]);
+    }
+
+    #[test]
+    fn test_membership() {
+        let mut mask = NoteMask::new();
+
+        assert!(mask.is_empty());
+        assert!(mask.insert(C));
+        assert!(!mask.insert(C));
+        assert!(mask.insert(FSharp));
+
+        assert!(mask.contains(C));
+        assert!(mask.contains(GFlat));
+        assert!(!mask.contains(CFive));
+
+        assert!(mask.remove(C));
+        assert!(!mask.remove(C));
+        assert!(!mask.contains(C));
+
+        assert_eq!(mask.bits(), 1 << 54);
+        assert_eq!(NoteMask::from_notes(&[CZero]).bits(), 1);
+
+        // Set operations.
+        let a = NoteMask::from_notes(&[C, E, G]);
+        let b = NoteMask::from_notes(&[E, G, B]);
+
+        assert_eq!((a & b).to_notes(), vec![E, G]);
+        assert_eq!((a | b).to_notes(), vec![C, E, G, B]);
+    }
+}
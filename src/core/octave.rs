@@ -1,10 +1,8 @@
 //! A module for the octave of a note.
 
-use std::ops::{Add, AddAssign, Sub, SubAssign};
+use core::ops::{Add, AddAssign, Sub, SubAssign};
 
-use once_cell::sync::Lazy;
-
-use crate::core::base::HasStaticName;
+use crate::core::base::{HasStaticName, Res};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -61,6 +59,22 @@ pub enum Octave {
 
 // Octave impls.
 
+impl Octave {
+    /// Returns the octave moved by the given number of octaves (e.g., `Octave::Four.transpose(-1)` is `Octave::Three`).
+    ///
+    /// Unlike `+` / `-` (which panic outside of the representable octaves), this returns an error if the result would be
+    /// outside of the playable range (i.e., below [`Octave::Zero`] or above [`Octave::Nine`]).
+    pub fn transpose(self, octaves: i8) -> Res<Octave> {
+        let new_octave = self as i8 + octaves;
+
+        if !(Octave::Zero as i8..=Octave::Nine as i8).contains(&new_octave) {
+            return Err(crate::core::base::Err::msg("Octaves must be in the range 0 - 9."));
+        }
+
+        Ok(self + octaves)
+    }
+}
+
 impl HasStaticName for Octave {
     #[inline]
     fn static_name(&self) -> &'static str {
@@ -94,7 +108,7 @@ impl Add for Octave {
         assert!(new_octave <= 15, "Octave overflow");
 
         // SAFETY: The new octave is guaranteed to be less than or equal to 15.
-        unsafe { std::mem::transmute(new_octave) }
+        unsafe { core::mem::transmute(new_octave) }
     }
 }
 
@@ -107,7 +121,7 @@ impl Sub for Octave {
         assert!(new_octave <= 15, "Octave overflow");
 
         // SAFETY: The new octave is guaranteed to be less than or equal to 15.
-        unsafe { std::mem::transmute(new_octave) }
+        unsafe { core::mem::transmute(new_octave) }
     }
 }
 
@@ -119,7 +133,7 @@ impl TryFrom<u8> for Octave {
             Err("Octave overflow.")
         } else {
             // SAFETY: The new octave is guaranteed to be less than or equal to 15.
-            Ok(unsafe { std::mem::transmute(value) })
+            Ok(unsafe { core::mem::transmute(value) })
         }
     }
 }
@@ -137,7 +151,7 @@ impl Add<i8> for Octave {
         }
 
         // SAFETY: The new octave is guaranteed to be less than or equal to 15.
-        unsafe { std::mem::transmute(new_octave) }
+        unsafe { core::mem::transmute(new_octave) }
     }
 }
 
@@ -176,26 +190,24 @@ impl HasOctave for Octave {
 // Statics.
 
 /// An array of all octaves.
-pub static ALL_OCTAVES: Lazy<[Octave; 16]> = Lazy::new(|| {
-    [
-        Octave::Zero,
-        Octave::One,
-        Octave::Two,
-        Octave::Three,
-        Octave::Four,
-        Octave::Five,
-        Octave::Six,
-        Octave::Seven,
-        Octave::Eight,
-        Octave::Nine,
-        Octave::Ten,
-        Octave::Eleven,
-        Octave::Twelve,
-        Octave::Thirteen,
-        Octave::Fourteen,
-        Octave::Fifteen,
-    ]
-});
+pub static ALL_OCTAVES: [Octave; 16] = [
+    Octave::Zero,
+    Octave::One,
+    Octave::Two,
+    Octave::Three,
+    Octave::Four,
+    Octave::Five,
+    Octave::Six,
+    Octave::Seven,
+    Octave::Eight,
+    Octave::Nine,
+    Octave::Ten,
+    Octave::Eleven,
+    Octave::Twelve,
+    Octave::Thirteen,
+    Octave::Fourteen,
+    Octave::Fifteen,
+];
 
 // Tests.
 
@@ -262,6 +274,15 @@ mod tests {
         assert_eq!(Octave::default(), Octave::Four);
     }
 
+    #[test]
+    fn test_transpose() {
+        assert_eq!(Octave::Four.transpose(1).unwrap(), Octave::Five);
+        assert_eq!(Octave::Four.transpose(-4).unwrap(), Octave::Zero);
+        assert_eq!(Octave::Four.transpose(5).unwrap(), Octave::Nine);
+        assert!(Octave::Nine.transpose(1).is_err());
+        assert!(Octave::Zero.transpose(-1).is_err());
+    }
+
     #[test]
     fn test_names() {
         assert_eq!(ALL_OCTAVES.map(|o| o.static_name()).join(" "), "0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15");
@@ -1,6 +1,9 @@
 //! A module for working with the parser for chord symbols.
 
+use std::fmt::Display;
+
 use pest_derive::Parser;
+use unicode_normalization::UnicodeNormalization;
 
 use crate::core::{
     base::Res,
@@ -15,8 +18,85 @@ use crate::core::{
 #[grammar = "../chord.pest"]
 pub struct ChordParser;
 
+/// A parse error that also carries suggested corrections for the input.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseErrorWithSuggestions {
+    /// The underlying parse error message.
+    pub message: String,
+    /// The suggested corrections, ranked from most to least likely.
+    pub suggestions: Vec<String>,
+}
+
+impl Display for ParseErrorWithSuggestions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)?;
+
+        if let Some(suggestion) = self.suggestions.first() {
+            write!(f, "\n\nDid you mean `{}`?", suggestion)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseErrorWithSuggestions {}
+
 // Helpers.
 
+/// Normalizes a chord (or note) symbol before it is parsed, so that, e.g., `cmaj7`, `Cmaj7`, and `CMAJ7` are all the same chord.
+///
+/// The input is trimmed and NFC normalized, note letters (at the start, and after a slash) are upper-cased, and the accidentals
+/// directly following a note letter are kept as-is (so `bbmaj7` is B♭ major 7).  The rest of the symbol (i.e., the quality) is
+/// case-folded, except that a lone upper-case `M` (i.e., not part of a word like `MAJ` or `DIM`) means major, so `CM7` is `Cmaj7`,
+/// and `CM` is `C` (while `Cm` is still C minor).
+pub fn normalize_symbol(input: &str) -> String {
+    let chars = input.trim().nfc().collect::<Vec<_>>();
+
+    let mut result = String::with_capacity(input.len());
+    let mut expect_note = true;
+    let mut is_in_upper_word = false;
+    let mut k = 0;
+
+    while k < chars.len() {
+        let c = chars[k];
+
+        if expect_note && matches!(c.to_ascii_lowercase(), 'a'..='g') {
+            result.push(c.to_ascii_uppercase());
+            k += 1;
+
+            while k < chars.len() && matches!(chars[k], '#' | '♯' | 'b' | '♭' | '𝄪' | '𝄫') {
+                result.push(chars[k]);
+                k += 1;
+            }
+
+            expect_note = false;
+            is_in_upper_word = false;
+            continue;
+        }
+
+        expect_note = c == '/';
+
+        let next = chars.get(k + 1);
+        let is_lone = !is_in_upper_word && !next.map(|n| n.is_alphabetic()).unwrap_or(false);
+
+        match c {
+            // The major triad is implied, so a lone `M` only matters before an extension.
+            'M' if is_lone => {
+                if next.map(|n| n.is_ascii_digit()).unwrap_or(false) {
+                    result.push_str("maj");
+                }
+            }
+            '−' | '–' => result.push('-'),
+            _ => result.extend(c.to_lowercase()),
+        }
+
+        is_in_upper_word = c.is_uppercase();
+        k += 1;
+    }
+
+    result
+}
+
 /// Parses a [`Note`] [`str`] into a [`Note`].
 
 pub fn note_str_to_note(note_str: &str) -> Res<Note> {
@@ -24,39 +104,53 @@ pub fn note_str_to_note(note_str: &str) -> Res<Note> {
         "A" => note::A,
         "A#" | "A♯" => note::ASharp,
         "A##" | "A𝄪" => note::ADoubleSharp,
+        "A###" | "A♯𝄪" => note::ATripleSharp,
         "Ab" | "A♭" => note::AFlat,
         "Abb" | "A𝄫" => note::ADoubleFlat,
+        "Abbb" | "A♭𝄫" => note::ATripleFlat,
         "B" => note::B,
         "B#" | "B♯" => note::BSharp,
         "B##" | "B𝄪" => note::BDoubleSharp,
+        "B###" | "B♯𝄪" => note::BTripleSharp,
         "Bb" | "B♭" => note::BFlat,
         "Bbb" | "B𝄫" => note::BDoubleFlat,
+        "Bbbb" | "B♭𝄫" => note::BTripleFlat,
         "C" => note::C,
         "C#" | "C♯" => note::CSharp,
         "C##" | "C𝄪" => note::CDoubleSharp,
+        "C###" | "C♯𝄪" => note::CTripleSharp,
         "Cb" | "C♭" => note::CFlat,
         "Cbb" | "C𝄫" => note::CDoubleFlat,
+        "Cbbb" | "C♭𝄫" => note::CTripleFlat,
         "D" => note::D,
         "D#" | "D♯" => note::DSharp,
         "D##" | "D𝄪" => note::DDoubleSharp,
+        "D###" | "D♯𝄪" => note::DTripleSharp,
         "Db" | "D♭" => note::DFlat,
         "Dbb" | "D𝄫" => note::DDoubleFlat,
+        "Dbbb" | "D♭𝄫" => note::DTripleFlat,
         "E" => note::E,
         "E#" | "E♯" => note::ESharp,
         "E##" | "E𝄪" => note::EDoubleSharp,
+        "E###" | "E♯𝄪" => note::ETripleSharp,
         "Eb" | "E♭" => note::EFlat,
         "Ebb" | "E𝄫" => note::EDoubleFlat,
+        "Ebbb" | "E♭𝄫" => note::ETripleFlat,
         "F" => note::F,
         "F#" | "F♯" => note::FSharp,
         "F##" | "F𝄪" => note::FDoubleSharp,
+        "F###" | "F♯𝄪" => note::FTripleSharp,
         "Fb" | "F♭" => note::FFlat,
         "Fbb" | "F𝄫" => note::FDoubleFlat,
+        "Fbbb" | "F♭𝄫" => note::FTripleFlat,
         "G" => note::G,
         "G#" | "G♯" => note::GSharp,
         "G##" | "G𝄪" => note::GDoubleSharp,
+        "G###" | "G♯𝄪" => note::GTripleSharp,
         "Gb" | "G♭" => note::GFlat,
         "Gbb" | "G𝄫" => note::GDoubleFlat,
-        _ => return Err(crate::core::base::Err::msg("Please use fairly standard notes (e.g., don't use triple sharps / flats).")),
+        "Gbbb" | "G♭𝄫" => note::GTripleFlat,
+        _ => return Err(crate::core::base::Err::msg("Please use a valid note (e.g., `C`, `F#`, or `Bbb`).")),
     };
 
     Ok(chord)
@@ -81,3 +175,118 @@ pub fn octave_str_to_octave(note_str: &str) -> Res<Octave> {
 
     Ok(octave)
 }
+
+/// Parses a Roman numeral [`str`] (in either case) into a zero-based scale degree (e.g., `"IV"` is `3`).
+pub fn roman_numeral_str_to_degree(numeral_str: &str) -> Res<usize> {
+    let degree = match numeral_str.to_ascii_uppercase().as_str() {
+        "I" => 0,
+        "II" => 1,
+        "III" => 2,
+        "IV" => 3,
+        "V" => 4,
+        "VI" => 5,
+        "VII" => 6,
+        _ => return Err(crate::core::base::Err::msg("Please use a valid Roman numeral (I - VII).")),
+    };
+
+    Ok(degree)
+}
+
+/// Returns the (optimal string alignment) edit distance between two [`str`]s.
+///
+/// This is the Levenshtein distance, where the transposition of two adjacent characters also counts as a single edit.
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let a = a.chars().collect::<Vec<_>>();
+    let b = b.chars().collect::<Vec<_>>();
+
+    let mut d = vec![vec![0; b.len() + 1]; a.len() + 1];
+
+    for (i, row) in d.iter_mut().enumerate() {
+        row[0] = i;
+    }
+
+    for j in 0..=b.len() {
+        d[0][j] = j;
+    }
+
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+
+            d[i][j] = (d[i - 1][j] + 1).min(d[i][j - 1] + 1).min(d[i - 1][j - 1] + cost);
+
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                d[i][j] = d[i][j].min(d[i - 2][j - 2] + 1);
+            }
+        }
+    }
+
+    d[a.len()][b.len()]
+}
+
+/// Returns a ranked list of suggested chord symbols that are "close" to the input.
+///
+/// The root note is kept (though its letter is uppercased), and the remainder of the symbol is compared
+/// against [`KNOWN_CHORD_SYMBOLS`].
+pub fn suggest_chord_symbols(input: &str) -> Vec<String> {
+    let Some((root, quality)) = split_root(input) else {
+        return vec![];
+    };
+
+    let max_distance = 2.max(quality.chars().count() / 2);
+
+    let mut candidates = KNOWN_CHORD_SYMBOLS
+        .iter()
+        .map(|symbol| (edit_distance(quality, symbol), format!("{}{}", root, symbol)))
+        .filter(|(distance, candidate)| *distance <= max_distance && candidate != input)
+        .collect::<Vec<_>>();
+
+    // A stable sort keeps the "more common" symbols first when the distances are equal.
+    candidates.sort_by_key(|(distance, _)| *distance);
+
+    candidates.into_iter().map(|(_, candidate)| candidate).take(MAX_SUGGESTIONS).collect()
+}
+
+/// Returns the chord symbols that complete the given partial symbol (e.g., `Cm` completes to `Cm`, `Cmaj7`, `Cm7`, etc.).
+///
+/// The root note is kept (though its letter is uppercased), and the remainder of the partial symbol is used as a prefix filter
+/// over [`KNOWN_CHORD_SYMBOLS`], so the completions are ordered (roughly) by how common they are.
+pub fn complete_chord_symbols(partial: &str) -> Vec<String> {
+    let Some((root, quality)) = split_root(partial) else {
+        return vec![];
+    };
+
+    KNOWN_CHORD_SYMBOLS
+        .iter()
+        .filter(|symbol| symbol.starts_with(quality))
+        .map(|symbol| format!("{}{}", root, symbol))
+        .collect()
+}
+
+/// Splits a (possibly partial) chord symbol into its root note (with the letter uppercased) and the remainder of the symbol.
+///
+/// Returns `None` if the symbol does not start with a valid note.
+fn split_root(input: &str) -> Option<(String, &str)> {
+    let mut chars = input.trim().chars();
+
+    let letter = chars.next()?.to_ascii_uppercase();
+
+    let rest = chars.as_str();
+    let accidentals = rest.chars().take_while(|c| matches!(c, '#' | '♯' | 'b' | '♭')).take(2).collect::<String>();
+    let root = format!("{}{}", letter, accidentals);
+
+    note_str_to_note(&root).ok()?;
+
+    Some((root, &rest[accidentals.len()..]))
+}
+
+// Statics.
+
+/// The maximum number of suggestions returned by [`suggest_chord_symbols`].
+pub const MAX_SUGGESTIONS: usize = 3;
+
+/// The chord quality symbols used by [`suggest_chord_symbols`], ordered (roughly) by how common they are.
+pub static KNOWN_CHORD_SYMBOLS: [&str; 32] = [
+    "", "m", "7", "maj7", "m7", "dim", "+", "sus4", "sus2", "6", "m6", "6/9", "9", "m9", "maj9", "add9", "11", "m11", "13", "m13", "m7b5", "dim7", "mmaj7", "+7", "+maj7", "7b9", "7#9", "7#11",
+    "7sus4", "ø", "7b5", "7alt",
+];
@@ -0,0 +1,147 @@
+//! A module for describing the piano keys of a [`Chord`] (e.g., for highlighting them on a keyboard).
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{
+    chord::{Chord, HasChord, HasRoot, HasSlash},
+    note::HasMidiNumber,
+    octave::Octave,
+};
+
+// Enum.
+
+/// The role of a piano key in a chord.
+#[derive(PartialEq, Eq, Copy, Clone, Hash, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum PianoKeyRole {
+    /// The key is not part of the chord.
+    #[default]
+    None,
+    /// The key is a tone of the chord (other than the root or slash).
+    Tone,
+    /// The key is the root of the chord (in any octave).
+    Root,
+    /// The key is the slash (bass) note of the chord.
+    Slash,
+}
+
+// Struct.
+
+/// A piano key, along with its role in a chord.
+#[derive(PartialEq, Eq, Copy, Clone, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PianoKey {
+    /// The MIDI note number of the key (where `C4` is `60`).
+    pub midi: u8,
+    /// Whether or not the key is a black key.
+    pub is_black: bool,
+    /// The role of the key in the chord.
+    pub role: PianoKeyRole,
+}
+
+// Impls.
+
+impl PianoKey {
+    /// Returns whether or not the key is part of the chord.
+    pub fn is_chord_tone(&self) -> bool {
+        self.role != PianoKeyRole::None
+    }
+}
+
+impl Chord {
+    /// Returns every piano key in the octaves spanned by the chord (from the `C` at or below its lowest note to the `B` at or above its highest note),
+    /// along with each key's role in the chord.
+    pub fn piano_keys(&self) -> Vec<PianoKey> {
+        let tones = self.chord().iter().map(|n| n.to_midi_number()).collect::<Vec<_>>();
+
+        match (tones.iter().min(), tones.iter().max()) {
+            (Some(lowest), Some(highest)) => self.piano_keys_between(lowest - lowest % 12, (highest - highest % 12).saturating_add(11).min(127)),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Returns every piano key from the `C` of the `lowest` octave to the `B` of the `highest` octave (within the MIDI range),
+    /// along with each key's role in the chord.
+    pub fn piano_keys_in_range(&self, lowest: Octave, highest: Octave) -> Vec<PianoKey> {
+        let first = (12 * (lowest as u16 + 1)).min(127) as u8;
+        let last = (12 * (highest as u16 + 2) - 1).min(127) as u8;
+
+        self.piano_keys_between(first, last)
+    }
+
+    /// Returns every piano key between the given MIDI note numbers (inclusive), along with each key's role in the chord.
+    fn piano_keys_between(&self, first: u8, last: u8) -> Vec<PianoKey> {
+        let tones = self.chord().iter().map(|n| n.to_midi_number()).collect::<Vec<_>>();
+
+        let root_class = self.root().to_midi_number() % 12;
+        let slash = (self.slash() != self.root()).then(|| tones.iter().min().copied()).flatten();
+
+        (first..=last)
+            .map(|midi| {
+                let role = if !tones.contains(&midi) {
+                    PianoKeyRole::None
+                } else if Some(midi) == slash {
+                    PianoKeyRole::Slash
+                } else if midi % 12 == root_class {
+                    PianoKeyRole::Root
+                } else {
+                    PianoKeyRole::Tone
+                };
+
+                PianoKey {
+                    midi,
+                    is_black: matches!(midi % 12, 1 | 3 | 6 | 8 | 10),
+                    role,
+                }
+            })
+            .collect()
+    }
+}
+
+// Tests.
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::base::Parsable;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_piano_keys() {
+        let keys = Chord::parse("C").unwrap().piano_keys();
+
+        assert_eq!(keys.len(), 12);
+        assert_eq!(
+            keys[0],
+            PianoKey {
+                midi: 60,
+                is_black: false,
+                role: PianoKeyRole::Root
+            }
+        );
+        assert_eq!(
+            keys[1],
+            PianoKey {
+                midi: 61,
+                is_black: true,
+                role: PianoKeyRole::None
+            }
+        );
+        assert_eq!(keys.iter().filter(|k| k.is_chord_tone()).map(|k| k.midi).collect::<Vec<_>>(), vec![60, 64, 67]);
+        assert_eq!(keys.iter().filter(|k| k.is_black).count(), 5);
+
+        // Slash chords mark the bass note, and the range covers every octave of the chord.
+        let keys = Chord::parse("D/F#").unwrap().piano_keys();
+        let roles = keys.iter().filter(|k| k.is_chord_tone()).map(|k| (k.midi, k.role)).collect::<Vec<_>>();
+
+        assert_eq!(keys.first().unwrap().midi, 48);
+        assert_eq!(keys.len(), 24);
+        assert_eq!(roles, vec![(54, PianoKeyRole::Slash), (62, PianoKeyRole::Root), (66, PianoKeyRole::Tone), (69, PianoKeyRole::Tone)]);
+
+        let keys = Chord::parse("C").unwrap().piano_keys_in_range(Octave::Three, Octave::Five);
+
+        assert_eq!(keys.len(), 36);
+        assert_eq!(keys.iter().filter(|k| k.is_chord_tone()).count(), 3);
+    }
+}
@@ -2,11 +2,15 @@
 
 // Traits.
 
-use std::time::Duration;
+use core::time::Duration;
 
-use once_cell::sync::Lazy;
+use super::{
+    base::{OutputSink, RenderOptions, Renderable, Void},
+    helpers::{mel, render_enveloped_tones},
+};
 
-use super::helpers::mel;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -37,32 +41,32 @@ pub trait HasMel: HasFrequency {
     }
 }
 
-#[cfg(feature = "audio")]
-use super::base::{Playable, PlaybackHandle, Res};
-
-#[cfg(feature = "audio")]
-impl<T: HasFrequency> Playable for T {
-    fn play(&self, delay: Duration, length: Duration, fade_in: Duration) -> Res<PlaybackHandle> {
-        use rodio::{source::SineWave, OutputStream, Sink, Source};
+impl<T: HasFrequency> Renderable for T {
+    fn render_with_options(&self, sink: &mut dyn OutputSink, delay: Duration, length: Duration, options: RenderOptions) -> Void {
+        let samples = render_enveloped_tones(&[(self.frequency(), delay)], length, options.to_envelope(), sink.sample_rate(), options.waveform);
 
-        let (stream, stream_handle) = OutputStream::try_default()?;
-        let sink = Sink::try_new(&stream_handle)?;
-        let source = SineWave::new(self.frequency()).take_duration(length - delay).buffered().delay(delay).fade_in(fade_in).amplify(0.20);
-        sink.append(source);
-
-        Ok(PlaybackHandle::new(stream, stream_handle, vec![sink]))
+        sink.write(&samples)
     }
 }
 
+#[cfg(feature = "audio")]
+use super::base::Playable;
+
+#[cfg(feature = "audio")]
+impl<T: HasFrequency> Playable for T {}
+
 // Enum.
 
 /// An enum representing the pitch of a note.
 ///
 /// The frequencies of the pitches are based on the [A4 frequency](https://en.wikipedia.org/wiki/A4_(pitch_standard)).
 /// There is no enharmonic representation here, so all of the sharps are represented.
+///
+/// In wasm, this is exposed as the `KordPitch` enum, whose values are the pitch class numbers (`0` for `C`, through `11` for `B`).
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(PartialEq, Eq, Clone, Copy, Hash, Debug, Ord, PartialOrd)]
 #[repr(u8)]
+#[cfg_attr(feature = "wasm", wasm_bindgen(js_name = KordPitch))]
 pub enum Pitch {
     /// The pitch C.
     C,
@@ -92,6 +96,13 @@ pub enum Pitch {
 
 // Pitch impls.
 
+impl Pitch {
+    /// Returns the pitch class number of the pitch (i.e., `0` for `C`, through `11` for `B`).
+    pub fn pitch_class_number(&self) -> u8 {
+        *self as u8
+    }
+}
+
 impl HasBaseFrequency for Pitch {
     
     fn base_frequency(&self) -> f32 {
@@ -143,22 +154,20 @@ impl TryFrom<u8> for Pitch {
 // Statics.
 
 /// An array of all the pitches.
-pub static ALL_PITCHES: Lazy<[Pitch; 12]> = Lazy::new(|| {
-    [
-        Pitch::C,
-        Pitch::DFlat,
-        Pitch::D,
-        Pitch::EFlat,
-        Pitch::E,
-        Pitch::F,
-        Pitch::GFlat,
-        Pitch::G,
-        Pitch::AFlat,
-        Pitch::A,
-        Pitch::BFlat,
-        Pitch::B,
-    ]
-});
+pub static ALL_PITCHES: [Pitch; 12] = [
+    Pitch::C,
+    Pitch::DFlat,
+    Pitch::D,
+    Pitch::EFlat,
+    Pitch::E,
+    Pitch::F,
+    Pitch::GFlat,
+    Pitch::G,
+    Pitch::AFlat,
+    Pitch::A,
+    Pitch::BFlat,
+    Pitch::B,
+];
 
 // Tests.
 
@@ -0,0 +1,127 @@
+//! A module for pitch-class set theory (e.g., normal form, prime form, and interval vectors).
+//!
+//! Pitch classes are represented by their numbers (i.e., `0` for `C`, through `11` for `B`), as returned by
+//! [`Pitch::pitch_class_number`].
+
+use crate::core::pitch::Pitch;
+
+// Functions.
+
+/// Returns the (sorted, and deduplicated) pitch-class numbers of the pitches.
+pub fn pitch_class_set(pitches: &[Pitch]) -> Vec<u8> {
+    let mut result = pitches.iter().map(Pitch::pitch_class_number).collect::<Vec<_>>();
+
+    result.sort();
+    result.dedup();
+
+    result
+}
+
+/// Returns the normal form of the pitches (i.e., the most compact rotation of the pitch-class set), using Rahn's algorithm.
+///
+/// The rotation with the smallest span (from the first to the last pitch class) is chosen; ties are broken by the smallest span to
+/// the second-to-last pitch class, and so on.  E.g., `E G C` is `[0, 4, 7]`.
+pub fn normal_form(pitches: &[Pitch]) -> Vec<u8> {
+    most_packed_rotation(&pitch_class_set(pitches))
+}
+
+/// Returns the prime form of the pitches (i.e., the more compact of the normal form and the normal form of the inversion, transposed to
+/// start on `0`), using Rahn's algorithm.
+///
+/// E.g., both a major triad and a minor triad are `[0, 3, 7]`.
+pub fn prime_form(pitches: &[Pitch]) -> Vec<u8> {
+    let set = pitch_class_set(pitches);
+    let mut inverted = set.iter().map(|p| (12 - p) % 12).collect::<Vec<_>>();
+    inverted.sort();
+
+    let original = transpose_to_zero(&most_packed_rotation(&set));
+    let inverted = transpose_to_zero(&most_packed_rotation(&inverted));
+
+    if packing_key(&inverted) < packing_key(&original) {
+        inverted
+    } else {
+        original
+    }
+}
+
+/// Returns the interval(-class) vector of the pitches, which counts the occurrences of each interval class (`1` through `6`) between
+/// every pair of pitch classes.
+///
+/// E.g., a major triad is `[0, 0, 1, 1, 1, 0]`.
+pub fn interval_vector(pitches: &[Pitch]) -> [u8; 6] {
+    let set = pitch_class_set(pitches);
+    let mut result = [0; 6];
+
+    for (k, a) in set.iter().enumerate() {
+        for b in &set[k + 1..] {
+            let distance = b - a;
+            let interval_class = distance.min(12 - distance);
+
+            result[interval_class as usize - 1] += 1;
+        }
+    }
+
+    result
+}
+
+// Helpers.
+
+/// Returns the rotation of the (sorted) pitch-class set that is most "packed" (see [`packing_key`]), starting from its first pitch class.
+fn most_packed_rotation(set: &[u8]) -> Vec<u8> {
+    (0..set.len())
+        .map(|k| set[k..].iter().chain(&set[..k]).copied().collect::<Vec<_>>())
+        .min_by_key(|rotation| packing_key(rotation))
+        .unwrap_or_default()
+}
+
+/// Returns the key used to compare the compactness of rotations: the spans from the first pitch class to the last, then to the
+/// second-to-last, and so on (and, finally, the first pitch class itself, so that the choice is deterministic).
+fn packing_key(rotation: &[u8]) -> Vec<u8> {
+    let first = rotation.first().copied().unwrap_or_default();
+
+    rotation.iter().rev().map(|p| (p + 12 - first) % 12).chain(std::iter::once(first)).collect()
+}
+
+/// Returns the rotation transposed so that it starts on `0`.
+fn transpose_to_zero(rotation: &[u8]) -> Vec<u8> {
+    let first = rotation.first().copied().unwrap_or_default();
+
+    rotation.iter().map(|p| (p + 12 - first) % 12).collect()
+}
+
+// Tests.
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{base::Parsable, chord::Chord, chord::HasChord, pitch::HasPitch};
+    use pretty_assertions::assert_eq;
+
+    fn pitches(symbol: &str) -> Vec<Pitch> {
+        Chord::parse(symbol).unwrap().chord().iter().map(|n| n.pitch()).collect()
+    }
+
+    #[test]
+    fn test_pitch_class_sets() {
+        assert_eq!(Pitch::C.pitch_class_number(), 0);
+        assert_eq!(Pitch::B.pitch_class_number(), 11);
+
+        assert_eq!(interval_vector(&pitches("C")), [0, 0, 1, 1, 1, 0]);
+        assert_eq!(interval_vector(&pitches("Am")), [0, 0, 1, 1, 1, 0]);
+        assert_eq!(interval_vector(&pitches("C7")), [0, 1, 2, 1, 1, 1]);
+        assert_eq!(interval_vector(&pitches("Cdim")), [0, 0, 4, 0, 0, 2]);
+        assert_eq!(interval_vector(&pitches("C+")), [0, 0, 0, 3, 0, 0]);
+
+        assert_eq!(normal_form(&[Pitch::E, Pitch::G, Pitch::C]), vec![0, 4, 7]);
+        assert_eq!(normal_form(&pitches("G")), vec![7, 11, 2]);
+        assert_eq!(normal_form(&pitches("C7")), vec![4, 7, 10, 0]);
+
+        assert_eq!(prime_form(&pitches("C")), vec![0, 3, 7]);
+        assert_eq!(prime_form(&pitches("Fm")), vec![0, 3, 7]);
+        assert_eq!(prime_form(&pitches("C7")), vec![0, 2, 5, 8]);
+        assert_eq!(prime_form(&pitches("Cm7b5")), vec![0, 2, 5, 8]);
+        assert_eq!(prime_form(&pitches("Cmaj7")), vec![0, 1, 5, 8]);
+        assert_eq!(prime_form(&pitches("Cdim")), vec![0, 3, 6, 9]);
+        assert!(prime_form(&[]).is_empty());
+    }
+}
@@ -0,0 +1,129 @@
+//! A module that contains the [`PolyChord`] type, which is one chord stacked over another (e.g., `D|C`).
+
+use pest::Parser;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{
+    base::{HasName, Parsable, Res},
+    chord::{Chord, Chordable, HasChord, HasRoot},
+    note::Note,
+    octave::HasOctave,
+    parser::{ChordParser, Rule},
+};
+
+// Struct.
+
+/// A polychord, which is an `upper` chord stacked over a `lower` chord (e.g., `D|C` is a D major triad over a C major triad).
+///
+/// Unlike a slash chord (e.g., `D/C`), which places a single bass note under a chord, the `lower` part is a full chord.
+#[derive(PartialEq, Eq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PolyChord {
+    /// The upper chord.
+    upper: Chord,
+    /// The lower chord.
+    lower: Chord,
+}
+
+// Impls.
+
+impl PolyChord {
+    /// Returns a new polychord with the `upper` chord stacked over the `lower` chord.
+    ///
+    /// If the `upper` chord is not already voiced above the root of the `lower` chord, it is moved to the octave above it.
+    pub fn new(upper: Chord, lower: Chord) -> Self {
+        let upper = if upper.root().octave() <= lower.root().octave() {
+            upper.with_octave(lower.root().octave() + 1)
+        } else {
+            upper
+        };
+
+        Self { upper, lower }
+    }
+
+    /// Returns the upper chord.
+    pub fn upper(&self) -> &Chord {
+        &self.upper
+    }
+
+    /// Returns the lower chord.
+    pub fn lower(&self) -> &Chord {
+        &self.lower
+    }
+}
+
+impl HasChord for PolyChord {
+    /// Returns the union of the notes of both chords (in ascending order).
+    fn chord(&self) -> Vec<Note> {
+        let mut notes = self.lower.chord();
+        notes.extend(self.upper.chord());
+
+        notes.sort();
+        notes.dedup();
+
+        notes
+    }
+}
+
+impl HasName for PolyChord {
+    /// Returns the name of the polychord (e.g., `D|C`), which is distinct from the slash chord `D/C`.
+    fn name(&self) -> String {
+        format!("{}|{}", self.upper.name(), self.lower.name())
+    }
+}
+
+impl Parsable for PolyChord {
+    fn parse(input: &str) -> Res<Self>
+    where
+        Self: Sized,
+    {
+        let root = ChordParser::parse(Rule::poly_chord, input)?.next().unwrap();
+
+        assert_eq!(Rule::poly_chord, root.as_rule());
+
+        let mut parts = root.into_inner().map(|part| Chord::parse(part.as_str().trim()));
+
+        let upper = parts.next().unwrap()?;
+        let lower = parts.next().unwrap()?;
+
+        Ok(Self::new(upper, lower))
+    }
+}
+
+// Tests.
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::note::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_poly_chord() {
+        let poly = PolyChord::parse("F#m|E").unwrap();
+
+        assert_eq!(poly.upper().name(), "F♯m");
+        assert_eq!(poly.lower().name(), "E");
+        assert_eq!(poly.name(), "F♯m|E");
+        assert_eq!(poly.chord(), vec![E, GSharp, B, FSharpFive, AFive, CSharpSix]);
+
+        // Polychords are distinct from slash chords.
+        let poly = PolyChord::parse("D|C").unwrap();
+
+        assert_eq!(poly.name(), "D|C");
+        assert_ne!(poly.name(), Chord::parse("D/C").unwrap().name());
+        assert_eq!(poly.chord(), vec![C, E, G, DFive, FSharpFive, AFive]);
+
+        // Both parts can be full chord symbols, and an upper chord that is already above the lower chord stays put.
+        let poly = PolyChord::parse("Bbmaj7@5 | C7").unwrap();
+
+        assert_eq!(poly.name(), "B♭maj7|C7");
+        assert_eq!(poly.chord(), vec![C, E, G, BFlat, BFlatFive, DSix, FSix, ASix]);
+
+        assert!(PolyChord::parse("D|").is_err());
+        assert!(PolyChord::parse("D|C|G").is_err());
+        assert!(Chord::parse("D|C").is_err());
+    }
+}
@@ -0,0 +1,242 @@
+//! A module for generating random (but reproducible) [`Chord`]s, which is useful for practice apps (e.g., ear training).
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use crate::core::{
+    chord::{Chord, Chordable},
+    known_chord::ChordQuality,
+    modifier::{Degree, Extension, Modifier},
+    note::{AFlat, BFlat, DFlat, EFlat, FSharp, Note, A, B, C, D, E, F, G},
+};
+
+// Statics.
+
+/// The roots that random chords are built on (one per pitch class, using the most common spelling).
+static RANDOM_CHORD_ROOTS: [Note; 12] = [C, DFlat, D, EFlat, E, F, FSharp, G, AFlat, A, BFlat, B];
+
+// Structs.
+
+/// Options that control which chords [`Chord::random_with_options`] generates.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RandomChordOptions {
+    /// The allowed qualities, and their relative weights (e.g., a quality with weight `2` is picked twice as often as one with weight `1`).
+    pub qualities: Vec<(ChordQuality, u32)>,
+    /// The probability (in `[0.0, 1.0]`) that a chord is colored with an extension (e.g., `C7` becoming `C9` or `C7(♭9)`).
+    ///
+    /// Only qualities that have common extensions (triads, and the major, dominant, minor, and suspended seventh chords) are colored.
+    pub extension_probability: f64,
+}
+
+impl RandomChordOptions {
+    /// Returns options that pick uniformly from the given qualities (using the default extension probability).
+    pub fn with_qualities(qualities: &[ChordQuality]) -> Self {
+        Self {
+            qualities: qualities.iter().map(|quality| (*quality, 1)).collect(),
+            ..Self::default()
+        }
+    }
+}
+
+impl Default for RandomChordOptions {
+    fn default() -> Self {
+        Self {
+            qualities: vec![
+                (ChordQuality::Major, 10),
+                (ChordQuality::Minor, 10),
+                (ChordQuality::DominantSeventh, 8),
+                (ChordQuality::MinorSeventh, 8),
+                (ChordQuality::MajorSeventh, 6),
+                (ChordQuality::HalfDiminishedSeventh, 3),
+                (ChordQuality::Suspended4, 3),
+                (ChordQuality::Suspended2, 2),
+                (ChordQuality::Diminished, 2),
+                (ChordQuality::Augmented, 2),
+                (ChordQuality::MajorSixth, 2),
+                (ChordQuality::MinorSixth, 2),
+                (ChordQuality::SuspendedSeventh, 2),
+                (ChordQuality::DiminishedSeventh, 2),
+                (ChordQuality::MinorMajorSeventh, 1),
+                (ChordQuality::AugmentedSeventh, 1),
+                (ChordQuality::AugmentedMajorSeventh, 1),
+            ],
+            extension_probability: 0.25,
+        }
+    }
+}
+
+// Enums.
+
+/// An extension that colors a random chord.
+#[derive(Clone, Copy, Debug)]
+enum Color {
+    /// Raises the dominant degree of a seventh chord (e.g., `C7` to `C9`).
+    Upper(Degree),
+    /// Adds a [`Modifier`] (e.g., `C7` to `C7(♭9)`).
+    Modifier(Modifier),
+    /// Adds an [`Extension`] (e.g., `C` to `C(add9)`).
+    Extension(Extension),
+}
+
+// Impls.
+
+impl Chord {
+    /// Returns a random [`Chord`] (using [`RandomChordOptions::default`]).
+    pub fn random<R: Rng + ?Sized>(rng: &mut R) -> Chord {
+        Chord::random_with_options(rng, &RandomChordOptions::default())
+    }
+
+    /// Returns a random [`Chord`], picking the root uniformly, the quality from the weighted pool of `options`,
+    /// and (sometimes) an extension that suits that quality.
+    ///
+    /// If `options` allows no qualities (or only zero weights), every quality is allowed.
+    pub fn random_with_options<R: Rng + ?Sized>(rng: &mut R, options: &RandomChordOptions) -> Chord {
+        let root = RANDOM_CHORD_ROOTS[rng.gen_range(0..RANDOM_CHORD_ROOTS.len())];
+
+        let quality = pick_weighted(rng, &options.qualities).unwrap_or_else(|| {
+            let defaults = RandomChordOptions::default().qualities;
+            defaults[rng.gen_range(0..defaults.len())].0
+        });
+
+        let color = if rng.gen_bool(options.extension_probability.clamp(0.0, 1.0)) {
+            pick_weighted(rng, colors_for_quality(quality))
+        } else {
+            None
+        };
+
+        let degree = match color {
+            Some(Color::Upper(degree)) => degree,
+            _ => Degree::Seven,
+        };
+
+        let chord = chord_with_quality(root, quality, degree);
+
+        match color {
+            Some(Color::Modifier(modifier)) => chord.with_modifier(modifier),
+            Some(Color::Extension(extension)) => chord.with_extension(extension),
+            _ => chord,
+        }
+    }
+
+    /// Returns a random [`Chord`] (using [`RandomChordOptions::default`]) that is always the same for the same `seed`.
+    pub fn random_from_seed(seed: u64) -> Chord {
+        Chord::random_from_seed_with_options(seed, &RandomChordOptions::default())
+    }
+
+    /// Returns a random [`Chord`] (see [`Chord::random_with_options`]) that is always the same for the same `seed` and `options`.
+    pub fn random_from_seed_with_options(seed: u64, options: &RandomChordOptions) -> Chord {
+        Chord::random_with_options(&mut StdRng::seed_from_u64(seed), options)
+    }
+}
+
+// Helpers.
+
+/// Picks an item from a weighted pool, or returns `None` if the pool has no weight.
+fn pick_weighted<R: Rng + ?Sized, T: Copy>(rng: &mut R, pool: &[(T, u32)]) -> Option<T> {
+    let total = pool.iter().map(|(_, weight)| *weight as u64).sum::<u64>();
+
+    if total == 0 {
+        return None;
+    }
+
+    let mut target = rng.gen_range(0..total);
+
+    for (item, weight) in pool {
+        let weight = *weight as u64;
+
+        if target < weight {
+            return Some(*item);
+        }
+
+        target -= weight;
+    }
+
+    None
+}
+
+/// Returns the weighted pool of extensions that commonly color a quality.
+fn colors_for_quality(quality: ChordQuality) -> &'static [(Color, u32)] {
+    match quality {
+        ChordQuality::Major | ChordQuality::Minor => &[(Color::Extension(Extension::Add9), 1)],
+        ChordQuality::MajorSeventh => &[(Color::Upper(Degree::Nine), 3), (Color::Upper(Degree::Thirteen), 1), (Color::Modifier(Modifier::Sharp11), 1)],
+        ChordQuality::DominantSeventh => &[
+            (Color::Upper(Degree::Nine), 3),
+            (Color::Upper(Degree::Thirteen), 2),
+            (Color::Modifier(Modifier::Flat9), 2),
+            (Color::Modifier(Modifier::Sharp9), 1),
+            (Color::Modifier(Modifier::Sharp11), 1),
+        ],
+        ChordQuality::MinorSeventh => &[(Color::Upper(Degree::Nine), 3), (Color::Upper(Degree::Eleven), 2)],
+        ChordQuality::SuspendedSeventh => &[(Color::Upper(Degree::Nine), 1)],
+        _ => &[],
+    }
+}
+
+/// Builds the chord of a quality on a root, using `degree` as the dominant degree of seventh chords that support it.
+fn chord_with_quality(root: Note, quality: ChordQuality, degree: Degree) -> Chord {
+    let chord = Chord::new(root);
+
+    let upper = |chord: Chord| if degree == Degree::Seven { chord } else { chord.dominant(degree) };
+
+    match quality {
+        ChordQuality::Major => chord,
+        ChordQuality::Minor => chord.minor(),
+        ChordQuality::Diminished => chord.minor().flat5(),
+        ChordQuality::Augmented => chord.augmented(),
+        ChordQuality::Suspended2 => chord.sus2(),
+        ChordQuality::Suspended4 => chord.sus4(),
+        ChordQuality::MajorSixth => chord.add6(),
+        ChordQuality::MinorSixth => chord.minor().add6(),
+        ChordQuality::MajorSeventh => upper(chord.major7()),
+        ChordQuality::DominantSeventh => chord.dominant(degree),
+        ChordQuality::SuspendedSeventh => chord.dominant(degree).sus4(),
+        ChordQuality::MinorSeventh => chord.minor().dominant(degree),
+        ChordQuality::MinorMajorSeventh => upper(chord.minor().major7()),
+        ChordQuality::HalfDiminishedSeventh => chord.half_diminished(),
+        ChordQuality::DiminishedSeventh => chord.dim(),
+        ChordQuality::AugmentedSeventh => chord.augmented().seven(),
+        ChordQuality::AugmentedMajorSeventh => chord.augmented().major7(),
+    }
+}
+
+// Tests.
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{base::HasName, chord::HasRoot};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_random_is_reproducible() {
+        let first = (0..32).map(|seed| Chord::random_from_seed(seed).name()).collect::<Vec<_>>();
+        let second = (0..32).map(|seed| Chord::random_from_seed(seed).name()).collect::<Vec<_>>();
+
+        assert_eq!(first, second);
+
+        // Different seeds should not all produce the same chord.
+        assert!(first.iter().any(|name| name != &first[0]));
+    }
+
+    #[test]
+    fn test_random_with_options() {
+        let options = RandomChordOptions {
+            extension_probability: 0.0,
+            ..RandomChordOptions::with_qualities(&[ChordQuality::Minor, ChordQuality::MinorSeventh])
+        };
+
+        let mut rng = StdRng::seed_from_u64(42);
+
+        for _ in 0..100 {
+            let chord = Chord::random_with_options(&mut rng, &options);
+
+            assert!(matches!(chord.quality(), Some(ChordQuality::Minor | ChordQuality::MinorSeventh)), "{}", chord.name());
+            assert!(RANDOM_CHORD_ROOTS.contains(&chord.root()));
+        }
+
+        // Every quality builds a chord of that quality, with or without an upper degree.
+        for (quality, _) in RandomChordOptions::default().qualities {
+            assert_eq!(chord_with_quality(C, quality, Degree::Seven).quality(), Some(quality));
+            assert_eq!(chord_with_quality(C, quality, Degree::Nine).quality(), Some(quality));
+        }
+    }
+}
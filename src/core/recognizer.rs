@@ -0,0 +1,149 @@
+//! A module that contains the [`ChordRecognizer`] struct, which incrementally recognizes chords as notes are added or removed.
+
+use std::collections::{BTreeSet, VecDeque};
+
+use crate::core::{chord::Chord, note::Note};
+
+/// The number of (most recently used) note sets whose interpretations are cached by a [`ChordRecognizer`].
+pub const RECOGNIZER_CACHE_CAPACITY: usize = 32;
+
+// Struct.
+
+/// A stateful chord recognizer, which keeps track of an accumulated set of [`Note`]s, and the best
+/// [`Chord`] interpretation of those notes.
+///
+/// This is useful in "live input" scenarios (e.g., a user pressing keys one at a time), where the best
+/// interpretation is needed after every change.  Every change requires a full guess (see [`Chord::try_from_notes`]),
+/// unless the new note set is one of the [`RECOGNIZER_CACHE_CAPACITY`] most recently used note sets, so adding and
+/// then removing a note (or revisiting a recent voicing) is cheap.  The cache is bounded, so a long session does
+/// not grow the memory usage.
+#[derive(Clone, Debug, Default)]
+pub struct ChordRecognizer {
+    /// The accumulated notes.
+    notes: BTreeSet<Note>,
+    /// The best interpretation of the most recently used note sets (the most recent last).
+    cache: VecDeque<(Vec<Note>, Option<Chord>)>,
+    /// The best interpretation of the current notes.
+    current_best: Option<Chord>,
+}
+
+// Impls.
+
+impl ChordRecognizer {
+    /// Returns a new, empty [`ChordRecognizer`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the accumulated notes (in ascending order).
+    pub fn notes(&self) -> Vec<Note> {
+        self.notes.iter().copied().collect()
+    }
+
+    /// Returns the best interpretation of the accumulated notes, if there is one.
+    ///
+    /// At least three notes are required for an interpretation.
+    pub fn current_best(&self) -> Option<&Chord> {
+        self.current_best.as_ref()
+    }
+
+    /// Adds a note, and returns the new best interpretation.
+    pub fn add_note(&mut self, note: Note) -> Option<&Chord> {
+        if self.notes.insert(note) {
+            self.refresh();
+        }
+
+        self.current_best()
+    }
+
+    /// Removes a note, and returns the new best interpretation.
+    pub fn remove_note(&mut self, note: Note) -> Option<&Chord> {
+        if self.notes.remove(&note) {
+            self.refresh();
+        }
+
+        self.current_best()
+    }
+
+    /// Removes all of the accumulated notes (the cache of prior interpretations is kept).
+    pub fn clear(&mut self) {
+        self.notes.clear();
+        self.current_best = None;
+    }
+
+    /// Recomputes the best interpretation of the current notes (reusing a cached interpretation, if possible).
+    fn refresh(&mut self) {
+        if self.notes.len() < 3 {
+            self.current_best = None;
+            return;
+        }
+
+        let notes = self.notes();
+
+        let entry = match self.cache.iter().position(|(cached, _)| *cached == notes) {
+            Some(k) => self.cache.remove(k).unwrap(),
+            None => {
+                let best = Chord::try_from_notes(&notes).ok().and_then(|candidates| candidates.into_iter().next());
+
+                if self.cache.len() == RECOGNIZER_CACHE_CAPACITY {
+                    self.cache.pop_front();
+                }
+
+                (notes, best)
+            }
+        };
+
+        self.current_best = entry.1.clone();
+        self.cache.push_back(entry);
+    }
+}
+
+// Tests.
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{chord::Chordable, note::*};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_recognizer() {
+        let mut recognizer = ChordRecognizer::new();
+
+        assert_eq!(recognizer.add_note(C), None);
+        assert_eq!(recognizer.add_note(E), None);
+        assert_eq!(recognizer.add_note(G), Some(&Chord::new(C)));
+        assert_eq!(recognizer.add_note(B), Some(&Chord::new(C).major7()));
+
+        // Adding a duplicate note does not change anything.
+        assert_eq!(recognizer.add_note(B), Some(&Chord::new(C).major7()));
+        assert_eq!(recognizer.notes(), vec![C, E, G, B]);
+
+        // Removing a note goes back to the (cached) previous interpretation.
+        assert_eq!(recognizer.remove_note(B), Some(&Chord::new(C)));
+        assert_eq!(recognizer.remove_note(C), None);
+
+        recognizer.clear();
+        assert_eq!(recognizer.current_best(), None);
+        assert_eq!(recognizer.notes(), vec![]);
+    }
+
+    #[test]
+    fn test_recognizer_cache_is_bounded() {
+        let mut recognizer = ChordRecognizer::new();
+
+        recognizer.add_note(C);
+        recognizer.add_note(E);
+
+        // Cycle through many different note sets.
+        for note in ALL_PITCH_NOTES.iter().skip(60).take(2 * RECOGNIZER_CACHE_CAPACITY) {
+            recognizer.add_note(*note);
+            recognizer.remove_note(*note);
+        }
+
+        assert!(recognizer.cache.len() <= RECOGNIZER_CACHE_CAPACITY);
+
+        // The most recent interpretations are still correct.
+        assert_eq!(recognizer.add_note(G), Some(&Chord::new(C)));
+    }
+}
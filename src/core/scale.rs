@@ -0,0 +1,678 @@
+//! A module that contains the [`Scale`] struct and related traits.
+
+use std::fmt::{self, Display, Formatter};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{
+    base::{Err, HasName, HasStaticName, Parsable, Res},
+    chord::{Chord, Chordable, HasRoot, HasScale},
+    interval::Interval,
+    known_chord::{HasRelativeScale, KnownChord},
+    note::{Note, NoteRecreator},
+    octave::{HasOctave, Octave},
+};
+
+// Traits.
+
+/// A trait for types that have a relative key (e.g., C major and A minor).
+pub trait HasRelative {
+    /// Returns the relative of the implementor (most likely a [`Scale`]).
+    fn relative(&self) -> Self;
+}
+
+/// A trait for types that have a parallel key (e.g., C major and C minor).
+pub trait HasParallel {
+    /// Returns the parallel of the implementor (most likely a [`Scale`]).
+    fn parallel(&self) -> Self;
+}
+
+/// A trait for types that are built on a [`ScaleKind`] (e.g., a dominant chord is built on the mixolydian scale).
+pub trait HasScaleKind {
+    /// Returns the kind of scale the implementor (most likely a [`Chord`](crate::core::chord::Chord)) is built on.
+    fn scale_kind(&self) -> ScaleKind;
+}
+
+// Enum.
+
+/// An enum representing the kind of a [`Scale`].
+#[derive(PartialEq, Eq, Copy, Clone, Hash, Debug, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(u8)]
+pub enum ScaleKind {
+    /// The major scale (ionian, the first mode of the major scale).
+    Major,
+    /// The (natural) minor scale (aeolian, the sixth mode of the major scale).
+    Minor,
+
+    /// The dorian scale (the second mode of the major scale).
+    Dorian,
+    /// The phrygian scale (the third mode of the major scale).
+    Phrygian,
+    /// The lydian scale (the fourth mode of the major scale).
+    Lydian,
+    /// The mixolydian scale (the fifth mode of the major scale).
+    Mixolydian,
+    /// The locrian scale (the seventh mode of the major scale).
+    Locrian,
+
+    /// The harmonic minor scale.
+    HarmonicMinor,
+    /// The (jazz) melodic minor scale.
+    MelodicMinor,
+
+    /// The major pentatonic scale.
+    MajorPentatonic,
+    /// The minor pentatonic scale.
+    MinorPentatonic,
+
+    /// The ionian augmented scale (major with a sharp five, the third mode of harmonic minor).
+    IonianAugmented,
+    /// The lydian augmented scale (major with a sharp four and five, the third mode of melodic minor).
+    LydianAugmented,
+    /// The lydian dominant scale (major with a sharp four and flat seven, the fourth mode of melodic minor).
+    LydianDominant,
+    /// The locrian natural 2 scale (locrian with a major second, the sixth mode of melodic minor).
+    LocrianNatural2,
+    /// The altered scale (super locrian, the seventh mode of melodic minor).
+    Altered,
+
+    /// The whole tone scale.
+    WholeTone,
+    /// The whole / half diminished scale.
+    WholeHalfDiminished,
+    /// The half / whole diminished scale.
+    HalfWholeDiminished,
+}
+
+// Struct.
+
+/// A scale, which is a [`ScaleKind`] built on a root [`Note`].
+#[derive(PartialEq, Eq, Copy, Clone, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Scale {
+    /// The root note (tonic) of the scale.
+    root: Note,
+    /// The kind of the scale.
+    kind: ScaleKind,
+}
+
+// Impls.
+
+impl Scale {
+    /// Returns a new scale of the given kind with the given root.
+    pub fn new(root: Note, kind: ScaleKind) -> Self {
+        Self { root, kind }
+    }
+
+    /// Returns the kind of the scale.
+    pub fn kind(&self) -> ScaleKind {
+        self.kind
+    }
+
+    /// Returns the note on the given (1-indexed) degree of the scale (e.g., degree `5` of C major is `G`).
+    ///
+    /// Degrees past the end of the scale continue into the octaves above (e.g., degree `9` of C major is `D5`).
+    ///
+    /// Returns an error if `n` is `0`, or if the degree is above the highest octave.
+    pub fn degree(&self, n: u8) -> Res<Note> {
+        if n == 0 {
+            return Err(anyhow::Error::msg("Scale degrees start at 1."));
+        }
+
+        let notes = self.scale();
+        let index = (n - 1) as usize;
+        let note = notes[index % notes.len()];
+
+        let octave = u8::try_from(index / notes.len())
+            .ok()
+            .and_then(|octaves| (note.octave() as u8).checked_add(octaves))
+            .and_then(|octave| Octave::try_from(octave).ok())
+            .ok_or_else(|| anyhow::Error::msg("The scale degree is above the highest octave."))?;
+
+        Ok(note.with_octave(octave))
+    }
+
+    /// Returns the triad built (by stacking thirds of the scale) on the given (1-indexed) degree of the scale
+    /// (e.g., degree `2` of C major is `Dm`, and degree `7` is `Bm(♭5)`).
+    ///
+    /// Degrees past the end of the scale wrap around (e.g., degree `9` of C major is also `Dm`).
+    ///
+    /// Panics if `n` is `0`.
+    pub fn chord_on_degree(&self, n: u8) -> Chord {
+        self.tertian_chord_on_degree(n, false)
+    }
+
+    /// Returns the seventh chord built (by stacking thirds of the scale) on the given (1-indexed) degree of the scale
+    /// (e.g., degree `5` of C major is `G7`, and degree `7` of A harmonic minor is `G♯dim`).
+    ///
+    /// Degrees past the end of the scale wrap around (e.g., degree `12` of C major is also `G7`).
+    ///
+    /// Panics if `n` is `0`.
+    pub fn seventh_chord_on_degree(&self, n: u8) -> Chord {
+        self.tertian_chord_on_degree(n, true)
+    }
+
+    /// Builds the triad (or seventh chord) on the given degree from the scale's third, fifth, and seventh above it.
+    fn tertian_chord_on_degree(&self, n: u8, is_seventh: bool) -> Chord {
+        assert!(n > 0, "Scale degrees start at 1.");
+
+        // Wrap the degree into the scale, so that the thirds stacked above it cannot overflow.
+        let notes = self.scale();
+        let n = ((n - 1) as usize % notes.len()) as u8 + 1;
+        let root = notes[(n - 1) as usize];
+
+        // The intervals are the same in any octave, so they are measured in the default octave (where the degrees above are in range).
+        let default_scale = Scale::new(self.root.with_octave(Octave::default()), self.kind);
+        let default_degree = |k: u8| default_scale.degree(k).expect("The degrees above the default octave are in range.");
+
+        let third = default_degree(n + 2) - default_degree(n);
+        let fifth = default_degree(n + 4) - default_degree(n);
+        let seventh = default_degree(n + 6) - default_degree(n);
+
+        if is_seventh && third == Interval::MinorThird && fifth == Interval::DiminishedFifth && seventh == Interval::DiminishedSeventh {
+            return Chord::new(root).dim();
+        }
+
+        let mut chord = Chord::new(root);
+
+        if third == Interval::MinorThird {
+            chord = chord.minor();
+        }
+
+        match fifth {
+            Interval::DiminishedFifth => chord = chord.flat5(),
+            Interval::AugmentedFifth => chord = chord.augmented(),
+            _ => {}
+        }
+
+        if is_seventh {
+            match seventh {
+                Interval::MajorSeventh => chord = chord.major7(),
+                Interval::MinorSeventh => chord = chord.seven(),
+                _ => {}
+            }
+        }
+
+        chord
+    }
+}
+
+impl ScaleKind {
+    /// Returns the notes of this kind of scale, built on the given root.
+    pub fn notes(&self, root: Note) -> Vec<Note> {
+        self.relative_scale().into_iter().map(|i| root + i).collect()
+    }
+}
+
+impl HasStaticName for ScaleKind {
+    fn static_name(&self) -> &'static str {
+        match self {
+            ScaleKind::Major => "major",
+            ScaleKind::Minor => "minor",
+            ScaleKind::Dorian => "dorian",
+            ScaleKind::Phrygian => "phrygian",
+            ScaleKind::Lydian => "lydian",
+            ScaleKind::Mixolydian => "mixolydian",
+            ScaleKind::Locrian => "locrian",
+            ScaleKind::HarmonicMinor => "harmonic minor",
+            ScaleKind::MelodicMinor => "melodic minor",
+            ScaleKind::MajorPentatonic => "major pentatonic",
+            ScaleKind::MinorPentatonic => "minor pentatonic",
+            ScaleKind::IonianAugmented => "ionian augmented",
+            ScaleKind::LydianAugmented => "lydian augmented",
+            ScaleKind::LydianDominant => "lydian dominant",
+            ScaleKind::LocrianNatural2 => "locrian ♮2",
+            ScaleKind::Altered => "altered",
+            ScaleKind::WholeTone => "whole tone",
+            ScaleKind::WholeHalfDiminished => "whole / half diminished",
+            ScaleKind::HalfWholeDiminished => "half / whole diminished",
+        }
+    }
+}
+
+impl Parsable for ScaleKind {
+    /// Parses a kind of scale from its name (e.g., `"harmonic minor"`), or a common alias (e.g., `"aeolian"`, or `"super locrian"`).
+    ///
+    /// Case, whitespace, hyphens, underscores, and slashes are ignored.
+    fn parse(symbol: &str) -> Res<Self>
+    where
+        Self: Sized,
+    {
+        let normalized = symbol.trim().to_lowercase().replace([' ', '-', '_', '/', '♮'], "");
+
+        if let Some(kind) = ALL_SCALE_KINDS.iter().find(|k| k.static_name().replace([' ', '/', '♮'], "") == normalized) {
+            return Ok(*kind);
+        }
+
+        let kind = match normalized.as_str() {
+            "ionian" => ScaleKind::Major,
+            "aeolian" | "naturalminor" => ScaleKind::Minor,
+            "superlocrian" => ScaleKind::Altered,
+            "locriannatural2" => ScaleKind::LocrianNatural2,
+            "wholehalf" | "diminished" => ScaleKind::WholeHalfDiminished,
+            "halfwhole" | "dominantdiminished" => ScaleKind::HalfWholeDiminished,
+            _ => return Err(Err::msg(format!("Unknown scale kind: {symbol}."))),
+        };
+
+        Ok(kind)
+    }
+}
+
+impl HasName for ScaleKind {
+    fn name(&self) -> String {
+        self.static_name().to_owned()
+    }
+}
+
+impl Parsable for Scale {
+    /// Parses a scale from a root note followed by the name of its kind (e.g., `"C major"`, or `"F#3 dorian"`).
+    fn parse(symbol: &str) -> Res<Self>
+    where
+        Self: Sized,
+    {
+        let (root, kind) = symbol.trim().split_once(char::is_whitespace).ok_or_else(|| Err::msg("Please provide a root note and a scale kind (e.g., `C major`)."))?;
+
+        Ok(Scale::new(Note::parse(root)?, ScaleKind::parse(kind)?))
+    }
+}
+
+impl HasName for Scale {
+    fn name(&self) -> String {
+        format!("{} {}", self.root.name_without_octave(), self.kind.static_name())
+    }
+}
+
+impl Display for Scale {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl HasRoot for Scale {
+    fn root(&self) -> Note {
+        self.root
+    }
+}
+
+impl HasRelativeScale for ScaleKind {
+    fn relative_scale(&self) -> Vec<Interval> {
+        match self {
+            ScaleKind::Major => vec![
+                Interval::PerfectUnison,
+                Interval::MajorSecond,
+                Interval::MajorThird,
+                Interval::PerfectFourth,
+                Interval::PerfectFifth,
+                Interval::MajorSixth,
+                Interval::MajorSeventh,
+            ],
+            ScaleKind::Minor => vec![
+                Interval::PerfectUnison,
+                Interval::MajorSecond,
+                Interval::MinorThird,
+                Interval::PerfectFourth,
+                Interval::PerfectFifth,
+                Interval::MinorSixth,
+                Interval::MinorSeventh,
+            ],
+            ScaleKind::Dorian => vec![
+                Interval::PerfectUnison,
+                Interval::MajorSecond,
+                Interval::MinorThird,
+                Interval::PerfectFourth,
+                Interval::PerfectFifth,
+                Interval::MajorSixth,
+                Interval::MinorSeventh,
+            ],
+            ScaleKind::Phrygian => vec![
+                Interval::PerfectUnison,
+                Interval::MinorSecond,
+                Interval::MinorThird,
+                Interval::PerfectFourth,
+                Interval::PerfectFifth,
+                Interval::MinorSixth,
+                Interval::MinorSeventh,
+            ],
+            ScaleKind::Lydian => vec![
+                Interval::PerfectUnison,
+                Interval::MajorSecond,
+                Interval::MajorThird,
+                Interval::AugmentedFourth,
+                Interval::PerfectFifth,
+                Interval::MajorSixth,
+                Interval::MajorSeventh,
+            ],
+            ScaleKind::Mixolydian => vec![
+                Interval::PerfectUnison,
+                Interval::MajorSecond,
+                Interval::MajorThird,
+                Interval::PerfectFourth,
+                Interval::PerfectFifth,
+                Interval::MajorSixth,
+                Interval::MinorSeventh,
+            ],
+            ScaleKind::Locrian => vec![
+                Interval::PerfectUnison,
+                Interval::MinorSecond,
+                Interval::MinorThird,
+                Interval::PerfectFourth,
+                Interval::DiminishedFifth,
+                Interval::MinorSixth,
+                Interval::MinorSeventh,
+            ],
+            ScaleKind::HarmonicMinor => vec![
+                Interval::PerfectUnison,
+                Interval::MajorSecond,
+                Interval::MinorThird,
+                Interval::PerfectFourth,
+                Interval::PerfectFifth,
+                Interval::MinorSixth,
+                Interval::MajorSeventh,
+            ],
+            ScaleKind::MelodicMinor => vec![
+                Interval::PerfectUnison,
+                Interval::MajorSecond,
+                Interval::MinorThird,
+                Interval::PerfectFourth,
+                Interval::PerfectFifth,
+                Interval::MajorSixth,
+                Interval::MajorSeventh,
+            ],
+            ScaleKind::MajorPentatonic => vec![Interval::PerfectUnison, Interval::MajorSecond, Interval::MajorThird, Interval::PerfectFifth, Interval::MajorSixth],
+            ScaleKind::MinorPentatonic => vec![Interval::PerfectUnison, Interval::MinorThird, Interval::PerfectFourth, Interval::PerfectFifth, Interval::MinorSeventh],
+            ScaleKind::IonianAugmented => vec![
+                Interval::PerfectUnison,
+                Interval::MajorSecond,
+                Interval::MajorThird,
+                Interval::PerfectFourth,
+                Interval::AugmentedFifth,
+                Interval::MajorSixth,
+                Interval::MajorSeventh,
+            ],
+            ScaleKind::LydianAugmented => vec![
+                Interval::PerfectUnison,
+                Interval::MajorSecond,
+                Interval::MajorThird,
+                Interval::AugmentedFourth,
+                Interval::AugmentedFifth,
+                Interval::MajorSixth,
+                Interval::MajorSeventh,
+            ],
+            ScaleKind::LydianDominant => vec![
+                Interval::PerfectUnison,
+                Interval::MajorSecond,
+                Interval::MajorThird,
+                Interval::AugmentedFourth,
+                Interval::PerfectFifth,
+                Interval::MajorSixth,
+                Interval::MinorSeventh,
+            ],
+            ScaleKind::LocrianNatural2 => vec![
+                Interval::PerfectUnison,
+                Interval::MajorSecond,
+                Interval::MinorThird,
+                Interval::PerfectFourth,
+                Interval::DiminishedFifth,
+                Interval::MinorSixth,
+                Interval::MinorSeventh,
+            ],
+            ScaleKind::Altered => vec![
+                Interval::PerfectUnison,
+                Interval::MinorSecond,
+                Interval::MinorThird,
+                Interval::DiminishedFourth,
+                Interval::DiminishedFifth,
+                Interval::MinorSixth,
+                Interval::MinorSeventh,
+            ],
+            ScaleKind::WholeTone => vec![
+                Interval::PerfectUnison,
+                Interval::MajorSecond,
+                Interval::MajorThird,
+                Interval::AugmentedFourth,
+                Interval::AugmentedFifth,
+                Interval::AugmentedSixth,
+            ],
+            ScaleKind::WholeHalfDiminished => vec![
+                Interval::PerfectUnison,
+                Interval::MajorSecond,
+                Interval::MinorThird,
+                Interval::PerfectFourth,
+                Interval::DiminishedFifth,
+                Interval::MinorSixth,
+                Interval::DiminishedSeventh,
+                Interval::MajorSeventh,
+            ],
+            ScaleKind::HalfWholeDiminished => vec![
+                Interval::PerfectUnison,
+                Interval::MinorSecond,
+                Interval::MinorThird,
+                Interval::MajorThird,
+                Interval::AugmentedFourth,
+                Interval::PerfectFifth,
+                Interval::MajorSixth,
+                Interval::MinorSeventh,
+            ],
+        }
+    }
+}
+
+impl HasScaleKind for KnownChord {
+    fn scale_kind(&self) -> ScaleKind {
+        match self {
+            KnownChord::Unknown => unreachable!(),
+            KnownChord::Major => ScaleKind::Major,
+            KnownChord::Minor => ScaleKind::Minor,
+            KnownChord::Major7(_) => ScaleKind::Major,
+            KnownChord::Dominant(_) => ScaleKind::Mixolydian,
+            KnownChord::MinorMajor7(_) => ScaleKind::MelodicMinor,
+            KnownChord::MinorDominant(_) => ScaleKind::Dorian,
+            KnownChord::DominantSharp11(_) => ScaleKind::LydianDominant,
+            KnownChord::Augmented => ScaleKind::IonianAugmented,
+            KnownChord::AugmentedMajor7(_) => ScaleKind::LydianAugmented,
+            KnownChord::AugmentedDominant(_) => ScaleKind::WholeTone,
+            KnownChord::HalfDiminished(_) => ScaleKind::LocrianNatural2,
+            KnownChord::Diminished => ScaleKind::WholeHalfDiminished,
+            KnownChord::DominantFlat9(_) => ScaleKind::HalfWholeDiminished,
+            KnownChord::DominantSharp9(_) => ScaleKind::Altered,
+            KnownChord::Altered => ScaleKind::Altered,
+        }
+    }
+}
+
+impl HasScale for Scale {
+    fn scale(&self) -> Vec<Note> {
+        self.kind.notes(self.root)
+    }
+}
+
+impl HasRelative for Scale {
+    /// Returns the relative key of a major or minor scale (other kinds of scales are their own relative).
+    ///
+    /// When the relative tonic would fall outside of the playable range (i.e., below octave `0` or above octave `9`), it keeps
+    /// the octave of this tonic.
+    fn relative(&self) -> Self {
+        // The relative minor is a minor third below the major tonic (which keeps the enharmonic spelling correct).  The
+        // interval is applied in the default octave (where it cannot overflow), and the octave shift is then applied to the tonic.
+        let shift = |shifted: Note| {
+            let octaves = shifted.octave() as i8 - Octave::default() as i8;
+            let shifted = shifted.with_octave(self.root.octave());
+
+            shifted.transpose_octaves(octaves).unwrap_or(shifted)
+        };
+
+        let root = self.root.with_octave(Octave::default());
+
+        match self.kind {
+            ScaleKind::Major => Scale::new(shift(root - Interval::MinorThird), ScaleKind::Minor),
+            ScaleKind::Minor => Scale::new(shift(root + Interval::MinorThird), ScaleKind::Major),
+            _ => *self,
+        }
+    }
+}
+
+impl HasParallel for Scale {
+    /// Returns the parallel key of a major or minor scale (other kinds of scales are their own parallel).
+    fn parallel(&self) -> Self {
+        match self.kind {
+            ScaleKind::Major => Scale::new(self.root, ScaleKind::Minor),
+            ScaleKind::Minor => Scale::new(self.root, ScaleKind::Major),
+            _ => *self,
+        }
+    }
+}
+
+// Statics.
+
+/// All the kinds of scales.
+pub static ALL_SCALE_KINDS: [ScaleKind; 19] = [
+    ScaleKind::Major,
+    ScaleKind::Minor,
+    ScaleKind::Dorian,
+    ScaleKind::Phrygian,
+    ScaleKind::Lydian,
+    ScaleKind::Mixolydian,
+    ScaleKind::Locrian,
+    ScaleKind::HarmonicMinor,
+    ScaleKind::MelodicMinor,
+    ScaleKind::MajorPentatonic,
+    ScaleKind::MinorPentatonic,
+    ScaleKind::IonianAugmented,
+    ScaleKind::LydianAugmented,
+    ScaleKind::LydianDominant,
+    ScaleKind::LocrianNatural2,
+    ScaleKind::Altered,
+    ScaleKind::WholeTone,
+    ScaleKind::WholeHalfDiminished,
+    ScaleKind::HalfWholeDiminished,
+];
+
+// Tests.
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{named_pitch::NamedPitch, note::*};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_scale() {
+        assert_eq!(Scale::new(C, ScaleKind::Major).scale(), vec![C, D, E, F, G, A, B]);
+        assert_eq!(Scale::new(A, ScaleKind::Minor).scale(), vec![A, B, CFive, DFive, EFive, FFive, GFive]);
+    }
+
+    #[test]
+    fn test_degree() {
+        let c_major = Scale::new(C, ScaleKind::Major);
+
+        assert_eq!(c_major.degree(1).unwrap(), C);
+        assert_eq!(c_major.degree(5).unwrap(), G);
+        assert_eq!(c_major.degree(8).unwrap(), CFive);
+        assert_eq!(c_major.degree(9).unwrap(), DFive);
+        assert_eq!(Scale::new(A, ScaleKind::HarmonicMinor).degree(7).unwrap(), GSharpFive);
+        assert_eq!(Scale::new(C, ScaleKind::MajorPentatonic).degree(6).unwrap(), CFive);
+
+        // Degree zero, and degrees above the highest octave, are errors (rather than panics).
+        assert!(c_major.degree(0).is_err());
+        assert_eq!(c_major.degree(84).unwrap(), Note::new(NamedPitch::B, Octave::Fifteen));
+        assert!(c_major.degree(85).is_err());
+        assert!(c_major.degree(100).is_err());
+        assert!(c_major.degree(u8::MAX).is_err());
+    }
+
+    #[test]
+    fn test_chord_on_degree() {
+        let c_major = Scale::new(C, ScaleKind::Major);
+
+        assert_eq!(c_major.chord_on_degree(5), Chord::new(G));
+        assert_eq!(c_major.seventh_chord_on_degree(5), Chord::new(G).seven());
+        assert_eq!(c_major.seventh_chord_on_degree(5).name(), "G7");
+
+        let triads = (1..=7).map(|n| c_major.chord_on_degree(n).name()).collect::<Vec<_>>();
+        let sevenths = (1..=7).map(|n| c_major.seventh_chord_on_degree(n).name()).collect::<Vec<_>>();
+
+        assert_eq!(triads, vec!["C", "Dm", "Em", "F", "G", "Am", "Bm(♭5)"]);
+        assert_eq!(sevenths, vec!["Cmaj7", "Dm7", "Em7", "Fmaj7", "G7", "Am7", "Bm7(♭5)"]);
+
+        let a_harmonic_minor = Scale::new(A, ScaleKind::HarmonicMinor);
+
+        assert_eq!(a_harmonic_minor.seventh_chord_on_degree(1).name(), "Am(maj7)");
+        assert_eq!(a_harmonic_minor.seventh_chord_on_degree(3).name(), "C+(maj7)");
+        assert_eq!(a_harmonic_minor.seventh_chord_on_degree(5).name(), "E7");
+        assert_eq!(a_harmonic_minor.seventh_chord_on_degree(7).name(), "G♯dim");
+
+        // Degrees past the end of the scale wrap around (without overflowing).
+        assert_eq!(c_major.chord_on_degree(9), c_major.chord_on_degree(2));
+        assert_eq!(c_major.seventh_chord_on_degree(12).name(), "G7");
+        assert_eq!(c_major.seventh_chord_on_degree(u8::MAX), c_major.seventh_chord_on_degree(3));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", Scale::new(C, ScaleKind::Major)), "C major");
+        assert_eq!(format!("{}", Scale::new(FSharp, ScaleKind::Dorian)), "F♯ dorian");
+    }
+
+    #[test]
+    fn test_scale_kinds() {
+        assert_eq!(ScaleKind::Dorian.notes(D), vec![D, E, F, G, A, B, CFive]);
+        assert_eq!(ScaleKind::Phrygian.notes(E), vec![E, F, G, A, B, CFive, DFive]);
+        assert_eq!(ScaleKind::Lydian.notes(F), vec![F, G, A, B, CFive, DFive, EFive]);
+        assert_eq!(ScaleKind::Mixolydian.notes(G), vec![G, A, B, CFive, DFive, EFive, FFive]);
+        assert_eq!(ScaleKind::Locrian.notes(B), vec![B, CFive, DFive, EFive, FFive, GFive, AFive]);
+        assert_eq!(ScaleKind::HarmonicMinor.notes(A), vec![A, B, CFive, DFive, EFive, FFive, GSharpFive]);
+        assert_eq!(ScaleKind::MelodicMinor.notes(C), vec![C, D, EFlat, F, G, A, B]);
+        assert_eq!(ScaleKind::MajorPentatonic.notes(C), vec![C, D, E, G, A]);
+        assert_eq!(ScaleKind::MinorPentatonic.notes(A), vec![A, CFive, DFive, EFive, GFive]);
+
+        assert_eq!(ScaleKind::HarmonicMinor.name(), "harmonic minor");
+        assert_eq!(Scale::new(FSharp, ScaleKind::Mixolydian).name(), "F♯ mixolydian");
+        assert_eq!(Scale::new(C, ScaleKind::Dorian).relative(), Scale::new(C, ScaleKind::Dorian));
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(ScaleKind::parse("Harmonic Minor").unwrap(), ScaleKind::HarmonicMinor);
+        assert_eq!(ScaleKind::parse("aeolian").unwrap(), ScaleKind::Minor);
+        assert_eq!(ScaleKind::parse("locrian ♮2").unwrap(), ScaleKind::LocrianNatural2);
+        assert_eq!(ScaleKind::parse("whole/half diminished").unwrap(), ScaleKind::WholeHalfDiminished);
+        assert!(ScaleKind::parse("klingon").is_err());
+
+        for kind in ALL_SCALE_KINDS {
+            assert_eq!(ScaleKind::parse(kind.static_name()).unwrap(), kind);
+        }
+
+        assert_eq!(Scale::parse("C major").unwrap(), Scale::new(C, ScaleKind::Major));
+        assert_eq!(Scale::parse(" F#3  lydian dominant ").unwrap(), Scale::new(FSharpThree, ScaleKind::LydianDominant));
+        assert_eq!(Scale::parse(&Scale::new(BFlat, ScaleKind::Dorian).name()).unwrap(), Scale::new(BFlat, ScaleKind::Dorian));
+        assert!(Scale::parse("major").is_err());
+        assert!(Scale::parse("H major").is_err());
+    }
+
+    #[test]
+    fn test_relative() {
+        assert_eq!(Scale::new(C, ScaleKind::Major).relative(), Scale::new(AThree, ScaleKind::Minor));
+        assert_eq!(Scale::new(A, ScaleKind::Minor).relative(), Scale::new(CFive, ScaleKind::Major));
+        assert_eq!(Scale::new(EFlat, ScaleKind::Major).relative(), Scale::new(CFour, ScaleKind::Minor));
+        assert_eq!(Scale::new(FSharp, ScaleKind::Major).relative(), Scale::new(DSharp, ScaleKind::Minor));
+        assert_eq!(Scale::new(BFlat, ScaleKind::Minor).relative(), Scale::new(DFlatFive, ScaleKind::Major));
+        assert_eq!(Scale::new(C, ScaleKind::Major).relative().relative(), Scale::new(C, ScaleKind::Major));
+
+        // A tonic at the edge of the range of octaves keeps its octave (rather than panicking).
+        assert_eq!(Scale::new(CZero, ScaleKind::Major).relative(), Scale::new(AZero, ScaleKind::Minor));
+        assert_eq!(
+            Scale::new(Note::new(NamedPitch::A, Octave::Fifteen), ScaleKind::Minor).relative(),
+            Scale::new(Note::new(NamedPitch::C, Octave::Fifteen), ScaleKind::Major)
+        );
+    }
+
+    #[test]
+    fn test_parallel() {
+        assert_eq!(Scale::new(C, ScaleKind::Major).parallel(), Scale::new(C, ScaleKind::Minor));
+        assert_eq!(Scale::new(C, ScaleKind::Minor).parallel(), Scale::new(C, ScaleKind::Major));
+        assert_eq!(Scale::new(C, ScaleKind::Major).parallel().scale(), vec![C, D, EFlat, F, G, AFlat, BFlat]);
+        assert_eq!(Scale::new(DFlat, ScaleKind::Major).parallel().scale(), vec![DFlat, EFlat, FFlat, GFlat, AFlat, BDoubleFlat, CFlatFive]);
+    }
+}
@@ -0,0 +1,121 @@
+//! A module for tuning systems, which determine the frequency of a [`Note`].
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{
+    note::{Note, A},
+    pitch::HasFrequency,
+};
+
+// Constants.
+
+/// The standard concert pitch of `A4`, in Hz.
+pub const CONCERT_A4: f32 = 440.0;
+
+/// The 5-limit just intonation ratios of each semitone above the tonic.
+const JUST_RATIOS: [f32; 12] = [
+    1.0,
+    16.0 / 15.0,
+    9.0 / 8.0,
+    6.0 / 5.0,
+    5.0 / 4.0,
+    4.0 / 3.0,
+    45.0 / 32.0,
+    3.0 / 2.0,
+    8.0 / 5.0,
+    5.0 / 3.0,
+    9.0 / 5.0,
+    15.0 / 8.0,
+];
+
+// Enum.
+
+/// A tuning system, which determines the frequency of every [`Note`].
+#[derive(PartialEq, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum TuningSystem {
+    /// Twelve-tone equal temperament, where `A4` has the given frequency (in Hz).
+    EqualTemperament {
+        /// The frequency of `A4` (e.g., `440.0` or `432.0`).
+        reference_a4: f32,
+    },
+    /// 5-limit just intonation, where every note is tuned by a whole-number ratio relative to the tonic.
+    ///
+    /// The tonic itself keeps its standard (equal-tempered, `A4 = 440`) frequency.
+    JustIntonation {
+        /// The tonic that the ratios are relative to (in any octave).
+        tonic: Note,
+    },
+}
+
+// Impls.
+
+impl Default for TuningSystem {
+    fn default() -> Self {
+        Self::EqualTemperament { reference_a4: CONCERT_A4 }
+    }
+}
+
+impl TuningSystem {
+    /// Returns the frequency of the given [`Note`] in this tuning system.
+    pub fn frequency_of(&self, note: &Note) -> f32 {
+        match self {
+            TuningSystem::EqualTemperament { reference_a4 } => reference_a4 * 2.0_f32.powf((note.semitones_from_c_zero() - A.semitones_from_c_zero()) as f32 / 12.0),
+            TuningSystem::JustIntonation { tonic } => {
+                let distance = (note.semitones_from_c_zero() - tonic.semitones_from_c_zero()) as i32;
+
+                tonic.frequency() * JUST_RATIOS[distance.rem_euclid(12) as usize] * 2.0_f32.powi(distance.div_euclid(12))
+            }
+        }
+    }
+}
+
+impl Note {
+    /// Returns the frequency of the [`Note`] in the given [`TuningSystem`].
+    ///
+    /// Unlike [`HasFrequency::frequency`], which always uses twelve-tone equal temperament with `A4 = 440`, this allows, e.g.,
+    /// `A4 = 432`, or just intonation.
+    pub fn frequency_in(&self, tuning: &TuningSystem) -> f32 {
+        tuning.frequency_of(self)
+    }
+}
+
+// Tests.
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{
+        named_pitch::NamedPitch,
+        note::{CFive, C, E, G},
+        octave::Octave,
+    };
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_tuning_systems() {
+        let standard = TuningSystem::default();
+        let low = TuningSystem::EqualTemperament { reference_a4: 432.0 };
+        let just = TuningSystem::JustIntonation { tonic: C };
+
+        assert_eq!(A.frequency_in(&standard), 440.0);
+        assert_eq!(A.frequency_in(&low), 432.0);
+        assert!((CFive.frequency_in(&low) / C.frequency_in(&low) - 2.0).abs() < 0.0001);
+
+        // Just intonation uses pure ratios relative to the tonic.
+        assert_eq!(C.frequency_in(&just), C.frequency());
+        assert!((G.frequency_in(&just) / C.frequency_in(&just) - 1.5).abs() < 0.0001);
+        assert!((E.frequency_in(&just) / C.frequency_in(&just) - 1.25).abs() < 0.0001);
+
+        // The equal-tempered fifth is about two cents narrower than the pure fifth.
+        let fifth = G.frequency_in(&standard) / C.frequency_in(&standard);
+        assert!(fifth < 1.5);
+        assert!((1200.0 * (1.5 / fifth).log2() - 1.955).abs() < 0.01);
+
+        // Notes that cross an octave (e.g., `B♯3`) are the same as their enharmonic equivalents.
+        let b_sharp_three = Note::new(NamedPitch::BSharp, Octave::Three);
+        assert_eq!(b_sharp_three.frequency_in(&low), C.frequency_in(&low));
+        assert_eq!(b_sharp_three.frequency_in(&just), C.frequency_in(&just));
+    }
+}
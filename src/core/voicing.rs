@@ -0,0 +1,138 @@
+//! A module for voicing the tones of a [`Chord`] (e.g., the "drop" voicings used by jazz arrangers).
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{
+    base::{HasStaticName, Parsable, Res},
+    chord::{Chord, HasChord, HasRoot, HasSlash},
+    interval::Interval,
+    note::Note,
+    octave::{HasOctave, Octave},
+};
+
+// Enum.
+
+/// A voicing of the tones of a chord.
+#[derive(PartialEq, Eq, Copy, Clone, Hash, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Voicing {
+    /// Close position (i.e., the tones of [`HasChord::chord`], as they are).
+    #[default]
+    Close,
+    /// Drop 2, where the second-highest voice is dropped an octave.
+    Drop2,
+    /// Drop 3, where the third-highest voice is dropped an octave.
+    Drop3,
+    /// Drop 2 and 4, where the second-highest and fourth-highest voices are dropped an octave.
+    Drop2And4,
+}
+
+// Impls.
+
+impl Voicing {
+    /// Returns the voices (counted from the top, starting at `1`) that are dropped an octave.
+    fn dropped_voices(&self) -> &'static [usize] {
+        match self {
+            Voicing::Close => &[],
+            Voicing::Drop2 => &[2],
+            Voicing::Drop3 => &[3],
+            Voicing::Drop2And4 => &[2, 4],
+        }
+    }
+}
+
+impl HasStaticName for Voicing {
+    fn static_name(&self) -> &'static str {
+        match self {
+            Voicing::Close => "close",
+            Voicing::Drop2 => "drop2",
+            Voicing::Drop3 => "drop3",
+            Voicing::Drop2And4 => "drop2and4",
+        }
+    }
+}
+
+impl Parsable for Voicing {
+    fn parse(symbol: &str) -> Res<Self>
+    where
+        Self: Sized,
+    {
+        let voicing = match symbol.trim().to_ascii_lowercase().replace([' ', '-', '_'], "").as_str() {
+            "close" => Voicing::Close,
+            "drop2" => Voicing::Drop2,
+            "drop3" => Voicing::Drop3,
+            "drop24" | "drop2and4" => Voicing::Drop2And4,
+            _ => return Err(crate::core::base::Err::msg("Please use a valid voicing (close, drop2, drop3, or drop2and4).")),
+        };
+
+        Ok(voicing)
+    }
+}
+
+impl Chord {
+    /// Returns the tones of the chord (from lowest to highest) in the given [`Voicing`].
+    ///
+    /// The voicing starts from the close-position tones of [`HasChord::chord`].  For slash chords, the bass note is kept as the
+    /// lowest note (moving it down an octave if a dropped voice would be below it), and only the upper voices are dropped.
+    /// Voices that do not exist (e.g., the fourth-highest voice of a triad) are ignored.
+    pub fn notes_with_voicing(&self, voicing: Voicing) -> Vec<Note> {
+        let mut upper = self.chord();
+        let bass = (self.slash() != self.root()).then(|| upper.remove(0));
+
+        let count = upper.len();
+
+        for voice in voicing.dropped_voices() {
+            if *voice <= count {
+                let note = &mut upper[count - voice];
+
+                if note.octave() > Octave::Zero {
+                    *note = *note - Interval::PerfectOctave;
+                }
+            }
+        }
+
+        upper.sort();
+
+        match bass {
+            Some(mut bass) => {
+                while upper.first().map(|n| *n < bass).unwrap_or(false) && bass.octave() > Octave::Zero {
+                    bass = bass - Interval::PerfectOctave;
+                }
+
+                std::iter::once(bass).chain(upper).collect()
+            }
+            None => upper,
+        }
+    }
+}
+
+// Tests.
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{chord::Chordable, note::*};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_drop_voicings() {
+        let chord = Chord::new(C).major7();
+
+        assert_eq!(chord.notes_with_voicing(Voicing::Close), vec![C, E, G, B]);
+        assert_eq!(chord.notes_with_voicing(Voicing::Drop2), vec![GThree, C, E, B]);
+        assert_eq!(chord.notes_with_voicing(Voicing::Drop3), vec![EThree, C, G, B]);
+        assert_eq!(chord.notes_with_voicing(Voicing::Drop2And4), vec![CThree, GThree, E, B]);
+
+        // Missing voices are ignored.
+        assert_eq!(Chord::new(C).notes_with_voicing(Voicing::Drop2And4), vec![EThree, C, G]);
+
+        // The bass of a slash chord stays the lowest note.
+        assert_eq!(Chord::parse("Cmaj7/D").unwrap().notes_with_voicing(Voicing::Drop2), vec![DThree, GThree, C, E, B]);
+        assert_eq!(Chord::parse("Cmaj7/A").unwrap().notes_with_voicing(Voicing::Drop3), vec![ATwo, EThree, C, G, B]);
+
+        assert_eq!(Voicing::parse("drop2").unwrap(), Voicing::Drop2);
+        assert_eq!(Voicing::parse("Drop 2-4").unwrap(), Voicing::Drop2And4);
+        assert!(Voicing::parse("drop5").is_err());
+    }
+}
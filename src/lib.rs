@@ -47,6 +47,18 @@
 #![feature(concat_idents)]
 #![feature(iter_advance_by)]
 #![feature(int_roundings)]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(not(any(feature = "std", feature = "no_std")))]
+compile_error!("Either the `std` feature (enabled by default) or the `no_std` feature must be enabled.");
+
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+
+// The `cdylib` crate type (for the WASM package) needs a panic handler and a global allocator, so, on hosted targets, `no_std`
+// builds still link `std` for those (without bringing it into scope); bare metal consumers of the `rlib` provide their own.
+#[cfg(all(not(feature = "std"), not(target_os = "none")))]
+extern crate std as _;
 
 pub mod core;
 pub mod helpers;
@@ -12,7 +12,7 @@ use burn::tensor::{backend::Backend, Tensor};
 use byteorder::{BigEndian, ReadBytesExt, WriteBytesExt};
 
 use crate::{
-    analyze::base::get_notes_from_smoothed_frequency_space,
+    analyze::base::{get_notes_from_smoothed_frequency_space_with_options, AnalyzeOptions},
     core::{
         base::Res,
         helpers::{inv_mel, mel},
@@ -146,7 +146,14 @@ pub fn linspace(start: f32, end: f32, num_points: usize) -> Vec<f32> {
 pub fn get_deterministic_guess(kord_item: &KordItem) -> u128 {
     let smoothed_frequency_space = kord_item.frequency_space.into_iter().enumerate().map(|(k, v)| (k as f32, v)).collect::<Vec<_>>();
 
-    let notes = get_notes_from_smoothed_frequency_space(&smoothed_frequency_space);
+    // The models were trained with the "legacy" harmonic reduction (and no peak merging), so keep the features stable.
+    let options = AnalyzeOptions {
+        collapse_octave_harmonics: false,
+        merge_tolerance_cents: 0.0,
+        ..Default::default()
+    };
+
+    let notes = get_notes_from_smoothed_frequency_space_with_options(&smoothed_frequency_space, &options);
 
     Note::id_mask(&notes)
 }
@@ -203,6 +210,11 @@ impl Sigmoid {
         Self { scale }
     }
 
+    /// Returns the scale (i.e., strength) of the sigmoid.
+    pub fn scale(&self) -> f32 {
+        self.scale
+    }
+
     /// Forward pass.
     pub fn forward<B: Backend, const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
         let scaled = input.mul_scalar(self.scale);
@@ -6,9 +6,12 @@ pub mod gather;
 pub mod helpers;
 pub mod mlp;
 pub mod model;
+pub mod onnx;
+pub mod quantize;
 
 use burn::config::Config;
-use std::path::PathBuf;
+use serde::{Deserialize, Serialize};
+use std::{path::PathBuf, str::FromStr};
 
 /// The standard frequency space size to use across all ML operations.
 ///
@@ -24,6 +27,9 @@ pub const MEL_SPACE_SIZE: usize = 512;
 /// The standard number of classes to use across all ML operations.
 pub const NUM_CLASSES: usize = 128;
 
+/// The default probability at (or above) which the model's output for a note is considered present.
+pub const DEFAULT_INFERENCE_THRESHOLD: f32 = 0.5;
+
 // Training configuration.
 
 /// The training configuration used for all training, inference, and hyper parameter tuning.
@@ -77,6 +83,57 @@ pub struct TrainConfig {
 
     /// Suppresses the training plots.
     pub no_plots: bool,
+
+    /// The path to which the per-epoch metrics history is written (as CSV if it ends in `.csv`, otherwise as JSON).
+    pub metrics_out: Option<String>,
+
+    /// The early stopping options, which stop training once the validation accuracy plateaus (and restore the best model).
+    pub early_stopping: Option<EarlyStopping>,
+    /// The learning rate schedule, which adjusts the Adam learning rate every epoch (defaults to a constant learning rate).
+    pub lr_schedule: Option<LrSchedule>,
+    /// The loss function that is minimized during training (defaults to the mean square loss).
+    pub loss: Option<TrainLoss>,
+
+    /// The real (recorded) samples to mix into the synthetic training data.
+    pub real_mix: Option<RealSampleMix>,
+}
+
+/// Options for mixing real (recorded) samples into the synthetic training data.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RealSampleMix {
+    /// The directory of real samples (saved as [`KordItem`] `*.bin` files).
+    pub source: String,
+    /// The fraction of the training data that is real, which must be less than `1.0` (e.g., `0.3` for 70% synthetic and 30% real).
+    pub ratio: f32,
+}
+
+/// Early stopping options for training.
+///
+/// Training stops once the validation accuracy has not improved for `patience` epochs, and the model
+/// from the best epoch (rather than the last) is kept.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct EarlyStopping {
+    /// The number of epochs without improvement after which training stops.
+    pub patience: usize,
+    /// The minimum increase in validation accuracy that counts as an improvement.
+    pub min_delta: f64,
+}
+
+impl EarlyStopping {
+    /// Creates new [`EarlyStopping`] options.
+    pub fn new(patience: usize, min_delta: f64) -> Self {
+        Self { patience, min_delta }
+    }
+
+    /// Returns whether the validation `accuracy` is an improvement over the `best` validation accuracy so far.
+    pub fn is_improvement(&self, best: f64, accuracy: f64) -> bool {
+        accuracy - best > self.min_delta
+    }
+
+    /// Returns whether training should stop after the given number of epochs without improvement.
+    pub fn should_stop(&self, epochs_without_improvement: usize) -> bool {
+        epochs_without_improvement >= self.patience
+    }
 }
 
 /// A single kord sample.
@@ -101,3 +158,165 @@ impl Default for KordItem {
         }
     }
 }
+
+/// A learning rate schedule for training, which is applied per epoch.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum LrSchedule {
+    /// A constant learning rate.
+    Constant,
+    /// Multiplies the learning rate by `gamma` every `step_size` epochs.
+    StepDecay {
+        /// The number of epochs between each decay.
+        step_size: usize,
+        /// The factor by which the learning rate decays.
+        gamma: f64,
+    },
+    /// Anneals the learning rate from the base learning rate (at the first epoch) down to `min_lr` (at the last epoch)
+    /// along a cosine curve.
+    CosineAnnealing {
+        /// The learning rate at the last epoch.
+        min_lr: f64,
+    },
+    /// Linearly increases the learning rate up to the base learning rate over the first `warmup_epochs` epochs.
+    LinearWarmup {
+        /// The number of epochs over which the learning rate warms up.
+        warmup_epochs: usize,
+    },
+}
+
+impl LrSchedule {
+    /// Returns the learning rate for the given (1-based) `epoch` of `num_epochs`, given the `base` learning rate.
+    pub fn learning_rate(&self, base: f64, epoch: usize, num_epochs: usize) -> f64 {
+        match *self {
+            LrSchedule::Constant => base,
+            LrSchedule::StepDecay { step_size, gamma } => base * gamma.powi((epoch.saturating_sub(1) / step_size.max(1)) as i32),
+            LrSchedule::CosineAnnealing { min_lr } => {
+                if num_epochs <= 1 {
+                    return base;
+                }
+
+                let progress = epoch.saturating_sub(1) as f64 / (num_epochs - 1) as f64;
+
+                min_lr + 0.5 * (base - min_lr) * (1.0 + (std::f64::consts::PI * progress).cos())
+            }
+            LrSchedule::LinearWarmup { warmup_epochs } => {
+                if epoch >= warmup_epochs {
+                    base
+                } else {
+                    base * epoch as f64 / warmup_epochs as f64
+                }
+            }
+        }
+    }
+}
+
+impl FromStr for LrSchedule {
+    type Err = anyhow::Error;
+
+    /// Parses a schedule of the form `constant`, `step:<step_size>:<gamma>`, `cosine:<min_lr>`, or `warmup:<warmup_epochs>`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts = s.split(':').map(str::trim).collect::<Vec<_>>();
+
+        let schedule = match parts.as_slice() {
+            ["constant"] => LrSchedule::Constant,
+            ["step", step_size, gamma] => LrSchedule::StepDecay {
+                step_size: step_size.parse()?,
+                gamma: gamma.parse()?,
+            },
+            ["cosine", min_lr] => LrSchedule::CosineAnnealing { min_lr: min_lr.parse()? },
+            ["warmup", warmup_epochs] => LrSchedule::LinearWarmup { warmup_epochs: warmup_epochs.parse()? },
+            _ => {
+                return Err(anyhow::Error::msg(
+                    "The schedule must be `constant`, `step:<step_size>:<gamma>`, `cosine:<min_lr>`, or `warmup:<warmup_epochs>`.",
+                ))
+            }
+        };
+
+        Ok(schedule)
+    }
+}
+
+/// A loss function for training.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum TrainLoss {
+    /// The mean square error between the outputs and the targets.
+    MeanSquare,
+    /// The binary cross entropy of the outputs.
+    BinaryCrossEntropy,
+    /// The focal loss, which down-weights the well-classified outputs.
+    Focal {
+        /// The focusing parameter (`0.0` is the binary cross entropy).
+        gamma: f32,
+    },
+    /// The soft F-beta loss, which only counts the positive classes (and is well suited to sparse multi-label targets).
+    SoftF1 {
+        /// The weight of recall relative to precision (`1.0` is the F1 loss).
+        beta: f32,
+    },
+}
+
+impl FromStr for TrainLoss {
+    type Err = anyhow::Error;
+
+    /// Parses a loss of the form `mse`, `bce`, `focal:<gamma>`, or `f1:<beta>`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts = s.split(':').map(str::trim).collect::<Vec<_>>();
+
+        let loss = match parts.as_slice() {
+            ["mse"] => TrainLoss::MeanSquare,
+            ["bce"] => TrainLoss::BinaryCrossEntropy,
+            ["focal", gamma] => TrainLoss::Focal { gamma: gamma.parse()? },
+            ["f1", beta] => TrainLoss::SoftF1 { beta: beta.parse()? },
+            _ => return Err(anyhow::Error::msg("The loss must be `mse`, `bce`, `focal:<gamma>`, or `f1:<beta>`.")),
+        };
+
+        Ok(loss)
+    }
+}
+
+// Tests.
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_early_stopping() {
+        let early_stopping = EarlyStopping::new(2, 0.01);
+
+        assert!(early_stopping.is_improvement(0.5, 0.52));
+        assert!(!early_stopping.is_improvement(0.5, 0.505));
+        assert!(!early_stopping.is_improvement(0.5, 0.4));
+
+        assert!(!early_stopping.should_stop(1));
+        assert!(early_stopping.should_stop(2));
+    }
+
+    #[test]
+    fn test_lr_schedule() {
+        let step = LrSchedule::from_str("step:2:0.5").unwrap();
+        assert_eq!((1..=5).map(|e| step.learning_rate(1.0, e, 5)).collect::<Vec<_>>(), vec![1.0, 1.0, 0.5, 0.5, 0.25]);
+
+        let cosine = LrSchedule::from_str("cosine:0").unwrap();
+        assert_eq!(cosine.learning_rate(1.0, 1, 5), 1.0);
+        assert!((cosine.learning_rate(1.0, 3, 5) - 0.5).abs() < 1e-12);
+        assert!(cosine.learning_rate(1.0, 5, 5).abs() < 1e-12);
+
+        let warmup = LrSchedule::from_str("warmup:4").unwrap();
+        assert_eq!((1..=5).map(|e| warmup.learning_rate(1.0, e, 5)).collect::<Vec<_>>(), vec![0.25, 0.5, 0.75, 1.0, 1.0]);
+
+        assert_eq!(LrSchedule::from_str("constant").unwrap().learning_rate(0.1, 3, 5), 0.1);
+        assert!(LrSchedule::from_str("cosine").is_err());
+    }
+
+    #[test]
+    fn test_train_loss() {
+        assert_eq!(TrainLoss::from_str("mse").unwrap(), TrainLoss::MeanSquare);
+        assert_eq!(TrainLoss::from_str("bce").unwrap(), TrainLoss::BinaryCrossEntropy);
+        assert_eq!(TrainLoss::from_str("focal:2").unwrap(), TrainLoss::Focal { gamma: 2.0 });
+        assert_eq!(TrainLoss::from_str("f1:0.5").unwrap(), TrainLoss::SoftF1 { beta: 0.5 });
+
+        assert!(TrainLoss::from_str("f1").is_err());
+        assert!(TrainLoss::from_str("hinge").is_err());
+    }
+}
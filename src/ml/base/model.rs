@@ -8,12 +8,12 @@ use burn::{
     tensor::{backend::Backend, Tensor},
 };
 
-use super::{helpers::Sigmoid, mlp::Mlp, INPUT_SPACE_SIZE, NUM_CLASSES};
+use super::{helpers::Sigmoid, mlp::Mlp, TrainLoss, INPUT_SPACE_SIZE, NUM_CLASSES};
 
 #[cfg(feature = "ml_train")]
 use crate::ml::train::{
     data::KordBatch,
-    helpers::{KordClassificationOutput, MeanSquareLoss},
+    helpers::{BinaryCrossEntropyLoss, FocalLoss, KordClassificationOutput, MeanSquareLoss, SoftF1Loss},
 };
 
 /// The primary model type for identifying notes / chords.
@@ -23,6 +23,8 @@ pub struct KordModel<B: Backend> {
     mlp: Param<Mlp<B>>,
     output: Param<nn::Linear<B>>,
     sigmoid: Sigmoid,
+    #[cfg_attr(not(feature = "ml_train"), allow(dead_code))]
+    loss: TrainLoss,
 }
 
 impl<B: Backend> KordModel<B> {
@@ -38,9 +40,21 @@ impl<B: Backend> KordModel<B> {
             mlp: Param::from(mlp),
             output: Param::from(output),
             sigmoid,
+            loss: TrainLoss::MeanSquare,
         }
     }
 
+    /// Sets the loss function that is minimized during training (see [`KordModel::forward_classification`]).
+    pub fn with_loss(mut self, loss: TrainLoss) -> Self {
+        self.loss = loss;
+        self
+    }
+
+    /// Returns the strength of the output sigmoid.
+    pub fn sigmoid_strength(&self) -> f32 {
+        self.sigmoid.scale()
+    }
+
     /// Forward pass through the model.
     pub fn forward(&self, input: Tensor<B, 2>) -> Tensor<B, 2> {
         let mut x = input;
@@ -59,15 +73,20 @@ impl<B: Backend> KordModel<B> {
         let targets = item.targets;
         let output = self.forward(item.samples);
 
-        let loss = MeanSquareLoss::default();
-        let loss = loss.forward(output.clone(), targets.clone());
-
-        // let loss = BinaryCrossEntropyLoss::default();
-        // let loss = loss.forward(output.clone(), targets.clone());
-
-        // let mut loss = FocalLoss::default();
-        // loss.gamma = 2.0;
-        // let loss = loss.forward(output.clone(), targets.clone());
+        let loss = match self.loss {
+            TrainLoss::MeanSquare => MeanSquareLoss::default().forward(output.clone(), targets.clone()),
+            TrainLoss::BinaryCrossEntropy => BinaryCrossEntropyLoss::default().forward(output.clone(), targets.clone()),
+            TrainLoss::Focal { gamma } => {
+                let mut loss = FocalLoss::default();
+                loss.gamma = gamma;
+                loss.forward(output.clone(), targets.clone())
+            }
+            TrainLoss::SoftF1 { beta } => {
+                let mut loss = SoftF1Loss::default();
+                loss.beta = beta;
+                loss.forward(output.clone(), targets.clone())
+            }
+        };
 
         //let loss = loss + l1_regularization(self, 1e-4);
 
@@ -0,0 +1,448 @@
+//! Export of a trained [`KordModel`] to [ONNX](https://onnx.ai), so that it can be run by non-Rust runtimes (e.g., `onnxruntime-web`).
+//!
+//! The exported graph takes the `[batch, INPUT_SPACE_SIZE]` sample (as produced from the `FREQUENCY_SPACE_SIZE` frequency space by
+//! [`kord_item_to_sample_tensor`](super::data::kord_item_to_sample_tensor)), and produces the `[batch, NUM_CLASSES]` sigmoid output.
+
+use std::path::Path;
+
+use burn::{
+    module::{Module, ModuleVisitor, ParamId},
+    tensor::{backend::Backend, Tensor},
+};
+
+use crate::core::base::{Res, Void};
+
+use super::{model::KordModel, INPUT_SPACE_SIZE, NUM_CLASSES};
+
+/// The ONNX IR version of the exported model.
+const ONNX_IR_VERSION: i64 = 8;
+
+/// The ONNX (default domain) opset version of the exported model.
+const ONNX_OPSET_VERSION: i64 = 13;
+
+/// The ONNX `FLOAT` tensor element type.
+const ONNX_FLOAT: i64 = 1;
+
+/// The name of the exported graph input.
+pub const ONNX_INPUT_NAME: &str = "input";
+
+/// The name of the exported graph output.
+pub const ONNX_OUTPUT_NAME: &str = "output";
+
+// Model export.
+
+/// Serializes the trained model to the bytes of an ONNX model.
+///
+/// The linear layers are mapped to `Gemm`, the activations to `Relu`, and the scaled sigmoid to `Mul` followed by `Sigmoid`
+/// (dropout is the identity at inference time, so it is omitted).
+pub fn model_to_onnx<B: Backend>(model: &KordModel<B>) -> Res<Vec<u8>> {
+    let layers = get_linear_layers(model)?;
+
+    Ok(encode_model(&layers, model.sigmoid_strength()))
+}
+
+/// Saves the trained model as an ONNX model at the given path.
+pub fn save_model_to_onnx<B: Backend>(model: &KordModel<B>, path: impl AsRef<Path>) -> Void {
+    std::fs::write(path, model_to_onnx(model)?)?;
+
+    Ok(())
+}
+
+// Layer extraction.
+
+/// The weights of a linear layer.
+#[derive(Clone, Debug)]
+pub(crate) struct LinearLayer {
+    /// The number of inputs.
+    pub inputs: usize,
+    /// The number of outputs.
+    pub outputs: usize,
+    /// The `[inputs, outputs]` weights (in row-major order).
+    pub weight: Vec<f32>,
+    /// The `[outputs]` bias.
+    pub bias: Vec<f32>,
+}
+
+/// Collects the linear layers of a module (in order), where each 2D weight is followed by its (optional) 1D bias.
+#[derive(Debug, Default)]
+struct LinearLayerVisitor {
+    layers: Vec<LinearLayer>,
+    error: Option<String>,
+}
+
+impl<B: Backend> ModuleVisitor<B> for LinearLayerVisitor {
+    fn visit<const D: usize>(&mut self, _: &ParamId, tensor: &Tensor<B, D>) {
+        let dims = tensor.shape().dims;
+        let values: Vec<f32> = tensor.to_data().convert().value;
+
+        match (D, self.layers.last_mut()) {
+            (2, _) => self.layers.push(LinearLayer {
+                inputs: dims[0],
+                outputs: dims[1],
+                weight: values,
+                bias: Vec::new(),
+            }),
+            (1, Some(layer)) if layer.bias.is_empty() && layer.outputs == dims[0] => layer.bias = values,
+            _ => self.error = Some(format!("Unexpected parameter of shape {:?} in the model.", dims)),
+        }
+    }
+}
+
+/// Returns the linear layers of the model (input, hidden, and output), with missing biases filled with zeros.
+pub(crate) fn get_linear_layers<B: Backend>(model: &KordModel<B>) -> Res<Vec<LinearLayer>> {
+    let mut visitor = LinearLayerVisitor::default();
+    model.visit(&mut visitor);
+
+    if let Some(error) = visitor.error {
+        return Err(anyhow::Error::msg(error));
+    }
+
+    let mut layers = visitor.layers;
+
+    if layers.len() < 2 || layers[0].inputs != INPUT_SPACE_SIZE || layers[layers.len() - 1].outputs != NUM_CLASSES {
+        return Err(anyhow::Error::msg("The model does not have the expected input and output layers."));
+    }
+
+    if layers.windows(2).any(|pair| pair[0].outputs != pair[1].inputs) {
+        return Err(anyhow::Error::msg("The model layer sizes do not line up."));
+    }
+
+    for layer in &mut layers {
+        if layer.bias.is_empty() {
+            layer.bias = vec![0.0; layer.outputs];
+        }
+    }
+
+    Ok(layers)
+}
+
+// Encoding.
+
+/// Encodes the layers as an ONNX `ModelProto`.
+///
+/// The first layer is linear, the hidden layers are followed by a `Relu`, and the last layer is followed by the scaled sigmoid.
+fn encode_model(layers: &[LinearLayer], sigmoid_strength: f32) -> Vec<u8> {
+    let mut model = ProtoWriter::default();
+
+    model.int(1, ONNX_IR_VERSION);
+    model.string(2, "kord");
+    model.string(3, env!("CARGO_PKG_VERSION"));
+    model.message(7, |graph| encode_graph(graph, layers, sigmoid_strength));
+    model.message(8, |opset| {
+        opset.string(1, "");
+        opset.int(2, ONNX_OPSET_VERSION);
+    });
+
+    model.buffer
+}
+
+/// Encodes the `GraphProto` of the model.
+fn encode_graph(graph: &mut ProtoWriter, layers: &[LinearLayer], sigmoid_strength: f32) {
+    let mut x = ONNX_INPUT_NAME.to_owned();
+
+    for (k, layer) in layers.iter().enumerate() {
+        let weight = format!("linear_{k}.weight");
+        let bias = format!("linear_{k}.bias");
+        let gemm = format!("linear_{k}");
+
+        encode_node(graph, "Gemm", &gemm, &[&x, &weight, &bias], &gemm);
+        x = gemm;
+
+        if k > 0 && k < layers.len() - 1 {
+            let relu = format!("relu_{k}");
+
+            encode_node(graph, "Relu", &relu, &[&x], &relu);
+            x = relu;
+        }
+    }
+
+    encode_node(graph, "Mul", "sigmoid_scale", &[&x, "sigmoid.strength"], "sigmoid_scaled");
+    encode_node(graph, "Sigmoid", "sigmoid", &["sigmoid_scaled"], ONNX_OUTPUT_NAME);
+
+    graph.string(2, "kord");
+
+    for (k, layer) in layers.iter().enumerate() {
+        encode_tensor(graph, &format!("linear_{k}.weight"), &[layer.inputs, layer.outputs], &layer.weight);
+        encode_tensor(graph, &format!("linear_{k}.bias"), &[layer.outputs], &layer.bias);
+    }
+
+    encode_tensor(graph, "sigmoid.strength", &[], &[sigmoid_strength]);
+
+    encode_value_info(graph, 11, ONNX_INPUT_NAME, INPUT_SPACE_SIZE);
+    encode_value_info(graph, 12, ONNX_OUTPUT_NAME, NUM_CLASSES);
+}
+
+/// Encodes a `NodeProto` (in the default domain, without attributes).
+fn encode_node(graph: &mut ProtoWriter, op_type: &str, name: &str, inputs: &[&str], output: &str) {
+    graph.message(1, |node| {
+        for input in inputs {
+            node.string(1, input);
+        }
+
+        node.string(2, output);
+        node.string(3, name);
+        node.string(4, op_type);
+    });
+}
+
+/// Encodes a float `TensorProto` initializer.
+fn encode_tensor(graph: &mut ProtoWriter, name: &str, dims: &[usize], values: &[f32]) {
+    graph.message(5, |tensor| {
+        for dim in dims {
+            tensor.int(1, *dim as i64);
+        }
+
+        tensor.int(2, ONNX_FLOAT);
+        tensor.string(8, name);
+        tensor.bytes(9, &values.iter().flat_map(|v| v.to_le_bytes()).collect::<Vec<_>>());
+    });
+}
+
+/// Encodes a `[batch, size]` float `ValueInfoProto` as the given (input or output) field of the graph.
+fn encode_value_info(graph: &mut ProtoWriter, field: u32, name: &str, size: usize) {
+    graph.message(field, |value_info| {
+        value_info.string(1, name);
+        value_info.message(2, |type_proto| {
+            type_proto.message(1, |tensor_type| {
+                tensor_type.int(1, ONNX_FLOAT);
+                tensor_type.message(2, |shape| {
+                    shape.message(1, |dim| dim.string(2, "batch"));
+                    shape.message(1, |dim| dim.int(1, size as i64));
+                });
+            });
+        });
+    });
+}
+
+/// A minimal protocol buffer writer, which is all that is needed to write ONNX models.
+#[derive(Debug, Default)]
+struct ProtoWriter {
+    buffer: Vec<u8>,
+}
+
+impl ProtoWriter {
+    /// Writes a base 128 varint.
+    fn varint(&mut self, mut value: u64) {
+        while value >= 0x80 {
+            self.buffer.push((value as u8 & 0x7f) | 0x80);
+            value >>= 7;
+        }
+
+        self.buffer.push(value as u8);
+    }
+
+    /// Writes a field key.
+    fn key(&mut self, field: u32, wire_type: u8) {
+        self.varint((field as u64) << 3 | wire_type as u64);
+    }
+
+    /// Writes an integer (`int32`, `int64`, or enum) field.
+    fn int(&mut self, field: u32, value: i64) {
+        self.key(field, 0);
+        self.varint(value as u64);
+    }
+
+    /// Writes a `bytes` field.
+    fn bytes(&mut self, field: u32, value: &[u8]) {
+        self.key(field, 2);
+        self.varint(value.len() as u64);
+        self.buffer.extend_from_slice(value);
+    }
+
+    /// Writes a `string` field.
+    fn string(&mut self, field: u32, value: &str) {
+        self.bytes(field, value.as_bytes());
+    }
+
+    /// Writes an embedded message field.
+    fn message(&mut self, field: u32, write: impl FnOnce(&mut ProtoWriter)) {
+        let mut inner = ProtoWriter::default();
+        write(&mut inner);
+
+        self.bytes(field, &inner.buffer);
+    }
+}
+
+// Tests.
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn::tensor::{Data, Shape};
+    use burn_ndarray::NdArrayBackend;
+    use pretty_assertions::assert_eq;
+
+    /// A protocol buffer field value (only the wire types written by [`ProtoWriter`]).
+    #[derive(Clone, Copy, Debug)]
+    enum ProtoValue<'a> {
+        Int(u64),
+        Bytes(&'a [u8]),
+    }
+
+    impl<'a> ProtoValue<'a> {
+        fn int(self) -> u64 {
+            match self {
+                ProtoValue::Int(value) => value,
+                ProtoValue::Bytes(_) => panic!("Expected an integer field."),
+            }
+        }
+
+        fn bytes(self) -> &'a [u8] {
+            match self {
+                ProtoValue::Bytes(value) => value,
+                ProtoValue::Int(_) => panic!("Expected a length-delimited field."),
+            }
+        }
+
+        fn string(self) -> String {
+            String::from_utf8(self.bytes().to_vec()).unwrap()
+        }
+    }
+
+    /// Reads a base 128 varint, advancing the buffer.
+    fn read_varint(buffer: &mut &[u8]) -> u64 {
+        let mut value = 0;
+
+        for shift in (0..64).step_by(7) {
+            let byte = buffer[0];
+            *buffer = &buffer[1..];
+            value |= ((byte & 0x7f) as u64) << shift;
+
+            if byte < 0x80 {
+                break;
+            }
+        }
+
+        value
+    }
+
+    /// Decodes the fields of a protocol buffer message, in order.
+    fn read_fields(mut buffer: &[u8]) -> Vec<(u32, ProtoValue<'_>)> {
+        let mut fields = Vec::new();
+
+        while !buffer.is_empty() {
+            let key = read_varint(&mut buffer);
+
+            let value = match key & 0x7 {
+                0 => ProtoValue::Int(read_varint(&mut buffer)),
+                2 => {
+                    let length = read_varint(&mut buffer) as usize;
+                    let (value, rest) = buffer.split_at(length);
+                    buffer = rest;
+
+                    ProtoValue::Bytes(value)
+                }
+                wire_type => panic!("Unexpected wire type {wire_type}."),
+            };
+
+            fields.push(((key >> 3) as u32, value));
+        }
+
+        fields
+    }
+
+    /// Returns the values of the given field of a decoded message.
+    fn field<'a>(fields: &[(u32, ProtoValue<'a>)], field: u32) -> Vec<ProtoValue<'a>> {
+        fields.iter().filter(|(f, _)| *f == field).map(|(_, v)| *v).collect()
+    }
+
+    /// Runs the ONNX model bytes on a single sample by decoding the graph, and evaluating its nodes in order.
+    ///
+    /// Only the operators that the exporter writes (`Gemm`, `Relu`, `Mul` by a scalar, and `Sigmoid`) are supported.
+    fn run_onnx(bytes: &[u8], input: &[f32]) -> Vec<f32> {
+        let model = read_fields(bytes);
+        let graph = read_fields(field(&model, 7)[0].bytes());
+
+        // Tensors are stored as their dims and values.
+        let mut values = std::collections::HashMap::<String, (Vec<usize>, Vec<f32>)>::new();
+
+        for initializer in field(&graph, 5) {
+            let tensor = read_fields(initializer.bytes());
+            let dims = field(&tensor, 1).into_iter().map(|d| d.int() as usize).collect();
+            let data = field(&tensor, 9)[0].bytes().chunks(4).map(|c| f32::from_le_bytes(c.try_into().unwrap())).collect();
+
+            values.insert(field(&tensor, 8)[0].string(), (dims, data));
+        }
+
+        let input_name = read_fields(field(&graph, 11)[0].bytes());
+        let output_name = read_fields(field(&graph, 12)[0].bytes());
+        values.insert(field(&input_name, 1)[0].string(), (vec![1, input.len()], input.to_vec()));
+
+        for node in field(&graph, 1) {
+            let node = read_fields(node.bytes());
+            let inputs = field(&node, 1).into_iter().map(|i| values[&i.string()].clone()).collect::<Vec<_>>();
+
+            let output = match field(&node, 4)[0].string().as_str() {
+                "Gemm" => {
+                    let ((a_dims, a), (b_dims, b), (_, c)) = (&inputs[0], &inputs[1], &inputs[2]);
+                    let (m, k, n) = (a_dims[0], a_dims[1], b_dims[1]);
+                    assert_eq!(k, b_dims[0]);
+
+                    let data = (0..m * n).map(|ij| c[ij % n] + (0..k).map(|l| a[ij / n * k + l] * b[l * n + ij % n]).sum::<f32>()).collect();
+
+                    (vec![m, n], data)
+                }
+                "Relu" => (inputs[0].0.clone(), inputs[0].1.iter().map(|v| v.max(0.0)).collect()),
+                "Mul" => {
+                    assert!(inputs[1].0.is_empty(), "Only scalar multiplication is supported.");
+                    (inputs[0].0.clone(), inputs[0].1.iter().map(|v| v * inputs[1].1[0]).collect())
+                }
+                "Sigmoid" => (inputs[0].0.clone(), inputs[0].1.iter().map(|v| 1.0 / (1.0 + (-v).exp())).collect()),
+                op_type => panic!("Unexpected operator `{op_type}`."),
+            };
+
+            values.insert(field(&node, 2)[0].string(), output);
+        }
+
+        values.remove(&field(&output_name, 1)[0].string()).unwrap().1
+    }
+
+    #[test]
+    fn test_onnx_export() {
+        let model = KordModel::<NdArrayBackend<f32>>::new(2, 32, 0.0, 0.5);
+
+        let layers = get_linear_layers(&model).unwrap();
+        assert_eq!(
+            layers.iter().map(|l| (l.inputs, l.outputs)).collect::<Vec<_>>(),
+            vec![(INPUT_SPACE_SIZE, 32), (32, 32), (32, 32), (32, NUM_CLASSES)]
+        );
+
+        // The exported model (decoded from its bytes) matches the burn inference (within a small tolerance) on a few samples.
+        let bytes = model_to_onnx(&model).unwrap();
+
+        for seed in 0..3 {
+            let input = (0..INPUT_SPACE_SIZE).map(|k| ((k * 7 + seed * 13) % 17) as f32 / 8.0 - 1.0).collect::<Vec<_>>();
+
+            let tensor = Tensor::<NdArrayBackend<f32>, 2>::from_data(Data::new(input.clone(), Shape::new([1, INPUT_SPACE_SIZE])));
+            let expected: Vec<f32> = model.forward(tensor).to_data().convert().value;
+            let actual = run_onnx(&bytes, &input);
+
+            assert_eq!(actual.len(), NUM_CLASSES);
+            assert!(expected.iter().zip(&actual).all(|(e, a)| (e - a).abs() < 1e-4));
+        }
+
+        // The model bytes contain every weight (as raw little-endian floats), plus some framing.
+        let num_weights = layers.iter().map(|l| l.weight.len() + l.bias.len()).sum::<usize>();
+
+        assert!(bytes.len() > 4 * num_weights);
+        assert!(bytes.windows(4).any(|w| w == b"Gemm"));
+        assert!(bytes.windows(7).any(|w| w == b"Sigmoid"));
+    }
+
+    #[test]
+    fn test_proto_writer() {
+        let mut writer = ProtoWriter::default();
+
+        writer.int(1, 150);
+        writer.string(2, "ab");
+        writer.message(3, |inner| inner.int(1, 1));
+
+        assert_eq!(writer.buffer, vec![0x08, 0x96, 0x01, 0x12, 0x02, b'a', b'b', 0x1a, 0x02, 0x08, 0x01]);
+
+        let fields = read_fields(&writer.buffer);
+
+        assert_eq!(field(&fields, 1)[0].int(), 150);
+        assert_eq!(field(&fields, 2)[0].string(), "ab");
+        assert_eq!(field(&read_fields(field(&fields, 3)[0].bytes()), 1)[0].int(), 1);
+    }
+}
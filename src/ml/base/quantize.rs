@@ -0,0 +1,107 @@
+//! Int8 quantization of the model state.
+//!
+//! Quantizing stores each tensor as `i8`s with a single (symmetric) `f32` scale, which makes the
+//! state roughly a quarter of its full-precision size, at the cost of a small amount of accuracy.
+
+use std::collections::HashMap;
+
+use burn::{
+    module::{State, StateNamed},
+    tensor::{DataSerialize, Element, ElementConversion},
+};
+use serde::{Deserialize, Serialize};
+
+// Struct.
+
+/// A tensor whose values have been quantized to `i8`s.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct QuantizedTensor {
+    /// The scale of the tensor (i.e., the full-precision value of a quantized `1`).
+    pub scale: f32,
+    /// The quantized values.
+    pub values: Vec<i8>,
+    /// The shape of the tensor.
+    pub shape: Vec<usize>,
+}
+
+// Enum.
+
+/// A quantized mirror of a module [`State`].
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum QuantizedState {
+    /// The named child states of a module.
+    Named(HashMap<String, QuantizedState>),
+    /// A quantized tensor.
+    Data(QuantizedTensor),
+    /// A parameter id.
+    ParamId(String),
+}
+
+// Helpers.
+
+/// Quantizes the values into `i8`s, returning the scale and the quantized values.
+///
+/// The scale maps the largest magnitude to `127`, so the error of each value is at most half of the scale.
+pub fn quantize_values(values: &[f32]) -> (f32, Vec<i8>) {
+    let max = values.iter().fold(0f32, |max, v| max.max(v.abs()));
+    let scale = if max > 0.0 { max / 127.0 } else { 1.0 };
+
+    let quantized = values.iter().map(|v| (v / scale).round().clamp(-127.0, 127.0) as i8).collect();
+
+    (scale, quantized)
+}
+
+/// Dequantizes the values with the given scale.
+pub fn dequantize_values(scale: f32, values: &[i8]) -> Vec<f32> {
+    values.iter().map(|v| *v as f32 * scale).collect()
+}
+
+/// Quantizes a module [`State`].
+pub fn quantize_state<E: Element>(state: &State<E>) -> QuantizedState {
+    match state {
+        State::StateNamed(named) => QuantizedState::Named(named.values.iter().map(|(name, state)| (name.clone(), quantize_state(state))).collect()),
+        State::Data(data) => {
+            let values = data.value.iter().map(|v| v.to_elem::<f32>()).collect::<Vec<_>>();
+            let (scale, values) = quantize_values(&values);
+
+            QuantizedState::Data(QuantizedTensor { scale, values, shape: data.shape.clone() })
+        }
+        State::ParamId(id) => QuantizedState::ParamId(id.clone()),
+    }
+}
+
+/// Dequantizes a [`QuantizedState`] back into a module [`State`], which can be loaded into a model.
+pub fn dequantize_state<E: Element>(state: &QuantizedState) -> State<E> {
+    match state {
+        QuantizedState::Named(named) => State::StateNamed(StateNamed {
+            values: named.iter().map(|(name, state)| (name.clone(), dequantize_state(state))).collect(),
+        }),
+        QuantizedState::Data(tensor) => State::Data(DataSerialize {
+            value: dequantize_values(tensor.scale, &tensor.values).into_iter().map(E::from_elem).collect(),
+            shape: tensor.shape.clone(),
+        }),
+        QuantizedState::ParamId(id) => State::ParamId(id.clone()),
+    }
+}
+
+// Tests.
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_quantize_values() {
+        let values = [0.5, -1.0, 0.25, 0.0, 0.01];
+        let (scale, quantized) = quantize_values(&values);
+
+        assert_eq!(quantized, vec![64, -127, 32, 0, 1]);
+
+        for (original, dequantized) in values.iter().zip(dequantize_values(scale, &quantized)) {
+            assert!((original - dequantized).abs() <= scale / 2.0);
+        }
+
+        assert_eq!(quantize_values(&[0.0, 0.0]), (1.0, vec![0, 0]));
+    }
+}
@@ -4,26 +4,145 @@ use anyhow::Context;
 use burn::{
     config::Config,
     module::{Module, State},
-    tensor::backend::Backend,
+    tensor::{backend::Backend, Element},
 };
 use burn_ndarray::{NdArrayBackend, NdArrayDevice};
 use serde::{de::DeserializeOwned, Serialize};
 
 use crate::{
-    analyze::base::{get_frequency_space, get_smoothed_frequency_space},
+    analyze::base::{get_smoothed_frequency_space_with_config, AnalysisConfig},
     core::{
         base::Res,
         note::{HasNoteId, Note},
     },
-    ml::base::{data::kord_item_to_sample_tensor, helpers::binary_to_u128, model::KordModel, KordItem, TrainConfig, FREQUENCY_SPACE_SIZE},
+    ml::base::{data::kord_item_to_sample_tensor, model::KordModel, KordItem, TrainConfig, DEFAULT_INFERENCE_THRESHOLD, FREQUENCY_SPACE_SIZE, NUM_CLASSES},
 };
 
+#[cfg(any(test, feature = "ml_quantized"))]
+use crate::ml::base::quantize::dequantize_state;
+#[cfg(feature = "ml_quantized")]
+use crate::ml::base::quantize::QuantizedState;
+
 /// Run the inference on a sample to produce a [`Vec`] of [`Note`]s.
+///
+/// With the `ml_quantized` feature, this uses the int8 quantized state (see [`quantize`](crate::ml::base::quantize)).
 pub fn run_inference<B: Backend>(device: &B::Device, kord_item: &KordItem) -> Res<Vec<Note>>
 where
     B::FloatElem: Serialize + DeserializeOwned,
 {
-    // Load the config and state.
+    run_inference_with_threshold::<B>(device, kord_item, DEFAULT_INFERENCE_THRESHOLD)
+}
+
+/// Run the inference on a sample to produce a [`Vec`] of [`Note`]s, where a note is present if the model's probability
+/// for it is at least `threshold` (in `0.0..=1.0`).
+///
+/// A higher threshold reduces false positives in noisy audio, and a lower one catches quieter notes.
+pub fn run_inference_with_threshold<B: Backend>(device: &B::Device, kord_item: &KordItem, threshold: f32) -> Res<Vec<Note>>
+where
+    B::FloatElem: Serialize + DeserializeOwned,
+{
+    if !(0.0..=1.0).contains(&threshold) {
+        return Err(anyhow::Error::msg("The inference threshold must be between 0 and 1."));
+    }
+
+    let mut inferred_notes = run_inference_probabilities::<B>(device, kord_item)?
+        .into_iter()
+        .filter(|(_, p)| *p >= threshold)
+        .map(|(note, _)| note)
+        .collect::<Vec<_>>();
+    inferred_notes.sort();
+
+    Ok(inferred_notes)
+}
+
+/// Run the inference on a sample to produce the model's probability for every one of the [`NUM_CLASSES`] notes.
+///
+/// The notes are sorted by descending probability (ties are broken by pitch, lowest first).
+pub fn run_inference_probabilities<B: Backend>(device: &B::Device, kord_item: &KordItem) -> Res<Vec<(Note, f32)>>
+where
+    B::FloatElem: Serialize + DeserializeOwned,
+{
+    let state = load_state::<B::FloatElem>()?;
+
+    // Run the inference.
+    let probabilities = infer_probabilities::<B>(device, kord_item, &state)?;
+
+    // The first class is the highest note (see `binary_to_u128`).
+    let mut result = probabilities
+        .into_iter()
+        .take(NUM_CLASSES)
+        .enumerate()
+        .map(|(k, p)| Ok((Note::from_id(1 << (NUM_CLASSES - 1 - k))?, p)))
+        .collect::<Res<Vec<_>>>()?;
+    result.sort_by(|(a_note, a), (b_note, b)| b.total_cmp(a).then(a_note.cmp(b_note)));
+
+    Ok(result)
+}
+
+/// Infer notes from the audio data, which was recorded over `length_in_seconds` (see [`AnalysisConfig::from_recording`]).
+pub fn infer(audio_data: &[f32], length_in_seconds: u8) -> Res<Vec<Note>> {
+    infer_with_threshold(audio_data, length_in_seconds, DEFAULT_INFERENCE_THRESHOLD)
+}
+
+/// Infer notes from the audio data, where a note is present if the model's probability for it is at least `threshold`
+/// (see [`run_inference_with_threshold`]).
+pub fn infer_with_threshold(audio_data: &[f32], length_in_seconds: u8, threshold: f32) -> Res<Vec<Note>> {
+    let kord_item = kord_item_from_audio_data(audio_data, &AnalysisConfig::from_recording(audio_data.len(), length_in_seconds))?;
+    let device = NdArrayDevice::Cpu;
+
+    // Run the inference.
+    let notes = run_inference_with_threshold::<NdArrayBackend<f32>>(&device, &kord_item, threshold)?;
+
+    Ok(notes)
+}
+
+/// Infer the probability of every note from the audio data, sorted by descending probability (see [`run_inference_probabilities`]).
+pub fn infer_probabilities_from_audio(audio_data: &[f32], length_in_seconds: u8) -> Res<Vec<(Note, f32)>> {
+    let kord_item = kord_item_from_audio_data(audio_data, &AnalysisConfig::from_recording(audio_data.len(), length_in_seconds))?;
+    let device = NdArrayDevice::Cpu;
+
+    // Run the inference.
+    run_inference_probabilities::<NdArrayBackend<f32>>(&device, &kord_item)
+}
+
+/// Infer notes from the audio data, which is described by the provided [`AnalysisConfig`].
+///
+/// The model always infers from [`FREQUENCY_SPACE_SIZE`] bins, so the config's `frequency_space_size` is ignored.
+pub fn infer_with_config(audio_data: &[f32], config: &AnalysisConfig) -> Res<Vec<Note>> {
+    let kord_item = kord_item_from_audio_data(audio_data, config)?;
+    let device = NdArrayDevice::Cpu;
+
+    // Run the inference.
+    let notes = run_inference::<NdArrayBackend<f32>>(&device, &kord_item)?;
+
+    Ok(notes)
+}
+
+// Helpers.
+
+/// Builds the [`KordItem`] (with only the smoothed frequency space) that the model infers from.
+fn kord_item_from_audio_data(audio_data: &[f32], config: &AnalysisConfig) -> Res<KordItem> {
+    let config = AnalysisConfig {
+        frequency_space_size: FREQUENCY_SPACE_SIZE,
+        ..*config
+    };
+
+    let smoothed_frequency_space: [_; FREQUENCY_SPACE_SIZE] = get_smoothed_frequency_space_with_config(audio_data, &config)?
+        .into_iter()
+        .map(|(_, v)| v)
+        .collect::<Vec<_>>()
+        .try_into()
+        .map_err(|_| anyhow::Error::msg("The audio data is too short for the model's frequency space."))?;
+
+    Ok(KordItem {
+        frequency_space: smoothed_frequency_space,
+        ..Default::default()
+    })
+}
+
+/// Runs the model, loaded with the given state, on a sample to produce the probability of each note.
+fn infer_probabilities<B: Backend>(device: &B::Device, kord_item: &KordItem, state: &State<B::FloatElem>) -> Res<Vec<f32>> {
+    // Load the config.
 
     let config = match TrainConfig::load_binary(CONFIG) {
         Ok(config) => config,
@@ -32,12 +151,9 @@ where
         }
     };
 
-    //let state = State::<B::Elem>::load_binary(STATE)?;
-    let (state, _len): (State<B::FloatElem>, usize) = bincode::serde::decode_from_slice(STATE_BINCODE, bincode::config::standard()).context("Failed to decode state.")?;
-
     // Define the model.
     let mut model = KordModel::<B>::new(config.mlp_layers, config.mlp_size, config.mlp_dropout, config.sigmoid_strength);
-    model = match model.load(&state) {
+    model = match model.load(state) {
         Ok(model) => model,
         Err(_) => {
             return Err(anyhow::Error::msg("Could not load the model state from within the binary."));
@@ -47,51 +163,42 @@ where
     // Prepare the sample.
     let sample = kord_item_to_sample_tensor(kord_item).to_device(device).detach();
 
-    // Run the inference.
-    let inferred = model.forward(sample).to_data().convert().value.into_iter().map(f32::round).collect::<Vec<_>>();
-    let inferred_array: [_; 128] = inferred.try_into().unwrap();
-    let mut inferred_notes = Note::from_id_mask(binary_to_u128(&inferred_array)).unwrap();
-    inferred_notes.sort();
-
-    Ok(inferred_notes)
+    Ok(model.forward(sample).to_data().convert().value)
 }
 
-/// Infer notes from the audio data.
-pub fn infer(audio_data: &[f32], length_in_seconds: u8) -> Res<Vec<Note>> {
-    let frequency_space = get_frequency_space(audio_data, length_in_seconds);
-    let smoothed_frequency_space: [_; FREQUENCY_SPACE_SIZE] = get_smoothed_frequency_space(&frequency_space, length_in_seconds)
-        .into_iter()
-        .take(FREQUENCY_SPACE_SIZE)
-        .map(|(_, v)| v)
-        .collect::<Vec<_>>()
-        .try_into()
-        .unwrap();
-
-    let kord_item = KordItem {
-        frequency_space: smoothed_frequency_space,
-        ..Default::default()
-    };
+/// Loads the full-precision state from within the binary.
+#[cfg(not(feature = "ml_quantized"))]
+fn load_state<E: Element + DeserializeOwned>() -> Res<State<E>> {
+    //let state = State::<B::Elem>::load_binary(STATE)?;
+    let (state, _len): (State<E>, usize) = bincode::serde::decode_from_slice(STATE_BINCODE, bincode::config::standard()).context("Failed to decode state.")?;
 
-    let device = NdArrayDevice::Cpu;
+    Ok(state)
+}
 
-    // Run the inference.
-    let notes = run_inference::<NdArrayBackend<f32>>(&device, &kord_item)?;
+/// Loads the int8 quantized state from within the binary.
+#[cfg(feature = "ml_quantized")]
+fn load_state<E: Element>() -> Res<State<E>> {
+    let (state, _len): (QuantizedState, usize) = bincode::serde::decode_from_slice(STATE_Q8_BINCODE, bincode::config::standard()).context("Failed to decode quantized state.")?;
 
-    Ok(notes)
+    Ok(dequantize_state(&state))
 }
 
 // Statics.
 #[cfg(host_family_unix)]
 static CONFIG: &[u8] = include_bytes!("../../../model/model_config.json");
-#[cfg(host_family_unix)]
+#[cfg(all(host_family_unix, not(feature = "ml_quantized")))]
 //static STATE: &[u8] = include_bytes!("../../../model/state.json.gz");
 static STATE_BINCODE: &[u8] = include_bytes!("../../../model/state.bincode");
+#[cfg(all(host_family_unix, feature = "ml_quantized"))]
+static STATE_Q8_BINCODE: &[u8] = include_bytes!("../../../model/state_q8.bincode");
 
 #[cfg(host_family_windows)]
 static CONFIG: &[u8] = include_bytes!("..\\..\\..\\model\\model_config.json");
-#[cfg(host_family_windows)]
+#[cfg(all(host_family_windows, not(feature = "ml_quantized")))]
 //static STATE: &[u8] = include_bytes!("..\\..\\..\\model\\state.json.gz");
 static STATE_BINCODE: &[u8] = include_bytes!("..\\..\\..\\model\\state.bincode");
+#[cfg(all(host_family_windows, feature = "ml_quantized"))]
+static STATE_Q8_BINCODE: &[u8] = include_bytes!("..\\..\\..\\model\\state_q8.bincode");
 
 // Tests.
 
@@ -101,10 +208,12 @@ mod tests {
     use std::{fs::File, io::Read};
 
     use super::*;
-    use crate::core::{base::Parsable, chord::Chord};
+    use crate::{
+        core::{base::Parsable, chord::Chord},
+        ml::base::quantize::{quantize_state, QuantizedState},
+    };
 
-    #[test]
-    fn test_inference() {
+    fn load_audio_data() -> Vec<f32> {
         let mut file = File::open("tests/vec.bin").unwrap();
         let file_size = file.metadata().unwrap().len() as usize;
         let float_size = std::mem::size_of::<f32>();
@@ -115,7 +224,12 @@ mod tests {
         file.read_exact(&mut buffer).unwrap();
 
         // Convert the buffer to a vector of f32
-        let audio_data: Vec<f32> = unsafe { std::slice::from_raw_parts(buffer.as_ptr() as *const f32, element_count).to_vec() };
+        unsafe { std::slice::from_raw_parts(buffer.as_ptr() as *const f32, element_count).to_vec() }
+    }
+
+    #[test]
+    fn test_inference() {
+        let audio_data = load_audio_data();
 
         let notes = infer(&audio_data, 5).unwrap();
 
@@ -123,4 +237,76 @@ mod tests {
 
         assert_eq!(chord[0], Chord::parse("C7b9").unwrap());
     }
+
+    #[test]
+    fn test_inference_threshold() {
+        let audio_data = load_audio_data();
+
+        let low = infer_with_threshold(&audio_data, 5, 0.2).unwrap();
+        let high = infer_with_threshold(&audio_data, 5, 0.8).unwrap();
+
+        // A higher threshold only ever removes notes.
+        assert!(high.iter().all(|n| low.contains(n)));
+        assert!(high.len() <= infer(&audio_data, 5).unwrap().len());
+        assert_eq!(infer_with_threshold(&audio_data, 5, DEFAULT_INFERENCE_THRESHOLD).unwrap(), infer(&audio_data, 5).unwrap());
+        assert!(infer_with_threshold(&audio_data, 5, 1.5).is_err());
+    }
+
+    #[test]
+    fn test_inference_probabilities() {
+        let audio_data = load_audio_data();
+
+        let probabilities = infer_probabilities_from_audio(&audio_data, 5).unwrap();
+
+        assert_eq!(probabilities.len(), NUM_CLASSES);
+        assert!(probabilities.windows(2).all(|w| w[0].1 >= w[1].1));
+        assert!(probabilities.iter().all(|(_, p)| (0.0..=1.0).contains(p)));
+
+        // The thresholded notes are exactly the most probable ones.
+        let mut likely = probabilities.iter().filter(|(_, p)| *p >= DEFAULT_INFERENCE_THRESHOLD).map(|(n, _)| *n).collect::<Vec<_>>();
+        likely.sort();
+
+        assert_eq!(likely, infer(&audio_data, 5).unwrap());
+    }
+
+    #[test]
+    fn test_committed_quantized_state() {
+        // The committed quantized state must be the quantization of the committed full-precision state (i.e., retraining
+        // the model without regenerating `state_q8.bincode` is caught here).
+        let full_bincode = std::fs::read("model/state.bincode").unwrap();
+        let (full, _len): (State<f32>, usize) = bincode::serde::decode_from_slice(&full_bincode, bincode::config::standard()).unwrap();
+
+        let q8_bincode = std::fs::read("model/state_q8.bincode").unwrap();
+        let (committed, _len): (QuantizedState, usize) = bincode::serde::decode_from_slice(&q8_bincode, bincode::config::standard()).unwrap();
+
+        assert_eq!(committed, quantize_state(&full));
+    }
+
+    #[test]
+    fn test_quantized_inference() {
+        // The largest allowed difference between any quantized and full-precision note probability.
+        let tolerance = std::env::var("KORD_QUANTIZATION_TOLERANCE").ok().and_then(|t| t.parse().ok()).unwrap_or(0.05f32);
+
+        let audio_data = load_audio_data();
+        let kord_item = kord_item_from_audio_data(&audio_data, &AnalysisConfig::from_recording(audio_data.len(), 5)).unwrap();
+
+        let full_bincode = std::fs::read("model/state.bincode").unwrap();
+        let (full, _len): (State<f32>, usize) = bincode::serde::decode_from_slice(&full_bincode, bincode::config::standard()).unwrap();
+        let quantized = quantize_state(&full);
+        let quantized_bincode = bincode::serde::encode_to_vec(&quantized, bincode::config::standard()).unwrap();
+
+        // The quantized state is roughly a quarter of the size.
+        assert!(quantized_bincode.len() * 3 < full_bincode.len());
+
+        let device = NdArrayDevice::Cpu;
+        let full_probabilities = infer_probabilities::<NdArrayBackend<f32>>(&device, &kord_item, &full).unwrap();
+        let quantized_probabilities = infer_probabilities::<NdArrayBackend<f32>>(&device, &kord_item, &dequantize_state(&quantized)).unwrap();
+
+        let max_error = full_probabilities.iter().zip(&quantized_probabilities).map(|(f, q)| (f - q).abs()).fold(0f32, f32::max);
+        assert!(max_error <= tolerance, "The quantized model differs by {max_error} (tolerance is {tolerance}).");
+
+        // The predicted notes are the same.
+        let round = |probabilities: &[f32]| probabilities.iter().map(|p| p.round()).collect::<Vec<_>>();
+        assert_eq!(round(&quantized_probabilities), round(&full_probabilities));
+    }
 }
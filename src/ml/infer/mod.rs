@@ -3,4 +3,9 @@
 pub mod execute;
 
 pub use execute::infer;
+pub use execute::infer_probabilities_from_audio;
+pub use execute::infer_with_threshold;
+pub use execute::infer_with_config;
 pub use execute::run_inference;
+pub use execute::run_inference_probabilities;
+pub use execute::run_inference_with_threshold;
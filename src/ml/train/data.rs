@@ -6,15 +6,19 @@ use burn::{
     data::{dataloader::batcher::Batcher, dataset::Dataset},
     tensor::{backend::Backend, Tensor},
 };
+use rand::{rngs::StdRng, seq::SliceRandom, SeedableRng};
 use rayon::prelude::*;
 
-use crate::ml::base::{
-    data::{kord_item_to_sample_tensor, kord_item_to_target_tensor},
-    helpers::load_kord_item,
-    KordItem,
+use crate::{
+    core::base::Res,
+    ml::base::{
+        data::{kord_item_to_sample_tensor, kord_item_to_target_tensor},
+        helpers::load_kord_item,
+        KordItem,
+    },
 };
 
-use super::helpers::get_simulated_kord_items;
+use super::helpers::{get_simulated_kord_items, SimulationConfig};
 
 // Dataset.
 
@@ -24,18 +28,10 @@ pub struct KordDataset {
 }
 
 impl KordDataset {
-    /// Load the kord dataset from the given folder.
-    pub fn from_folder_and_simulation(name: impl AsRef<Path>, count: usize, peak_radius: f32, harmonic_decay: f32, frequency_wobble: f32) -> (Self, Self) {
-        // First, get all of the *.bin files in the folder.
-        let test_files = std::fs::read_dir(name)
-            .unwrap()
-            .map(|entry| entry.unwrap().path())
-            .filter(|path| path.is_file())
-            .filter(|path| path.extension().unwrap() == "bin")
-            .collect::<Vec<_>>();
-
-        let test_items: Vec<_> = test_files.par_iter().map(load_kord_item).collect();
-        let train_items = get_simulated_kord_items(count, peak_radius, harmonic_decay, frequency_wobble);
+    /// Load the kord dataset from the given folder, along with `count` rounds of simulated samples.
+    pub fn from_folder_and_simulation(name: impl AsRef<Path>, count: usize, simulation: &SimulationConfig) -> (Self, Self) {
+        let test_items = load_kord_items(name);
+        let train_items = get_simulated_kord_items(count, simulation);
 
         // Return the train and test datasets.
         let train = Self { items: train_items };
@@ -43,6 +39,69 @@ impl KordDataset {
 
         (train, test)
     }
+
+    /// Mixes the (real) items into this (synthetic) dataset, so that `real_ratio` (in `0.0..1.0`) of the items are real.
+    ///
+    /// Both sets of items are shuffled (with the given seed), and then interleaved evenly, rather than appended, so that
+    /// every block of `block_size` items (i.e., every batch) honors the ratio, up to rounding.  The items within each
+    /// block, and the order of the (full) blocks, are then shuffled, so the real items do not sit at fixed positions.
+    /// All of the synthetic items are kept, and the real items are repeated if there are not enough of them.
+    pub fn mix_with(self, real_items: Vec<KordItem>, real_ratio: f32, block_size: usize, seed: u64) -> Res<Self> {
+        if !(0.0..1.0).contains(&real_ratio) {
+            return Err(anyhow::Error::msg("The real ratio must be at least 0 and less than 1 (so that the synthetic items are kept)."));
+        }
+
+        let real_ratio = real_ratio as f64;
+
+        if real_items.is_empty() || real_ratio == 0.0 {
+            return Ok(self);
+        }
+
+        let mut rng = StdRng::seed_from_u64(seed);
+
+        let mut synthetic_items = self.items;
+        let mut real_items = real_items;
+        synthetic_items.shuffle(&mut rng);
+        real_items.shuffle(&mut rng);
+
+        let total = (synthetic_items.len() as f64 / (1.0 - real_ratio)).round() as usize;
+
+        let mut synthetic_items = synthetic_items.into_iter();
+        let mut real_items = real_items.iter().cycle();
+
+        let items = (0..total)
+            .filter_map(|k| {
+                let is_real = ((k + 1) as f64 * real_ratio).floor() > (k as f64 * real_ratio).floor();
+
+                if is_real {
+                    real_items.next().cloned()
+                } else {
+                    synthetic_items.next()
+                }
+            })
+            .collect::<Vec<_>>();
+
+        // Shuffle within the blocks, and then the order of the full blocks (the last, partial, block stays last).
+        let mut blocks = items.chunks(block_size.max(1)).map(<[_]>::to_vec).collect::<Vec<_>>();
+        blocks.iter_mut().for_each(|block| block.shuffle(&mut rng));
+
+        let num_full_blocks = items.len() / block_size.max(1);
+        blocks[..num_full_blocks].shuffle(&mut rng);
+
+        Ok(Self { items: blocks.concat() })
+    }
+}
+
+/// Loads all of the kord samples (i.e., the `*.bin` files) in the given folder.
+pub fn load_kord_items(name: impl AsRef<Path>) -> Vec<KordItem> {
+    let files = std::fs::read_dir(name)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.is_file())
+        .filter(|path| path.extension().unwrap() == "bin")
+        .collect::<Vec<_>>();
+
+    files.par_iter().map(load_kord_item).collect()
 }
 
 impl Dataset<KordItem> for KordDataset {
@@ -85,3 +144,46 @@ impl<B: Backend> Batcher<KordItem, KordBatch<B>> for KordBatcher<B> {
         KordBatch { samples: frequency_spaces, targets }
     }
 }
+
+// Tests.
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_mix_with() {
+        let item = |label: u128| KordItem { label, ..Default::default() };
+
+        let synthetic = KordDataset {
+            items: (0..70).map(|_| item(0)).collect(),
+        };
+        let real = (1..=3).map(item).collect::<Vec<_>>();
+
+        let mixed = synthetic.mix_with(real.clone(), 0.3, 10, 42).unwrap();
+
+        assert_eq!(mixed.items.len(), 100);
+
+        // Every batch of 10 has exactly 3 real items.
+        for batch in mixed.items.chunks(10) {
+            assert_eq!(batch.iter().filter(|i| i.label != 0).count(), 3);
+        }
+
+        // The labels of the real items are preserved (and the real items are repeated evenly).
+        for label in 1..=3 {
+            assert_eq!(mixed.items.iter().filter(|i| i.label == label).count(), 10);
+        }
+
+        // The real items are not always at the same positions within the batches.
+        assert!(mixed.items.chunks(10).map(|batch| batch.iter().position(|i| i.label != 0)).collect::<std::collections::HashSet<_>>().len() > 1);
+
+        // Without real items, the dataset is unchanged.
+        let synthetic = KordDataset { items: vec![item(0); 5] };
+        assert_eq!(synthetic.mix_with(Vec::new(), 0.5, 10, 42).unwrap().items.len(), 5);
+
+        // A dataset that is entirely real would drop the synthetic items.
+        let synthetic = KordDataset { items: vec![item(0); 5] };
+        assert!(synthetic.mix_with(real, 1.0, 10, 42).is_err());
+    }
+}
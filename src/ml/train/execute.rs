@@ -3,7 +3,7 @@ use std::sync::Arc;
 use burn::{
     config::Config,
     data::dataloader::DataLoaderBuilder,
-    module::Module,
+    module::{Module, State},
     optim::{decay::WeightDecayConfig, Adam, AdamConfig},
     tensor::backend::{ADBackend, Backend},
     train::{metric::LossMetric, LearnerBuilder},
@@ -17,13 +17,16 @@ use crate::{
         data::{kord_item_to_sample_tensor, kord_item_to_target_tensor},
         helpers::{binary_to_u128, get_deterministic_guess},
         model::KordModel,
-        NUM_CLASSES,
+        onnx::save_model_to_onnx,
+        quantize::quantize_state,
+        KordItem, LrSchedule, TrainLoss, NUM_CLASSES,
     },
 };
 
 use super::{
-    data::{KordBatcher, KordDataset},
-    helpers::KordAccuracyMetric,
+    data::{load_kord_items, KordBatcher, KordDataset},
+    helpers::{KordAccuracyMetric, KordPerClassAccuracyMetric, PerClassAccuracyReport, SimulationConfig},
+    history::{MetricsHistory, RecordedMetric},
 };
 
 use crate::ml::base::TrainConfig;
@@ -32,34 +35,48 @@ pub fn run_training<B: ADBackend>(device: B::Device, config: &TrainConfig, print
 where
     B::FloatElem: Serialize + DeserializeOwned,
 {
-    // Define the Adam config.
+    // Define the Adam config (for a given learning rate, since a schedule may change it every epoch).
 
-    let adam_config = AdamConfig::new(config.adam_learning_rate)
-        .with_weight_decay(Some(WeightDecayConfig::new(config.adam_weight_decay)))
-        .with_beta_1(config.adam_beta1)
-        .with_beta_2(config.adam_beta2)
-        .with_epsilon(config.adam_epsilon);
+    let adam_config = |learning_rate: f64| {
+        AdamConfig::new(learning_rate)
+            .with_weight_decay(Some(WeightDecayConfig::new(config.adam_weight_decay)))
+            .with_beta_1(config.adam_beta1)
+            .with_beta_2(config.adam_beta2)
+            .with_epsilon(config.adam_epsilon)
+    };
 
     // Define the datasets.
 
-    let (train_dataset, test_dataset) = KordDataset::from_folder_and_simulation(
-        &config.source,
-        config.simulation_size,
-        config.simulation_peak_radius,
-        config.simulation_harmonic_decay,
-        config.simulation_frequency_wobble,
-    );
+    let simulation = SimulationConfig {
+        peak_radius: config.simulation_peak_radius,
+        harmonic_decay: config.simulation_harmonic_decay,
+        frequency_wobble: config.simulation_frequency_wobble,
+        seed: Some(config.model_seed),
+        ..SimulationConfig::default()
+    };
+
+    let (mut train_dataset, test_dataset) = KordDataset::from_folder_and_simulation(&config.source, config.simulation_size, &simulation);
+
+    if let Some(real_mix) = &config.real_mix {
+        train_dataset = train_dataset.mix_with(load_kord_items(&real_mix.source), real_mix.ratio, config.model_batch_size, config.model_seed)?;
+    }
+
+    // Keep the validation items around, so that the validation accuracy can be checked for early stopping.
+    let valid_items = if config.early_stopping.is_some() { test_dataset.items.clone() } else { Vec::new() };
 
     // Define the data loaders.
 
     let batcher_train = Arc::new(KordBatcher::<B>::new(device.clone()));
     let batcher_valid = Arc::new(KordBatcher::<B::InnerBackend>::new(device.clone()));
 
-    let dataloader_train = DataLoaderBuilder::new(batcher_train)
-        .batch_size(config.model_batch_size)
-        .shuffle(config.model_seed)
-        .num_workers(config.model_workers)
-        .build(Arc::new(train_dataset));
+    let mut dataloader_train_builder = DataLoaderBuilder::new(batcher_train).batch_size(config.model_batch_size).num_workers(config.model_workers);
+
+    // A mixed dataset is already shuffled in blocks, so that every batch honors the mix ratio.
+    if config.real_mix.is_none() {
+        dataloader_train_builder = dataloader_train_builder.shuffle(config.model_seed);
+    }
+
+    let dataloader_train = dataloader_train_builder.build(Arc::new(train_dataset));
 
     let dataloader_test = DataLoaderBuilder::new(batcher_valid)
         .batch_size(config.model_batch_size)
@@ -68,27 +85,123 @@ where
 
     // Define the model.
 
-    let optimizer = Adam::new(&adam_config);
-    let model = KordModel::new(config.mlp_layers, config.mlp_size, config.mlp_dropout, config.sigmoid_strength);
+    let model = KordModel::new(config.mlp_layers, config.mlp_size, config.mlp_dropout, config.sigmoid_strength).with_loss(config.loss.unwrap_or(TrainLoss::MeanSquare));
+
+    // Every metric also records its per-epoch value, so that the history can be exported.
+    let history = MetricsHistory::new();
+
+    // The per-class metrics keep the counts of their last epoch, so that they can be reported after training.
+    let train_per_class = PerClassAccuracyReport::new();
+    let valid_per_class = PerClassAccuracyReport::new();
+
+    // Define the learner builder (as a closure, since early stopping and learning rate schedules build a learner per epoch).
+
+    let learner_builder = |num_epochs: usize| {
+        let mut learner_builder = LearnerBuilder::new(&config.log)
+            //.with_file_checkpointer::<f32>(2)
+            .devices(vec![device.clone()])
+            .num_epochs(num_epochs);
+
+        if !config.no_plots {
+            learner_builder = learner_builder
+                .metric_train_plot(RecordedMetric::new(KordAccuracyMetric::new(), "train_accuracy", &history))
+                .metric_valid_plot(RecordedMetric::new(KordAccuracyMetric::new(), "valid_accuracy", &history))
+                .metric_train_plot(RecordedMetric::new(KordPerClassAccuracyMetric::new(&train_per_class), "train_macro_f1", &history))
+                .metric_valid_plot(RecordedMetric::new(KordPerClassAccuracyMetric::new(&valid_per_class), "valid_macro_f1", &history))
+                .metric_train_plot(RecordedMetric::new(LossMetric::new(), "train_loss", &history))
+                .metric_valid_plot(RecordedMetric::new(LossMetric::new(), "valid_loss", &history));
+        } else if config.metrics_out.is_some() {
+            learner_builder = learner_builder
+                .metric_train(RecordedMetric::new(KordAccuracyMetric::new(), "train_accuracy", &history))
+                .metric_valid(RecordedMetric::new(KordAccuracyMetric::new(), "valid_accuracy", &history))
+                .metric_train(RecordedMetric::new(KordPerClassAccuracyMetric::new(&train_per_class), "train_macro_f1", &history))
+                .metric_valid(RecordedMetric::new(KordPerClassAccuracyMetric::new(&valid_per_class), "valid_macro_f1", &history))
+                .metric_train(RecordedMetric::new(LossMetric::new(), "train_loss", &history))
+                .metric_valid(RecordedMetric::new(LossMetric::new(), "valid_loss", &history));
+        } else if print_accuracy_report {
+            learner_builder = learner_builder.metric_valid(KordPerClassAccuracyMetric::new(&valid_per_class));
+        }
 
-    let mut learner_builder = LearnerBuilder::new(&config.log)
-        //.with_file_checkpointer::<f32>(2)
-        .devices(vec![device.clone()])
-        .num_epochs(config.model_epochs);
+        learner_builder
+    };
 
-    if !config.no_plots {
-        learner_builder = learner_builder
-            .metric_train_plot(KordAccuracyMetric::new())
-            .metric_valid_plot(KordAccuracyMetric::new())
-            .metric_train_plot(LossMetric::new())
-            .metric_valid_plot(LossMetric::new());
-    }
+    // Train the model.
 
-    let learner = learner_builder.build(model, optimizer);
+    let model_trained = if config.early_stopping.is_none() && config.lr_schedule.is_none() {
+        learner_builder(config.model_epochs)
+            .build(model, Adam::new(&adam_config(config.adam_learning_rate)))
+            .fit(dataloader_train, dataloader_test)
+    } else {
+        // Train one epoch at a time, resuming the model and optimizer from the previous epoch's checkpoint.
 
-    // Train the model.
+        let lr_schedule = config.lr_schedule.unwrap_or(LrSchedule::Constant);
+
+        let mut model = model;
+        let mut best: Option<(usize, f64, State<B::FloatElem>)> = None;
+
+        for epoch in 1..=config.model_epochs {
+            let learning_rate = lr_schedule.learning_rate(config.adam_learning_rate, epoch, config.model_epochs);
+            history.record_learning_rate(learning_rate);
+
+            let mut epoch_builder = learner_builder(epoch).with_file_checkpointer::<f32>(1);
+
+            if epoch > 1 {
+                epoch_builder = epoch_builder.checkpoint(epoch - 1);
+            }
+
+            model = epoch_builder
+                .build(model, Adam::new(&adam_config(learning_rate)))
+                .fit(dataloader_train.clone(), dataloader_test.clone());
+
+            // Track the best model, and stop once the validation accuracy plateaus.
+
+            let Some(early_stopping) = config.early_stopping else {
+                continue;
+            };
+
+            let accuracy = compute_accuracy(&model, &valid_items, &device);
+            let is_best = match &best {
+                Some((_, best_accuracy, _)) => early_stopping.is_improvement(*best_accuracy, accuracy),
+                None => true,
+            };
+
+            if is_best {
+                best = Some((epoch, accuracy, model.state()));
+            }
+
+            if let Some((best_epoch, _, _)) = &best {
+                if early_stopping.should_stop(epoch - best_epoch) {
+                    println!("Stopping early after epoch {epoch} (no improvement for {} epochs).", epoch - best_epoch);
+                    break;
+                }
+            }
+        }
+
+        // Restore the best model.
 
-    let model_trained = learner.fit(dataloader_train, dataloader_test);
+        match best {
+            Some((best_epoch, best_accuracy, best_state)) => {
+                println!("Restoring the model from epoch {best_epoch} (validation accuracy: {:.2}%).", best_accuracy * 100.0);
+
+                model.load(&best_state).map_err(|_| anyhow::Error::msg("Could not restore the best model."))?
+            }
+            None => model,
+        }
+    };
+
+    // Report the per-class accuracy of the last validation epoch.
+
+    if print_accuracy_report && !valid_per_class.counts().is_empty() {
+        println!();
+        println!("Per-note validation accuracy (last epoch):");
+        println!("{}", valid_per_class.counts().report());
+    }
+
+    // Save the metrics history.
+
+    if let Some(metrics_out) = &config.metrics_out {
+        history.save(metrics_out, config.adam_learning_rate)?;
+    }
 
     // Save the model.
 
@@ -96,14 +209,23 @@ where
         let config_path = format!("{}/model_config.json", &config.destination);
         let state_path = format!("{}/state.json.gz", &config.destination);
         let state_bincode_path = format!("{}/state.bincode", &config.destination);
+        let state_q8_bincode_path = format!("{}/state_q8.bincode", &config.destination);
+        let onnx_path = format!("{}/model.onnx", &config.destination);
         let _ = std::fs::create_dir_all(&config.destination);
         let _ = std::fs::remove_file(&config_path);
         let _ = std::fs::remove_file(&state_path);
         let _ = std::fs::remove_file(&state_bincode_path);
+        let _ = std::fs::remove_file(&state_q8_bincode_path);
+        let _ = std::fs::remove_file(&onnx_path);
 
         config.save(&config_path)?;
         model_trained.state().save(&state_path)?;
         std::fs::write(&state_bincode_path, bincode::serde::encode_to_vec(&model_trained.state(), bincode::config::standard())?)?;
+        std::fs::write(
+            &state_q8_bincode_path,
+            bincode::serde::encode_to_vec(quantize_state(&model_trained.state()), bincode::config::standard())?,
+        )?;
+        save_model_to_onnx(&model_trained, &onnx_path)?;
     }
 
     // Compute overall accuracy.
@@ -114,8 +236,28 @@ where
 }
 
 
+/// Computes the accuracy of the model over the given items (i.e., the fraction of items whose notes are all inferred correctly).
+pub fn compute_accuracy<B: Backend>(model: &KordModel<B>, kord_items: &[KordItem], device: &B::Device) -> f64 {
+    if kord_items.is_empty() {
+        return 0.0;
+    }
+
+    let correct = kord_items
+        .iter()
+        .filter(|kord_item| {
+            let sample = kord_item_to_sample_tensor(kord_item).to_device(device).detach();
+            let target: Vec<f32> = kord_item_to_target_tensor::<B>(kord_item).into_data().convert().value;
+            let inferred = model.forward(sample).to_data().convert().value.into_iter().map(f32::round).collect::<Vec<f32>>();
+
+            target == inferred
+        })
+        .count();
+
+    correct as f64 / kord_items.len() as f64
+}
+
 pub fn compute_overall_accuracy<B: Backend>(model_trained: &KordModel<B>, device: &B::Device) -> f32 {
-    let dataset = KordDataset::from_folder_and_simulation("samples", 0, 0.0, 0.0, 0.0);
+    let dataset = KordDataset::from_folder_and_simulation("samples", 0, &SimulationConfig::default());
 
     let kord_items = dataset.1.items;
     //kord_items.extend(dataset.0.items);
@@ -242,6 +384,11 @@ pub fn hyper_parameter_tuning(source: String, destination: String, log: String,
                                             adam_epsilon: f32::EPSILON,
                                             sigmoid_strength: 1.0,
                                             no_plots: true,
+                                            metrics_out: None,
+                                            early_stopping: None,
+                                            lr_schedule: None,
+                                            loss: None,
+                                            real_mix: None,
                                         };
 
                                         println!("Running training {}/{}:\n\n{}\n", count, total, config);
@@ -307,14 +454,13 @@ pub fn hyper_parameter_tuning(source: String, destination: String, log: String,
 #[cfg(feature = "ml_train")]
 mod tests {
     use super::*;
+    use crate::ml::base::EarlyStopping;
     use burn_autodiff::ADBackendDecorator;
     use burn_ndarray::{NdArrayBackend, NdArrayDevice};
 
-    #[test]
-    fn test_train() {
-        let device = NdArrayDevice::Cpu;
-
-        let config = TrainConfig {
+    /// Returns the (small) config that the training tests start from.
+    fn test_config() -> TrainConfig {
+        TrainConfig {
             source: "tests/samples".to_string(),
             destination: ".hidden/test_model".to_string(),
             log: ".hidden/test_log".to_string(),
@@ -336,8 +482,87 @@ mod tests {
             adam_epsilon: 1e-5,
             sigmoid_strength: 1.0,
             no_plots: true,
+            metrics_out: None,
+            early_stopping: None,
+            lr_schedule: None,
+            loss: None,
+            real_mix: None,
+        }
+    }
+
+    #[test]
+    fn test_train() {
+        let device = NdArrayDevice::Cpu;
+
+        let config = test_config();
+
+        run_training::<ADBackendDecorator<NdArrayBackend<f32>>>(device, &config, false, false).unwrap();
+    }
+
+    #[test]
+    fn test_train_metrics_out() {
+        let device = NdArrayDevice::Cpu;
+        let metrics_out = ".hidden/test_metrics/history.json";
+
+        let config = TrainConfig {
+            model_epochs: 2,
+            metrics_out: Some(metrics_out.to_string()),
+            ..test_config()
         };
 
         run_training::<ADBackendDecorator<NdArrayBackend<f32>>>(device, &config, false, false).unwrap();
+
+        let entries: Vec<serde_json::Value> = serde_json::from_str(&std::fs::read_to_string(metrics_out).unwrap()).unwrap();
+
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[1]["epoch"], 2);
+        assert!(entries[0]["train_loss"].is_number());
+        assert!(entries[0]["valid_accuracy"].is_number());
+    }
+
+    #[test]
+    fn test_train_early_stopping() {
+        let device = NdArrayDevice::Cpu;
+        let metrics_out = ".hidden/test_metrics/early_stopping.json";
+
+        // No epoch can improve on the first by more than 100%, so training stops after `patience` more epochs.
+        let config = TrainConfig {
+            log: ".hidden/test_log_early_stopping".to_string(),
+            model_epochs: 5,
+            metrics_out: Some(metrics_out.to_string()),
+            early_stopping: Some(EarlyStopping::new(1, 1.0)),
+            ..test_config()
+        };
+
+        run_training::<ADBackendDecorator<NdArrayBackend<f32>>>(device, &config, false, false).unwrap();
+
+        let entries: Vec<serde_json::Value> = serde_json::from_str(&std::fs::read_to_string(metrics_out).unwrap()).unwrap();
+
+        assert_eq!(entries.len(), 2);
+    }
+
+    #[test]
+    fn test_train_lr_schedule() {
+        let device = NdArrayDevice::Cpu;
+        let metrics_out = ".hidden/test_metrics/lr_schedule.json";
+        let lr_schedule = LrSchedule::CosineAnnealing { min_lr: 1e-5 };
+
+        let config = TrainConfig {
+            log: ".hidden/test_log_lr_schedule".to_string(),
+            model_epochs: 3,
+            metrics_out: Some(metrics_out.to_string()),
+            lr_schedule: Some(lr_schedule),
+            ..test_config()
+        };
+
+        run_training::<ADBackendDecorator<NdArrayBackend<f32>>>(device, &config, false, false).unwrap();
+
+        let entries: Vec<serde_json::Value> = serde_json::from_str(&std::fs::read_to_string(metrics_out).unwrap()).unwrap();
+
+        assert_eq!(entries.len(), 3);
+
+        for (k, entry) in entries.iter().enumerate() {
+            assert_eq!(entry["learning_rate"].as_f64().unwrap(), lr_schedule.learning_rate(1e-4, k + 1, 3));
+        }
     }
 }
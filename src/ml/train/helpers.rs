@@ -1,5 +1,11 @@
 //! Helpers for training models.
 
+use std::{
+    marker::PhantomData,
+    path::PathBuf,
+    sync::{Arc, Mutex},
+};
+
 use burn::{
     module::{Module, ModuleVisitor, ParamId},
     tensor::{
@@ -14,7 +20,7 @@ use burn::{
         TrainOutput, TrainStep, ValidStep,
     },
 };
-use rand::Rng;
+use rand::{rngs::StdRng, Rng, SeedableRng};
 use rayon::prelude::{IntoParallelIterator, ParallelIterator};
 
 use crate::{
@@ -124,6 +130,46 @@ impl<B: Backend> FocalLoss<B> {
     }
 }
 
+/// A differentiable (soft) F-beta loss, which is `1 - F`, computed from the soft true positives, false positives, and false negatives of the batch.
+///
+/// Since it only counts the positive classes, it is well suited to sparse multi-label targets.  A `beta` of `1.0` (the default) is the F1 loss, and
+/// larger values weight recall more heavily than precision.
+#[derive(Debug, Clone)]
+pub struct SoftF1Loss<B: Backend> {
+    /// The weight of recall relative to precision (i.e., recall is considered `beta` times as important as precision).
+    pub beta: f32,
+    _b: B,
+}
+
+impl<B: Backend> Default for SoftF1Loss<B> {
+    fn default() -> Self {
+        Self { beta: 1.0, _b: B::default() }
+    }
+}
+
+impl<B: Backend> SoftF1Loss<B> {
+    pub fn forward(&self, outputs: Tensor<B, 2>, targets: Tensor<B, 2>) -> Tensor<B, 1> {
+        let beta_squared = self.beta * self.beta;
+
+        let true_positives = outputs.clone().mul(targets.clone()).sum();
+        let false_positives = outputs.clone().mul(targets.clone().neg().add_scalar(1f32)).sum();
+        let false_negatives = outputs.neg().add_scalar(1f32).mul(targets).sum();
+
+        let numerator = true_positives.mul_scalar(1.0 + beta_squared);
+        let denominator = numerator.clone().add(false_negatives.mul_scalar(beta_squared)).add(false_positives).add_scalar(0.000001f32);
+
+        let loss = numerator.div(denominator).neg().add_scalar(1f32);
+
+        let value: f32 = loss.to_data().convert().value[0];
+
+        if value.is_nan() {
+            panic!("NaN loss");
+        }
+
+        loss
+    }
+}
+
 // Harmonic loss penalty.
 
 pub fn get_harmonic_penalty_tensor<B: Backend>() -> Tensor<B, 2> {
@@ -238,29 +284,306 @@ impl<B: Backend> Numeric for KordAccuracyMetric<B> {
     }
 }
 
+// Per-class accuracy metrics.
+
+/// The per-class (i.e., per-note) true positive, false positive, and false negative counts.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PerClassCounts {
+    /// The number of times each class was correctly predicted.
+    pub true_positives: [u64; NUM_CLASSES],
+    /// The number of times each class was predicted, but was not present.
+    pub false_positives: [u64; NUM_CLASSES],
+    /// The number of times each class was present, but was not predicted.
+    pub false_negatives: [u64; NUM_CLASSES],
+}
+
+impl Default for PerClassCounts {
+    fn default() -> Self {
+        Self {
+            true_positives: [0; NUM_CLASSES],
+            false_positives: [0; NUM_CLASSES],
+            false_negatives: [0; NUM_CLASSES],
+        }
+    }
+}
+
+impl PerClassCounts {
+    /// Creates a new, empty [`PerClassCounts`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the (row-major, `NUM_CLASSES` wide) outputs and targets of a batch, where values of at least `0.5` are positive.
+    pub fn add(&mut self, outputs: &[f32], targets: &[f32]) {
+        for (k, (output, target)) in outputs.iter().zip(targets).enumerate() {
+            let class = k % NUM_CLASSES;
+
+            match (*output >= 0.5, *target >= 0.5) {
+                (true, true) => self.true_positives[class] += 1,
+                (true, false) => self.false_positives[class] += 1,
+                (false, true) => self.false_negatives[class] += 1,
+                (false, false) => {}
+            }
+        }
+    }
+
+    /// Returns whether or not nothing has been counted.
+    pub fn is_empty(&self) -> bool {
+        (0..NUM_CLASSES).all(|class| self.true_positives[class] + self.false_positives[class] + self.false_negatives[class] == 0)
+    }
+
+    /// Returns the precision of the class (if it was ever predicted).
+    pub fn precision(&self, class: usize) -> Option<f64> {
+        let predicted = self.true_positives[class] + self.false_positives[class];
+
+        (predicted > 0).then(|| self.true_positives[class] as f64 / predicted as f64)
+    }
+
+    /// Returns the recall of the class (if it was ever present).
+    pub fn recall(&self, class: usize) -> Option<f64> {
+        let present = self.true_positives[class] + self.false_negatives[class];
+
+        (present > 0).then(|| self.true_positives[class] as f64 / present as f64)
+    }
+
+    /// Returns the F1 score of the class (if it was ever predicted or present).
+    pub fn f1(&self, class: usize) -> Option<f64> {
+        let denominator = 2 * self.true_positives[class] + self.false_positives[class] + self.false_negatives[class];
+
+        (denominator > 0).then(|| 2.0 * self.true_positives[class] as f64 / denominator as f64)
+    }
+
+    /// Returns the F1 score averaged over the classes that were ever predicted or present.
+    pub fn macro_f1(&self) -> f64 {
+        let scores = (0..NUM_CLASSES).filter_map(|class| self.f1(class)).collect::<Vec<_>>();
+
+        if scores.is_empty() {
+            return 0.0;
+        }
+
+        scores.iter().sum::<f64>() / scores.len() as f64
+    }
+
+    /// Returns the [`Note`] represented by the class.
+    pub fn class_note(class: usize) -> Option<Note> {
+        Note::from_id(1 << (NUM_CLASSES - 1 - class)).ok()
+    }
+
+    /// Returns a per-note precision / recall table of the classes that were ever predicted or present.
+    pub fn report(&self) -> String {
+        let mut report = format!("{:>6} {:>8} {:>8} {:>8} {:>10} {:>8} {:>8}\n", "note", "tp", "fp", "fn", "precision", "recall", "f1");
+
+        for class in 0..NUM_CLASSES {
+            let Some(f1) = self.f1(class) else {
+                continue;
+            };
+
+            let note = Self::class_note(class).map(|n| n.to_string()).unwrap_or_else(|| format!("#{class}"));
+            let format_ratio = |value: Option<f64>| value.map(|v| format!("{:.3}", v)).unwrap_or_else(|| "-".to_owned());
+
+            report.push_str(&format!(
+                "{:>6} {:>8} {:>8} {:>8} {:>10} {:>8} {:>8.3}\n",
+                note,
+                self.true_positives[class],
+                self.false_positives[class],
+                self.false_negatives[class],
+                format_ratio(self.precision(class)),
+                format_ratio(self.recall(class)),
+                f1
+            ));
+        }
+
+        report
+    }
+}
+
+/// A shared report of the per-class counts of the most recently completed epoch.
+///
+/// Clones share the same underlying counts, so a clone can be handed to a [`KordPerClassAccuracyMetric`], and read after training.
+#[derive(Clone, Debug, Default)]
+pub struct PerClassAccuracyReport {
+    counts: Arc<Mutex<PerClassCounts>>,
+}
+
+impl PerClassAccuracyReport {
+    /// Creates a new, empty [`PerClassAccuracyReport`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the counts of the most recently completed epoch.
+    pub fn counts(&self) -> PerClassCounts {
+        self.counts.lock().unwrap().clone()
+    }
+
+    /// Replaces the counts with those of a completed epoch.
+    fn set(&self, counts: PerClassCounts) {
+        *self.counts.lock().unwrap() = counts;
+    }
+}
+
+/// A metric that tracks the true positives, false positives, and false negatives of each of the `NUM_CLASSES` note classes.
+///
+/// Its numeric value is the (batch) macro-averaged F1 score, and the counts of each completed epoch are stored into its [`PerClassAccuracyReport`].
+pub struct KordPerClassAccuracyMetric<B: Backend> {
+    state: NumericMetricState,
+    counts: PerClassCounts,
+    report: PerClassAccuracyReport,
+    _b: PhantomData<B>,
+}
+
+impl<B: Backend> KordPerClassAccuracyMetric<B> {
+    /// Create the metric, which stores its per-epoch counts into the given report.
+    pub fn new(report: &PerClassAccuracyReport) -> Self {
+        Self {
+            state: NumericMetricState::default(),
+            counts: PerClassCounts::new(),
+            report: report.clone(),
+            _b: PhantomData,
+        }
+    }
+}
+
+impl<B: Backend> Metric for KordPerClassAccuracyMetric<B> {
+    type Input = KordAccuracyInput<B>;
+
+    fn update(&mut self, input: &KordAccuracyInput<B>) -> MetricEntry {
+        let [batch_size, _n_classes] = input.targets.dims();
+
+        let outputs: Vec<f32> = input.outputs.clone().into_data().convert().value;
+        let targets: Vec<f32> = input.targets.clone().into_data().convert().value;
+
+        let mut batch_counts = PerClassCounts::new();
+        batch_counts.add(&outputs, &targets);
+        self.counts.add(&outputs, &targets);
+
+        let macro_f1 = 100.0 * batch_counts.macro_f1();
+
+        self.state.update(macro_f1, batch_size, FormatOptions::new("Macro F1").unit("%").precision(2))
+    }
+
+    fn clear(&mut self) {
+        if !self.counts.is_empty() {
+            self.report.set(std::mem::take(&mut self.counts));
+        }
+
+        self.state.reset()
+    }
+}
+
+impl<B: Backend> Numeric for KordPerClassAccuracyMetric<B> {
+    fn value(&self) -> f64 {
+        self.state.value()
+    }
+}
+
+// Simulation config.
+
+/// The configuration of the simulated (synthetic) training data.
+#[derive(Clone, Debug)]
+pub struct SimulationConfig {
+    /// The index (into [`ALL_PITCH_NOTES`]) of the lowest simulated chord root.
+    pub lowest_note_index: usize,
+    /// The number of simulated chord roots (ascending from the lowest).
+    pub note_count: usize,
+    /// The chord shapes that are simulated for every root (i.e., the number of chords per note).
+    ///
+    /// Each shape is a list of interval candidate sets: one interval is picked (at random) from each set, and added to the root.
+    pub chord_shapes: Vec<Vec<Vec<Interval>>>,
+    /// The noise files that the samples are layered onto, along with their (relative) probabilities.
+    pub noise_files: Vec<(PathBuf, f32)>,
+    /// The radius (in Hz) of each simulated harmonic peak.
+    pub peak_radius: f32,
+    /// The amount that each successive harmonic decays.
+    pub harmonic_decay: f32,
+    /// The amount that each harmonic is (randomly) detuned.
+    pub frequency_wobble: f32,
+    /// The seed of the simulation, which makes the generated samples reproducible (or `None` for a random seed).
+    pub seed: Option<u64>,
+}
+
+impl Default for SimulationConfig {
+    fn default() -> Self {
+        let lower = vec![Interval::MinorSecond, Interval::MajorSecond, Interval::MinorThird, Interval::MajorThird, Interval::PerfectFourth];
+        let middle = vec![Interval::AugmentedFourth, Interval::PerfectFifth, Interval::AugmentedFifth, Interval::MajorSixth];
+        let upper = vec![
+            Interval::MinorSeventh,
+            Interval::MajorSeventh,
+            Interval::MinorNinth,
+            Interval::MajorNinth,
+            Interval::AugmentedNinth,
+            Interval::DiminishedEleventh,
+            Interval::PerfectEleventh,
+            Interval::AugmentedEleventh,
+            Interval::MinorThirteenth,
+            Interval::MajorThirteenth,
+            Interval::AugmentedThirteenth,
+        ];
+
+        Self {
+            lowest_note_index: 24,
+            note_count: 60,
+            chord_shapes: vec![vec![], vec![], vec![lower.clone()], vec![lower.clone(), middle.clone()], vec![lower, middle, upper]],
+            noise_files: ["no_noise", "pink_noise", "white_noise", "brown_noise"]
+                .into_iter()
+                .map(|name| (PathBuf::from(format!("assets/{name}.bin")), 1.0))
+                .collect(),
+            peak_radius: 1.0,
+            harmonic_decay: 0.1,
+            frequency_wobble: 0.4,
+            seed: None,
+        }
+    }
+}
+
+impl SimulationConfig {
+    /// Returns the number of chords simulated for each root.
+    pub fn chords_per_note(&self) -> usize {
+        self.chord_shapes.len()
+    }
+
+    /// Picks a noise file (according to the probabilities), and loads it, or returns an empty item if there are no noise files.
+    fn load_random_noise(&self, rng: &mut impl Rng) -> KordItem {
+        let total = self.noise_files.iter().map(|(_, p)| p).sum::<f32>();
+
+        if total <= 0.0 {
+            return KordItem::default();
+        }
+
+        let mut choice = get_random_between(rng, 0.0, total);
+
+        for (path, probability) in &self.noise_files {
+            if choice < *probability {
+                return load_kord_item(path);
+            }
+
+            choice -= probability;
+        }
+
+        load_kord_item(&self.noise_files.last().unwrap().0)
+    }
+}
+
 // Operations for simulating kord samples.
 
-pub fn get_simulated_kord_item(notes: &[Note], peak_radius: f32, harmonic_decay: f32, frequency_wobble: f32) -> KordItem {
+pub fn get_simulated_kord_item(rng: &mut impl Rng, notes: &[Note], config: &SimulationConfig) -> KordItem {
     let wobble_divisor = 35.0;
+    let peak_radius = config.peak_radius;
+    let harmonic_decay = config.harmonic_decay;
+    let frequency_wobble = config.frequency_wobble;
 
-    let mut result = match get_random_between(0.0, 4.0).round() as u32 {
-        0 | 4 => load_kord_item("assets/no_noise.bin"),
-        1 => load_kord_item("assets/pink_noise.bin"),
-        2 => load_kord_item("assets/white_noise.bin"),
-        3 => load_kord_item("assets/brown_noise.bin"),
-        _ => unreachable!(),
-    };
+    let mut result = config.load_random_noise(rng);
 
     for note in notes {
         let mut harmonic_strength = 1.0;
 
-        let note_frequency = note.frequency() + (1.0 + 1.0 / wobble_divisor * get_random_between(-frequency_wobble, frequency_wobble));
+        let note_frequency = note.frequency() + (1.0 + 1.0 / wobble_divisor * get_random_between(rng, -frequency_wobble, frequency_wobble));
 
         let true_harmonic_series = (1..14)
             .into_iter()
             .map(|k| {
                 let f = k as f32 * note_frequency;
-                f * (1.0 + 1.0 / wobble_divisor * get_random_between(-frequency_wobble, frequency_wobble))
+                f * (1.0 + 1.0 / wobble_divisor * get_random_between(rng, -frequency_wobble, frequency_wobble))
             })
             .collect::<Vec<_>>();
 
@@ -278,7 +601,7 @@ pub fn get_simulated_kord_item(notes: &[Note], peak_radius: f32, harmonic_decay:
                 continue;
             }
 
-            let peak_strength = 4000.0 * harmonic_strength * get_random_between(0.8, 1.0);
+            let peak_strength = 4000.0 * harmonic_strength * get_random_between(rng, 0.8, 1.0);
 
             for i in (harmonic_frequency - peak_radius).round() as usize..(harmonic_frequency + peak_radius).round() as usize {
                 result.frequency_space[i] += peak_strength * (1.0 - ((2.0 / peak_radius) * (i as f32 - harmonic_frequency).abs()).tanh());
@@ -293,61 +616,31 @@ pub fn get_simulated_kord_item(notes: &[Note], peak_radius: f32, harmonic_decay:
     result
 }
 
-pub fn get_simulated_kord_items(count: usize, peak_radius: f32, harmonic_decay: f32, frequency_wobble: f32) -> Vec<KordItem> {
-    let results = (0..count).into_par_iter().map(|_| {
-        let note_count = 60;
-        let chord_count = 5;
-        let mut inner_result = Vec::with_capacity(note_count * chord_count);
+/// Simulates `count` rounds of samples, where each round simulates every chord shape on every root.
+///
+/// Each round is generated from its own RNG, seeded from the base seed and the round index, so that a seeded simulation
+/// is deterministic regardless of how the rounds are scheduled across threads.
+pub fn get_simulated_kord_items(count: usize, config: &SimulationConfig) -> Vec<KordItem> {
+    let seed = config.seed.unwrap_or_else(|| rand::thread_rng().gen());
 
-        for note in ALL_PITCH_NOTES.iter().skip(24).take(note_count) {
+    let results = (0..count).into_par_iter().map(|k| {
+        let mut rng = StdRng::seed_from_u64(seed.wrapping_add(k as u64));
+        let mut inner_result = Vec::with_capacity(config.note_count * config.chords_per_note());
+
+        for note in ALL_PITCH_NOTES.iter().skip(config.lowest_note_index).take(config.note_count) {
             let note = *note;
 
-            for k in 0..chord_count {
-                let mut notes = vec![];
-
-                match k {
-                    0 => {
-                        notes.push(note);
-                    }
-                    1 => {
-                        notes.push(note);
-                    }
-                    2 => {
-                        notes.push(note);
-                        notes.push(note + get_random_item(&[Interval::MinorSecond, Interval::MajorSecond, Interval::MinorThird, Interval::MajorThird, Interval::PerfectFourth]));
-                    }
-                    3 => {
-                        notes.push(note);
-                        notes.push(note + get_random_item(&[Interval::MinorSecond, Interval::MajorSecond, Interval::MinorThird, Interval::MajorThird, Interval::PerfectFourth]));
-                        notes.push(note + get_random_item(&[Interval::AugmentedFourth, Interval::PerfectFifth, Interval::AugmentedFifth, Interval::MajorSixth]));
-                    }
-                    4 => {
-                        notes.push(note);
-                        notes.push(note + get_random_item(&[Interval::MinorSecond, Interval::MajorSecond, Interval::MinorThird, Interval::MajorThird, Interval::PerfectFourth]));
-                        notes.push(note + get_random_item(&[Interval::AugmentedFourth, Interval::PerfectFifth, Interval::AugmentedFifth, Interval::MajorSixth]));
-                        notes.push(
-                            note + get_random_item(&[
-                                Interval::MinorSeventh,
-                                Interval::MajorSeventh,
-                                Interval::MinorNinth,
-                                Interval::MajorNinth,
-                                Interval::AugmentedNinth,
-                                Interval::DiminishedEleventh,
-                                Interval::PerfectEleventh,
-                                Interval::AugmentedEleventh,
-                                Interval::MinorThirteenth,
-                                Interval::MajorThirteenth,
-                                Interval::AugmentedThirteenth,
-                            ]),
-                        );
-                    }
-                    _ => unreachable!(),
+            for shape in &config.chord_shapes {
+                let mut notes = vec![note];
+
+                for candidates in shape {
+                    notes.push(note + get_random_item(&mut rng, candidates));
                 }
 
                 notes.sort();
 
                 // Generate the sample.
-                let kord_item = get_simulated_kord_item(&notes, peak_radius, harmonic_decay, frequency_wobble);
+                let kord_item = get_simulated_kord_item(&mut rng, &notes, config);
 
                 inner_result.push(kord_item);
             }
@@ -360,21 +653,18 @@ pub fn get_simulated_kord_items(count: usize, peak_radius: f32, harmonic_decay:
 }
 
 /// Get a random item from a list of items.
-pub fn get_random_item<T: Copy>(items: &[T]) -> T {
-    let mut rng = rand::thread_rng();
+pub fn get_random_item<T: Copy>(rng: &mut impl Rng, items: &[T]) -> T {
     let index = rng.gen_range(0..items.len());
     items[index]
 }
 
 /// Get a random number between 0 and 1.
-pub fn get_random() -> f32 {
-    let mut rng = rand::thread_rng();
+pub fn get_random(rng: &mut impl Rng) -> f32 {
     rng.gen()
 }
 
 /// Get a random number between two numbers.
-pub fn get_random_between(min: f32, max: f32) -> f32 {
-    let mut rng = rand::thread_rng();
+pub fn get_random_between(rng: &mut impl Rng, min: f32, max: f32) -> f32 {
     rng.gen_range(min..max)
 }
 
@@ -384,7 +674,10 @@ pub fn get_random_between(min: f32, max: f32) -> f32 {
 mod tests {
     use std::path::Path;
 
-    use crate::ml::base::{helpers::save_kord_item, KordItem, FREQUENCY_SPACE_SIZE};
+    use crate::{
+        core::note::{BFlatOne, COne, DTwo, EOne, GOne},
+        ml::base::{helpers::save_kord_item, KordItem, FREQUENCY_SPACE_SIZE},
+    };
 
     use super::*;
     use pretty_assertions::assert_eq;
@@ -405,4 +698,102 @@ mod tests {
 
         assert_eq!(item.label, loaded.label);
     }
+
+    #[test]
+    fn test_per_class_counts() {
+        let mut counts = PerClassCounts::new();
+        assert!(counts.is_empty());
+
+        // Two samples: the first predicts classes 0 and 1 (only 0 is present), and the second misses class 2.
+        let mut outputs = vec![0f32; 2 * NUM_CLASSES];
+        let mut targets = vec![0f32; 2 * NUM_CLASSES];
+
+        outputs[0] = 0.9;
+        outputs[1] = 0.7;
+        targets[0] = 1.0;
+
+        outputs[NUM_CLASSES] = 0.6;
+        targets[NUM_CLASSES] = 1.0;
+        targets[NUM_CLASSES + 2] = 1.0;
+
+        counts.add(&outputs, &targets);
+
+        assert_eq!(counts.true_positives[0], 2);
+        assert_eq!(counts.false_positives[1], 1);
+        assert_eq!(counts.false_negatives[2], 1);
+
+        assert_eq!(counts.precision(0), Some(1.0));
+        assert_eq!(counts.precision(1), Some(0.0));
+        assert_eq!(counts.recall(1), None);
+        assert_eq!(counts.recall(2), Some(0.0));
+        assert_eq!(counts.f1(3), None);
+        assert_eq!(counts.macro_f1(), 1.0 / 3.0);
+
+        // Only the three classes that were predicted or present are reported (plus the header).
+        assert_eq!(counts.report().lines().count(), 4);
+        assert_eq!(PerClassCounts::class_note(NUM_CLASSES - 1), Note::from_id(1).ok());
+    }
+
+    #[test]
+    fn test_soft_f1_loss() {
+        use burn_ndarray::NdArrayBackend;
+
+        let tensor = |data: [[f32; 2]; 2]| Tensor::<NdArrayBackend<f32>, 2>::from_data(Data::<f32, 2>::from(data).convert());
+        let value = |loss: Tensor<NdArrayBackend<f32>, 1>| -> f32 { loss.into_data().convert().value[0] };
+
+        let outputs = tensor([[0.8, 0.2], [0.6, 0.0]]);
+        let targets = tensor([[1.0, 0.0], [0.0, 1.0]]);
+
+        // The soft counts are: TP = 0.8, FP = 0.2 + 0.6 = 0.8, and FN = 0.2 + 1.0 = 1.2.
+        let mut loss = SoftF1Loss::default();
+        assert!((value(loss.forward(outputs.clone(), targets.clone())) - (1.0 - 1.6 / 3.6)).abs() < 1e-5);
+
+        loss.beta = 2.0;
+        assert!((value(loss.forward(outputs, targets.clone())) - (1.0 - 4.0 / 9.6)).abs() < 1e-5);
+
+        // A perfect prediction has (almost) no loss.
+        assert!(value(SoftF1Loss::default().forward(targets.clone(), targets)).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_simulated_kord_items() {
+        let config = SimulationConfig::default();
+        assert_eq!(config.chords_per_note(), 5);
+        assert_eq!(get_simulated_kord_items(1, &config).len(), 300);
+
+        // A wider bass range, with denser (five note) chords, and no noise.
+        let config = SimulationConfig {
+            lowest_note_index: 12,
+            note_count: 2,
+            chord_shapes: vec![vec![vec![Interval::MajorThird], vec![Interval::PerfectFifth], vec![Interval::MinorSeventh], vec![Interval::MajorNinth]]],
+            noise_files: vec![],
+            ..SimulationConfig::default()
+        };
+
+        let items = get_simulated_kord_items(1, &config);
+
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].label, Note::id_mask(&[COne, EOne, GOne, BFlatOne, DTwo]));
+        assert!(items.iter().all(|item| item.label.count_ones() == 5));
+    }
+    #[test]
+    fn test_seeded_simulation() {
+        let config = SimulationConfig {
+            note_count: 12,
+            seed: Some(42),
+            ..SimulationConfig::default()
+        };
+
+        let labels = |config: &SimulationConfig| get_simulated_kord_items(4, config).iter().map(|item| item.label.to_le_bytes()).collect::<Vec<_>>();
+
+        // The same seed yields the exact same dataset (even though the rounds are generated in parallel).
+        let first = get_simulated_kord_items(4, &config);
+        let second = get_simulated_kord_items(4, &config);
+
+        assert_eq!(labels(&config), labels(&config));
+        assert!(first.iter().zip(&second).all(|(a, b)| a.frequency_space == b.frequency_space));
+
+        // A different seed yields a different dataset.
+        assert_ne!(labels(&config), labels(&SimulationConfig { seed: Some(43), ..config.clone() }));
+    }
 }
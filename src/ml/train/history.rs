@@ -0,0 +1,209 @@
+//! Per-epoch training metrics history, which can be exported as JSON or CSV.
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    path::Path,
+    sync::{Arc, Mutex},
+};
+
+use burn::train::metric::{Metric, MetricEntry, Numeric};
+use serde::Serialize;
+
+use crate::core::base::Void;
+
+// History.
+
+/// The metric values recorded at the end of a single epoch.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct EpochMetrics {
+    /// The (1-based) epoch number.
+    pub epoch: usize,
+    /// The learning rate used for the epoch.
+    pub learning_rate: f64,
+    /// The metric values, keyed by name (e.g., `train_loss`, `valid_accuracy`).
+    #[serde(flatten)]
+    pub values: BTreeMap<String, f64>,
+}
+
+/// A shared, per-epoch history of metric values.
+///
+/// Clones share the same underlying history, so a clone can be handed to every [`RecordedMetric`].
+#[derive(Clone, Debug, Default)]
+pub struct MetricsHistory {
+    values: Arc<Mutex<BTreeMap<String, Vec<f64>>>>,
+    learning_rates: Arc<Mutex<Vec<f64>>>,
+}
+
+impl MetricsHistory {
+    /// Creates a new, empty [`MetricsHistory`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records the end-of-epoch value of the named metric.
+    pub fn record(&self, name: &str, value: f64) {
+        self.values.lock().unwrap().entry(name.to_owned()).or_default().push(value);
+    }
+
+    /// Records the learning rate used for the next epoch (e.g., when following a learning rate schedule).
+    pub fn record_learning_rate(&self, learning_rate: f64) {
+        self.learning_rates.lock().unwrap().push(learning_rate);
+    }
+
+    /// Returns the recorded history, with one entry per epoch.
+    ///
+    /// Each epoch gets its recorded learning rate, and any epoch without one gets the given `learning_rate`.
+    pub fn epochs(&self, learning_rate: f64) -> Vec<EpochMetrics> {
+        let values = self.values.lock().unwrap();
+        let learning_rates = self.learning_rates.lock().unwrap();
+        let num_epochs = values.values().map(Vec::len).max().unwrap_or_default();
+
+        (0..num_epochs)
+            .map(|k| EpochMetrics {
+                epoch: k + 1,
+                learning_rate: learning_rates.get(k).copied().unwrap_or(learning_rate),
+                values: values.iter().filter_map(|(name, history)| history.get(k).map(|v| (name.clone(), *v))).collect(),
+            })
+            .collect()
+    }
+
+    /// Saves the history to the given path.
+    ///
+    /// Paths ending in `.csv` are written as CSV (with a header row); all others are written as a JSON array.
+    pub fn save(&self, path: impl AsRef<Path>, learning_rate: f64) -> Void {
+        let path = path.as_ref();
+        let epochs = self.epochs(learning_rate);
+
+        let contents = if path.extension().map(|e| e.eq_ignore_ascii_case("csv")).unwrap_or(false) {
+            Self::to_csv(&epochs)
+        } else {
+            serde_json::to_string_pretty(&epochs)?
+        };
+
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        std::fs::write(path, contents)?;
+
+        Ok(())
+    }
+
+    /// Formats the epochs as CSV.
+    fn to_csv(epochs: &[EpochMetrics]) -> String {
+        let names = epochs.iter().flat_map(|e| e.values.keys()).collect::<BTreeSet<_>>();
+
+        let mut csv = String::from("epoch,learning_rate");
+        for name in &names {
+            csv.push_str(&format!(",{name}"));
+        }
+        csv.push('\n');
+
+        for epoch in epochs {
+            csv.push_str(&format!("{},{}", epoch.epoch, epoch.learning_rate));
+
+            for name in &names {
+                csv.push(',');
+
+                if let Some(value) = epoch.values.get(*name) {
+                    csv.push_str(&value.to_string());
+                }
+            }
+
+            csv.push('\n');
+        }
+
+        csv
+    }
+}
+
+// Metric wrapper.
+
+/// A [`Metric`] wrapper that records the wrapped metric's value into a [`MetricsHistory`] at the end of every epoch.
+///
+/// The learner clears its metrics at the end of each epoch, so the (epoch-aggregated) value is captured right before that happens.
+pub struct RecordedMetric<M> {
+    inner: M,
+    name: String,
+    history: MetricsHistory,
+    has_updates: bool,
+}
+
+impl<M> RecordedMetric<M> {
+    /// Wraps the metric, recording it into the history under the given name.
+    pub fn new(inner: M, name: &str, history: &MetricsHistory) -> Self {
+        Self {
+            inner,
+            name: name.to_owned(),
+            history: history.clone(),
+            has_updates: false,
+        }
+    }
+}
+
+impl<M: Metric + Numeric> Metric for RecordedMetric<M> {
+    type Input = M::Input;
+
+    fn update(&mut self, item: &Self::Input) -> MetricEntry {
+        self.has_updates = true;
+        self.inner.update(item)
+    }
+
+    fn clear(&mut self) {
+        if self.has_updates {
+            self.history.record(&self.name, self.inner.value());
+            self.has_updates = false;
+        }
+
+        self.inner.clear();
+    }
+}
+
+impl<M: Metric + Numeric> Numeric for RecordedMetric<M> {
+    fn value(&self) -> f64 {
+        self.inner.value()
+    }
+}
+
+// Tests.
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_history() {
+        let history = MetricsHistory::new();
+
+        history.record("train_loss", 0.5);
+        history.record("valid_loss", 0.6);
+        history.record("train_loss", 0.25);
+        history.record("valid_loss", 0.3);
+
+        let epochs = history.epochs(1e-4);
+
+        assert_eq!(epochs.len(), 2);
+        assert_eq!(epochs[1].epoch, 2);
+        assert_eq!(epochs[1].values["train_loss"], 0.25);
+
+        let json_path = ".hidden/test_history/history.json";
+        history.save(json_path, 1e-4).unwrap();
+
+        let entries: Vec<serde_json::Value> = serde_json::from_str(&std::fs::read_to_string(json_path).unwrap()).unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0]["valid_loss"], 0.6);
+
+        let csv_path = ".hidden/test_history/history.csv";
+        history.save(csv_path, 1e-4).unwrap();
+
+        assert_eq!(
+            std::fs::read_to_string(csv_path).unwrap(),
+            "epoch,learning_rate,train_loss,valid_loss\n1,0.0001,0.5,0.6\n2,0.0001,0.25,0.3\n"
+        );
+
+        // Recorded learning rates take precedence.
+        history.record_learning_rate(1e-3);
+        assert_eq!(history.epochs(1e-4).iter().map(|e| e.learning_rate).collect::<Vec<_>>(), vec![1e-3, 1e-4]);
+    }
+}
@@ -3,5 +3,6 @@
 pub mod data;
 pub mod execute;
 pub mod helpers;
+pub mod history;
 
 pub use execute::run_training;
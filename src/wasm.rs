@@ -8,15 +8,23 @@ use js_sys::{Array, Object, Reflect};
 use wasm_bindgen::{convert::RefFromWasmAbi, prelude::*};
 
 use crate::core::{
-    base::{HasDescription, HasName, HasPreciseName, HasStaticName, Parsable, PlaybackHandle, Res},
-    chord::{Chord, Chordable, HasChord, HasExtensions, HasInversion, HasIsCrunchy, HasModifiers, HasRoot, HasScale, HasSlash},
-    interval::Interval,
+    base::{HasDescription, HasName, HasPreciseName, HasStaticName, Parsable, PlaybackHandle, Res, Waveform},
+    chord::{Chord, ChordBuilder, Chordable, HasChord, HasExtensions, HasIntervals, HasInversion, HasInversionName, HasIsCrunchy, HasModifiers, HasRoot, HasScale, HasSlash},
+    interval::{HasConsonance, Interval},
+    modifier::{Degree, Extension, Modifier},
     named_pitch::HasNamedPitch,
-    note::{HasPrimaryHarmonicSeries, Note},
+    note::{HasMidiNumber, HasPrimaryHarmonicSeries, Note, NoteRecreator},
     octave::{HasOctave, Octave},
-    pitch::HasFrequency,
+    piano::PianoKeyRole,
+    pitch::{HasFrequency, HasPitch, Pitch},
+    recognizer::ChordRecognizer,
+    scale::{HasParallel, HasRelative, HasScaleKind, Scale, ScaleKind, ALL_SCALE_KINDS},
+    voicing::Voicing,
 };
 
+#[cfg(feature = "analyze_base")]
+use crate::analyze::stream::AudioAnalyzer;
+
 // Use `wee_alloc` as the global allocator.
 #[global_allocator]
 static ALLOC: wee_alloc::WeeAlloc<'_> = wee_alloc::WeeAlloc::INIT;
@@ -56,6 +64,12 @@ impl KordNote {
         Ok(Self { inner: Note::parse(&name).to_js_error()? })
     }
 
+    /// Returns the equal-tempered [`Note`] closest to the given frequency (clamped to octaves `0` through `9`).
+    #[wasm_bindgen(js_name = nearestToFrequency)]
+    pub fn nearest_to_frequency(frequency: f32) -> KordNote {
+        Note::nearest_to_frequency(frequency).into()
+    }
+
     /// Returns [`Note`]s from audio data.
     #[cfg(feature = "analyze_base")]
     #[wasm_bindgen(js_name = fromAudio)]
@@ -65,21 +79,51 @@ impl KordNote {
         Ok(notes.into_js_array())
     }
 
-    /// Returns [`Note`]s from audio data using the ML inference algorithm.
+    /// Returns [`Note`]s from audio data using the ML inference algorithm (with an optional detection threshold, which defaults to `0.5`).
     #[cfg(all(feature = "ml_infer", feature = "analyze_base"))]
     #[wasm_bindgen(js_name = fromAudioMl)]
-    pub fn from_audio_ml(data: &[f32], length_in_seconds: u8) -> JsRes<Array> {
-        let notes = Note::try_from_audio_ml(data, length_in_seconds).to_js_error()?.into_iter().map(KordNote::from);
+    pub fn from_audio_ml(data: &[f32], length_in_seconds: u8, threshold: Option<f32>) -> JsRes<Array> {
+        use crate::ml::base::DEFAULT_INFERENCE_THRESHOLD;
+
+        let notes = Note::try_from_audio_ml_with_threshold(data, length_in_seconds, threshold.unwrap_or(DEFAULT_INFERENCE_THRESHOLD))
+            .to_js_error()?
+            .into_iter()
+            .map(KordNote::from);
 
         Ok(notes.into_js_array())
     }
 
+    /// Returns the ML inference probability of every [`Note`] from audio data, as an array of `{ note, probability }` objects
+    /// (sorted by descending probability).
+    #[cfg(all(feature = "ml_infer", feature = "analyze_base"))]
+    #[wasm_bindgen(js_name = fromAudioMlProbabilities)]
+    pub fn from_audio_ml_probabilities(data: &[f32], length_in_seconds: u8) -> JsRes<Array> {
+        Note::try_from_audio_ml_probabilities(data, length_in_seconds)
+            .to_js_error()?
+            .into_iter()
+            .map(|(note, probability)| {
+                let object = Object::new();
+                Reflect::set(&object, &"note".into(), &KordNote::from(note).into())?;
+                Reflect::set(&object, &"probability".into(), &probability.into())?;
+
+                Ok(object)
+            })
+            .collect::<JsRes<Vec<_>>>()
+            .map(|probabilities| probabilities.into_js_array())
+    }
+
     /// Returns the [`Note`]'s friendly name.
     #[wasm_bindgen]
     pub fn name(&self) -> String {
         self.inner.name()
     }
 
+    /// Returns the [`Note`]'s name without the octave (e.g., `C♯` rather than `C♯4`).
+    #[wasm_bindgen(js_name = nameWithoutOctave)]
+    pub fn name_without_octave(&self) -> String {
+        self.inner.name_without_octave()
+    }
+
     /// Returns the [`Note`] represented as a string (same as `name`).
     #[allow(clippy::inherent_to_string)]
     #[wasm_bindgen(js_name = toString)]
@@ -93,6 +137,13 @@ impl KordNote {
         self.inner.named_pitch().static_name().to_string()
     }
 
+    /// Returns the [`Note`]'s [`Pitch`] as a typed `KordPitch` (i.e., its pitch class, ignoring spelling; e.g., `C♯` and `D♭`
+    /// are both `KordPitch.DFlat`).
+    #[wasm_bindgen(js_name = pitchClass)]
+    pub fn pitch_class(&self) -> Pitch {
+        self.inner.pitch()
+    }
+
     /// Returns the [`Note`]'s [`Octave`].
     #[wasm_bindgen]
     pub fn octave(&self) -> u8 {
@@ -105,6 +156,20 @@ impl KordNote {
         self.inner.frequency()
     }
 
+    /// Returns the [`Note`]'s MIDI note number (where `C4` is `60`).
+    #[wasm_bindgen(js_name = toMidiNumber)]
+    pub fn to_midi_number(&self) -> u8 {
+        self.inner.to_midi_number()
+    }
+
+    /// Creates a new [`Note`] from a MIDI note number (using naturals and sharps).
+    #[wasm_bindgen(js_name = fromMidiNumber)]
+    pub fn from_midi_number(number: u8) -> JsRes<KordNote> {
+        Ok(Self {
+            inner: Note::from_midi_number(number).to_js_error()?,
+        })
+    }
+
     /// Adds the given interval to the [`Note`], producing a new [`Note`] instance.
     #[wasm_bindgen(js_name = addInterval)]
     pub fn add_interval(&self, interval: Interval) -> KordNote {
@@ -127,6 +192,32 @@ impl KordNote {
         self.inner - other.inner
     }
 
+    /// Returns a copy of the [`Note`] in the given octave (`0` - `9`).
+    #[wasm_bindgen(js_name = withOctave)]
+    pub fn with_octave(&self, octave: u8) -> JsRes<KordNote> {
+        if octave > 9 {
+            return Err(JsValue::from_str("Octaves must be in the range 0 - 9."));
+        }
+
+        Ok(Self {
+            inner: self.inner.with_octave(Octave::try_from(octave)?),
+        })
+    }
+
+    /// Returns a copy of the [`Note`] moved by the given number of octaves (erroring outside of octaves `0` - `9`).
+    #[wasm_bindgen(js_name = transposeOctaves)]
+    pub fn transpose_octaves(&self, octaves: i8) -> JsRes<KordNote> {
+        Ok(Self {
+            inner: self.inner.transpose_octaves(octaves).to_js_error()?,
+        })
+    }
+
+    /// Returns the difference, in cents, between the [`Note`] and the given [`Note`].
+    #[wasm_bindgen(js_name = centsFrom)]
+    pub fn cents_from(&self, other: KordNote) -> f32 {
+        self.inner.cents_from(&other.inner)
+    }
+
     /// Returns the primary (first 13) harmonic series of the [`Note`].
     #[wasm_bindgen(js_name = harmonicSeries)]
     pub fn harmonic_series(&self) -> Array {
@@ -135,6 +226,24 @@ impl KordNote {
         series.into_iter().map(KordNote::from).into_js_array()
     }
 
+    /// Returns whether or not the [`Note`] is the same as the given [`Note`] (same spelling and octave).
+    #[wasm_bindgen]
+    pub fn equals(&self, other: &KordNote) -> bool {
+        self.inner == other.inner
+    }
+
+    /// Returns whether or not the [`Note`] sounds the same pitch class as the given [`Note`] (ignoring spelling and octave).
+    #[wasm_bindgen(js_name = pitchClassEquals)]
+    pub fn pitch_class_equals(&self, other: &KordNote) -> bool {
+        self.inner.pitch() == other.inner.pitch()
+    }
+
+    /// Returns a hash of the [`Note`]'s pitch class (equal for any two notes that are `pitchClassEquals`).
+    #[wasm_bindgen(js_name = pitchClassHash)]
+    pub fn pitch_class_hash(&self) -> u32 {
+        1 << self.inner.pitch() as u32
+    }
+
     /// Returns the clone of the [`Note`].
     #[wasm_bindgen]
     pub fn copy(&self) -> KordNote {
@@ -163,6 +272,30 @@ impl From<KordChord> for Chord {
     }
 }
 
+/// The TypeScript shape of the object returned by `KordChord.toObject`.
+#[wasm_bindgen(typescript_custom_section)]
+const KORD_CHORD_OBJECT: &str = r#"
+export interface KordChordObject {
+    name: string;
+    preciseName: string;
+    root: string;
+    slash: string;
+    inversion: number;
+    isCrunchy: boolean;
+    chord: string[];
+    scale: string[];
+    modifiers: string[];
+    extensions: string[];
+}
+"#;
+
+#[wasm_bindgen]
+extern "C" {
+    /// The plain JS object returned by `KordChord.toObject` (typed as the `KordChordObject` TypeScript interface).
+    #[wasm_bindgen(typescript_type = "KordChordObject")]
+    pub type KordChordObject;
+}
+
 /// The [`Chord`] impl.
 #[wasm_bindgen]
 impl KordChord {
@@ -174,15 +307,25 @@ impl KordChord {
         })
     }
 
+    /// Returns a random [`Chord`] that is always the same for the same `seed` (a `BigInt` in JS), which is useful for practice apps.
+    #[wasm_bindgen]
+    pub fn random(seed: u64) -> KordChord {
+        Chord::random_from_seed(seed).into()
+    }
+
+    /// Returns the chord symbols that complete the given partial symbol (e.g., `Cm` completes to `Cm7`, `Cmaj7`, etc.).
+    #[wasm_bindgen]
+    pub fn suggest(partial: String) -> Array {
+        Chord::suggest(&partial).into_iter().map(JsValue::from).into_js_array()
+    }
+
     /// Creates a new [`Chord`] from a set of [`Note`]s.
     ///
-    /// The [`Note`]s should be represented as a space-separated string.
-    /// E.g., `C E G`.
+    /// The [`Note`]s may be separated by any mix of whitespace, commas, hyphens, and slashes.
+    /// E.g., `C E G`, `C, E, G`, or `C-E-G`.
     #[wasm_bindgen(js_name = fromNotesString)]
     pub fn from_notes_string(notes: String) -> JsRes<Array> {
-        let notes = notes.split_whitespace().map(|note| Note::parse(note).to_js_error()).collect::<JsRes<Vec<Note>>>()?;
-
-        let candidates = Chord::try_from_notes(&notes).to_js_error()?.into_iter().map(KordChord::from);
+        let candidates = Chord::try_from_notes_str(&notes).to_js_error()?.into_iter().map(KordChord::from);
 
         Ok(candidates.into_js_array())
     }
@@ -197,6 +340,16 @@ impl KordChord {
         Ok(candidates.into_js_array())
     }
 
+    /// Creates a new [`Chord`] from the pitch classes of a set of [`Note`]s (i.e., ignoring their octaves).
+    #[wasm_bindgen(js_name = fromPitchClasses)]
+    pub fn from_pitch_classes(notes: Array) -> JsRes<Array> {
+        let pitches = notes.cloned_into_vec_inner::<KordNote, Note>()?.iter().map(|note| note.pitch()).collect::<Vec<_>>();
+
+        let candidates = Chord::try_from_pitch_classes(&pitches).to_js_error()?.into_iter().map(KordChord::from);
+
+        Ok(candidates.into_js_array())
+    }
+
     /// Returns the [`Chord`]'s friendly name.
     #[wasm_bindgen]
     pub fn name(&self) -> String {
@@ -225,7 +378,7 @@ impl KordChord {
     /// Returns the [`Chord`]'s display text.
     #[wasm_bindgen]
     pub fn display(&self) -> String {
-        self.inner.to_string()
+        self.inner.summary()
     }
 
     /// Returns the [`Chord`]'s root note.
@@ -246,6 +399,12 @@ impl KordChord {
         self.inner.inversion()
     }
 
+    /// Returns the [`Chord`]'s inversion name (e.g., `first inversion (6)`).
+    #[wasm_bindgen(js_name = inversionName)]
+    pub fn inversion_name(&self) -> String {
+        self.inner.inversion_name()
+    }
+
     /// Returns whether or not the [`Chord`] is "crunchy".
     #[wasm_bindgen(js_name = isCrunchy)]
     pub fn is_crunchy(&self) -> bool {
@@ -258,6 +417,40 @@ impl KordChord {
         self.inner.chord().into_iter().map(KordNote::from).into_js_array()
     }
 
+    /// Returns the [`Chord`]'s tones in the given voicing (`close`, `drop2`, `drop3`, or `drop2and4`).
+    #[wasm_bindgen]
+    pub fn voicing(&self, voicing: String) -> JsRes<Array> {
+        let voicing = Voicing::parse(&voicing).to_js_error()?;
+
+        Ok(self.inner.notes_with_voicing(voicing).into_iter().map(KordNote::from).into_js_array())
+    }
+
+    /// Returns the piano keys in the octaves spanned by the [`Chord`], as an array of `{ midi, isBlack, role }` objects,
+    /// where `role` is one of `none`, `tone`, `root`, or `slash`.
+    #[wasm_bindgen(js_name = pianoKeys)]
+    pub fn piano_keys(&self) -> JsRes<Array> {
+        self.inner
+            .piano_keys()
+            .into_iter()
+            .map(|key| {
+                let role = match key.role {
+                    PianoKeyRole::None => "none",
+                    PianoKeyRole::Tone => "tone",
+                    PianoKeyRole::Root => "root",
+                    PianoKeyRole::Slash => "slash",
+                };
+
+                let object = Object::new();
+                Reflect::set(&object, &"midi".into(), &key.midi.into())?;
+                Reflect::set(&object, &"isBlack".into(), &key.is_black.into())?;
+                Reflect::set(&object, &"role".into(), &role.into())?;
+
+                Ok(object)
+            })
+            .collect::<JsRes<Vec<_>>>()
+            .map(|keys| keys.into_js_array())
+    }
+
     /// Returns the [`Chord`]'s chord tones as a string.
     #[wasm_bindgen(js_name = chordString)]
     pub fn chord_string(&self) -> String {
@@ -276,6 +469,18 @@ impl KordChord {
         self.inner.scale().iter().map(|n| n.name()).collect::<Vec<_>>().join(" ")
     }
 
+    /// Returns the name of the kind of scale the [`Chord`] is built on (e.g., `mixolydian`).
+    #[wasm_bindgen(js_name = scaleName)]
+    pub fn scale_name(&self) -> String {
+        self.inner.scale_kind().name()
+    }
+
+    /// Returns the [`Chord`]'s intervals above the root (e.g., `Perfect Unison, Major Third, Perfect Fifth`).
+    #[wasm_bindgen]
+    pub fn intervals(&self) -> Array {
+        self.inner.intervals().into_iter().map(|i| i.to_string()).into_js_array()
+    }
+
     /// Returns the [`Chord`]'s modifiers.
     #[wasm_bindgen]
     pub fn modifiers(&self) -> Array {
@@ -288,6 +493,40 @@ impl KordChord {
         self.inner.extensions().iter().map(|e| e.static_name()).into_js_array()
     }
 
+    /// Returns the [`Chord`] as a plain JS object with all of its useful fields at once (see the `KordChordObject` TypeScript
+    /// interface, whose field names are stable).
+    ///
+    /// Notes are written with their octaves (e.g., `C4`), and the modifiers and extensions are sorted.  For a slash chord,
+    /// `slash` is the voiced bass note (i.e., the lowest note of `chord`).
+    #[wasm_bindgen(js_name = toObject)]
+    pub fn to_object(&self) -> JsRes<KordChordObject> {
+        let mut modifiers = self.inner.modifiers().iter().copied().collect::<Vec<_>>();
+        modifiers.sort();
+
+        let mut extensions = self.inner.extensions().iter().copied().collect::<Vec<_>>();
+        extensions.sort();
+
+        let chord = self.inner.chord();
+        let slash = match chord.first() {
+            Some(bass) if self.inner.slash() != self.inner.root() => *bass,
+            _ => self.inner.slash(),
+        };
+
+        let object = Object::new();
+        Reflect::set(&object, &"name".into(), &self.inner.name().into())?;
+        Reflect::set(&object, &"preciseName".into(), &self.inner.precise_name().into())?;
+        Reflect::set(&object, &"root".into(), &self.inner.root().name().into())?;
+        Reflect::set(&object, &"slash".into(), &slash.name().into())?;
+        Reflect::set(&object, &"inversion".into(), &self.inner.inversion().into())?;
+        Reflect::set(&object, &"isCrunchy".into(), &self.inner.is_crunchy().into())?;
+        Reflect::set(&object, &"chord".into(), &chord.iter().map(|n| n.name()).into_js_array())?;
+        Reflect::set(&object, &"scale".into(), &self.inner.scale().iter().map(|n| n.name()).into_js_array())?;
+        Reflect::set(&object, &"modifiers".into(), &modifiers.iter().map(|m| m.static_name()).into_js_array())?;
+        Reflect::set(&object, &"extensions".into(), &extensions.iter().map(|e| e.static_name()).into_js_array())?;
+
+        Ok(object.unchecked_into())
+    }
+
     /// Returns a new [`Chord`] with the inversion set to the provided value.
     #[wasm_bindgen(js_name = withInversion)]
     pub fn with_inversion(&self, inversion: u8) -> Self {
@@ -296,6 +535,12 @@ impl KordChord {
         }
     }
 
+    /// Returns the [`Chord`] in every inversion (from root position through the highest inversion).
+    #[wasm_bindgen(js_name = allInversions)]
+    pub fn all_inversions(&self) -> Array {
+        self.inner.all_inversions().into_iter().map(KordChord::from).into_js_array()
+    }
+
     /// Returns a new [`Chord`] with the slash set to the provided value.
     #[wasm_bindgen(js_name = withSlash)]
     pub fn with_slash(&self, slash: &KordNote) -> Self {
@@ -304,6 +549,34 @@ impl KordChord {
         }
     }
 
+    /// Returns a new [`Chord`] transposed up by the given [`Interval`] (keeping its quality, inversion, and crunchiness).
+    #[wasm_bindgen]
+    pub fn transpose(&self, interval: Interval) -> KordChord {
+        KordChord { inner: self.inner.transpose(interval) }
+    }
+
+    /// Returns a new [`Chord`] with the given root (keeping its quality, inversion, and crunchiness).
+    #[wasm_bindgen(js_name = transposeTo)]
+    pub fn transpose_to(&self, root: &KordNote) -> KordChord {
+        KordChord {
+            inner: self.inner.transpose_to(root.inner),
+        }
+    }
+
+    /// Returns a new [`Chord`] with its awkwardly spelled tones (e.g., `F𝄪`) respelled, keeping the root as is.
+    #[wasm_bindgen(js_name = simplifyEnharmonics)]
+    pub fn simplify_enharmonics(&self) -> KordChord {
+        KordChord {
+            inner: self.inner.simplify_enharmonics(),
+        }
+    }
+
+    /// Returns the Roman numeral (functional) label of the [`Chord`] in the given major key (e.g., `G7` in `C` is `"V7"`).
+    #[wasm_bindgen(js_name = relativeTo)]
+    pub fn relative_to(&self, key: &KordNote) -> String {
+        self.inner.relative_to(key.inner)
+    }
+
     /// Returns a new [`Chord`] with the octave of the root set to the provided value.
     #[wasm_bindgen(js_name = withOctave)]
     pub fn with_octave(&self, octave: u8) -> JsRes<KordChord> {
@@ -312,6 +585,14 @@ impl KordChord {
         })
     }
 
+    /// Returns a new [`Chord`] with the octave of the bass note set to the provided value.
+    #[wasm_bindgen(js_name = withBassOctave)]
+    pub fn with_bass_octave(&self, octave: u8) -> JsRes<KordChord> {
+        Ok(KordChord {
+            inner: self.inner.clone().with_bass_octave(Octave::try_from(octave)?),
+        })
+    }
+
     /// Returns a new [`Chord`] with the "crunchiness" set to the provided value.
     #[wasm_bindgen(js_name = withCrunchy)]
     pub fn with_crunchy(&self, is_crunchy: bool) -> Self {
@@ -320,26 +601,106 @@ impl KordChord {
         }
     }
 
-    /// Plays the [`Chord`].
+    /// Plays the [`Chord`] (with an optional [`Waveform`], which defaults to sine tones).
     #[wasm_bindgen]
     #[cfg(feature = "audio")]
-    pub async fn play(&self, delay: f32, length: f32, fade_in: f32) -> JsRes<()> {
-        use crate::core::base::Playable;
+    pub async fn play(&self, delay: f32, length: f32, fade_in: f32, waveform: Option<Waveform>) -> JsRes<()> {
+        use crate::core::base::{Playable, RenderOptions};
         use anyhow::Context;
         use gloo_timers::future::TimeoutFuture;
         use std::time::Duration;
 
         let delay = Duration::from_secs_f32(delay);
         let length = Duration::from_secs_f32(length);
-        let fade_in = Duration::from_secs_f32(fade_in);
+        let options = RenderOptions {
+            waveform: waveform.unwrap_or_default(),
+            ..RenderOptions::with_fade_in(Duration::from_secs_f32(fade_in))
+        };
 
-        let _handle = self.inner.play(delay, length, fade_in).context("Could not start the playback.").to_js_error()?;
+        let _handle = self.inner.play_with_options(delay, length, options).context("Could not start the playback.").to_js_error()?;
 
         TimeoutFuture::new(length.as_millis() as u32).await;
 
         Ok(())
     }
 
+    /// Plays the [`Chord`] arpeggiated (or strummed), with `delay_per_note` between successive onsets in the given direction.
+    ///
+    /// A `delay_per_note` of zero plays the chord as a block.
+    #[wasm_bindgen(js_name = playArpeggio)]
+    #[cfg(feature = "audio")]
+    pub async fn play_arpeggio(&self, delay_per_note: f32, direction: crate::core::base::ArpeggioDirection, length: f32) -> JsRes<()> {
+        use anyhow::Context;
+        use gloo_timers::future::TimeoutFuture;
+        use std::time::Duration;
+
+        let delay = Duration::from_secs_f32(delay_per_note);
+        let length = Duration::from_secs_f32(length);
+
+        let _handle = self
+            .inner
+            .play_arpeggio(delay, length, Duration::ZERO, direction)
+            .context("Could not start the playback.")
+            .to_js_error()?;
+
+        TimeoutFuture::new(length.as_millis() as u32).await;
+
+        Ok(())
+    }
+
+    /// Renders the [`Chord`] into the bytes of a 16-bit PCM WAV file (with an optional [`Waveform`], which defaults to sine tones).
+    #[wasm_bindgen(js_name = toWav)]
+    pub fn to_wav(&self, length: f32, sample_rate: u32, fade_in: f32, waveform: Option<Waveform>) -> JsRes<Vec<u8>> {
+        use crate::core::base::RenderOptions;
+        use std::time::Duration;
+
+        let options = RenderOptions {
+            waveform: waveform.unwrap_or_default(),
+            sample_rate,
+            ..RenderOptions::with_fade_in(Duration::from_secs_f32(fade_in))
+        };
+
+        self.inner.to_wav_bytes(Duration::from_secs_f32(length), options).to_js_error()
+    }
+
+    /// Returns whether or not the [`Chord`] is the same as the given [`Chord`] (built and spelled the same way).
+    #[wasm_bindgen]
+    pub fn equals(&self, other: &KordChord) -> bool {
+        self.inner == other.inner
+    }
+
+    /// Returns whether or not the [`Chord`] sounds the same pitch classes as the given [`Chord`] (ignoring spelling, octaves, and voicing).
+    #[wasm_bindgen(js_name = pitchClassEquals)]
+    pub fn pitch_class_equals(&self, other: &KordChord) -> bool {
+        self.pitch_class_hash() == other.pitch_class_hash()
+    }
+
+    /// Returns a hash of the [`Chord`]'s pitch classes (equal for any two chords that are `pitchClassEquals`).
+    ///
+    /// The hash is the set of pitch classes as a 12-bit mask (where `C` is the lowest bit).
+    #[wasm_bindgen(js_name = pitchClassHash)]
+    pub fn pitch_class_hash(&self) -> u32 {
+        self.inner.chord().iter().fold(0, |mask, note| mask | 1 << note.pitch() as u32)
+    }
+
+    /// Returns whether or not the [`Chord`] contains the pitch class of the given [`Note`] (ignoring spelling and octave).
+    #[wasm_bindgen(js_name = containsNote)]
+    pub fn contains_note(&self, note: &KordNote) -> bool {
+        self.inner.contains_note(&note.inner)
+    }
+
+    /// Returns whether or not the [`Chord`] contains the given pitch class (e.g., `B♭` or `A#`).
+    #[wasm_bindgen(js_name = containsPitch)]
+    pub fn contains_pitch(&self, pitch: String) -> JsRes<bool> {
+        Ok(self.inner.contains_pitch(Note::parse(&pitch).to_js_error()?.pitch()))
+    }
+
+    /// Returns whether or not the [`Chord`] contains exactly the given [`Note`] (matching both the spelling and the octave).
+    #[wasm_bindgen(js_name = containsExact)]
+    pub fn contains_exact(&self, note: &KordNote) -> bool {
+        self.inner.contains_exact(&note.inner)
+    }
+
     /// Returns the clone of the [`Chord`].
     #[wasm_bindgen]
     pub fn copy(&self) -> KordChord {
@@ -347,6 +708,364 @@ impl KordChord {
     }
 }
 
+// [`Scale`] ABI.
+
+/// The [`Scale`] wrapper.
+#[derive(Clone, Debug)]
+#[wasm_bindgen]
+pub struct KordScale {
+    inner: Scale,
+}
+
+impl From<Scale> for KordScale {
+    fn from(scale: Scale) -> Self {
+        KordScale { inner: scale }
+    }
+}
+
+impl From<KordScale> for Scale {
+    fn from(kord_scale: KordScale) -> Self {
+        kord_scale.inner
+    }
+}
+
+/// The [`Scale`] impl.
+#[wasm_bindgen]
+impl KordScale {
+    /// Creates a new [`Scale`] of the given kind (e.g., `dorian`) on the given root.
+    #[wasm_bindgen(constructor)]
+    pub fn new(root: &KordNote, kind: String) -> JsRes<KordScale> {
+        Ok(Self {
+            inner: Scale::new(root.inner, ScaleKind::parse(&kind).to_js_error()?),
+        })
+    }
+
+    /// Parses a [`Scale`] from a root note followed by the name of its kind (e.g., `C major`, or `F# dorian`).
+    #[wasm_bindgen]
+    pub fn parse(name: String) -> JsRes<KordScale> {
+        Ok(Self {
+            inner: Scale::parse(&name).to_js_error()?,
+        })
+    }
+
+    /// Returns the names of all the kinds of scales (i.e., the modes), which are accepted by `parse` and the constructor.
+    #[wasm_bindgen]
+    pub fn all() -> Array {
+        ALL_SCALE_KINDS.iter().map(|kind| JsValue::from_str(kind.static_name())).into_js_array()
+    }
+
+    /// Returns the [`Scale`]'s friendly name (e.g., `C major`).
+    #[wasm_bindgen]
+    pub fn name(&self) -> String {
+        self.inner.name()
+    }
+
+    /// Returns the [`Scale`] represented as a string (same as `name`).
+    #[allow(clippy::inherent_to_string)]
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_string(&self) -> String {
+        self.inner.name()
+    }
+
+    /// Returns the name of the kind of the [`Scale`] (e.g., `harmonic minor`).
+    #[wasm_bindgen]
+    pub fn kind(&self) -> String {
+        self.inner.kind().static_name().to_string()
+    }
+
+    /// Returns the root [`Note`] of the [`Scale`].
+    #[wasm_bindgen]
+    pub fn root(&self) -> KordNote {
+        self.inner.root().into()
+    }
+
+    /// Returns the [`Note`]s of the [`Scale`].
+    #[wasm_bindgen]
+    pub fn notes(&self) -> Array {
+        self.inner.scale().into_iter().map(KordNote::from).into_js_array()
+    }
+
+    /// Returns the [`Note`] on the given (1-indexed) degree of the [`Scale`] (e.g., degree `5` of C major is `G`).
+    #[wasm_bindgen]
+    pub fn degree(&self, n: u8) -> JsRes<KordNote> {
+        Ok(self.inner.degree(n).to_js_error()?.into())
+    }
+
+    /// Returns the triad built on the given (1-indexed) degree of the [`Scale`].
+    #[wasm_bindgen(js_name = chordOnDegree)]
+    pub fn chord_on_degree(&self, n: u8) -> JsRes<KordChord> {
+        if n == 0 {
+            return Err(JsValue::from_str("Scale degrees start at 1."));
+        }
+
+        Ok(self.inner.chord_on_degree(n).into())
+    }
+
+    /// Returns the seventh chord built on the given (1-indexed) degree of the [`Scale`].
+    #[wasm_bindgen(js_name = seventhChordOnDegree)]
+    pub fn seventh_chord_on_degree(&self, n: u8) -> JsRes<KordChord> {
+        if n == 0 {
+            return Err(JsValue::from_str("Scale degrees start at 1."));
+        }
+
+        Ok(self.inner.seventh_chord_on_degree(n).into())
+    }
+
+    /// Returns a copy of the [`Scale`] with the given root.
+    #[wasm_bindgen(js_name = withRoot)]
+    pub fn with_root(&self, root: &KordNote) -> KordScale {
+        Scale::new(root.inner, self.inner.kind()).into()
+    }
+
+    /// Returns the relative [`Scale`] (e.g., A minor for C major).
+    #[wasm_bindgen]
+    pub fn relative(&self) -> KordScale {
+        self.inner.relative().into()
+    }
+
+    /// Returns the parallel [`Scale`] (e.g., C minor for C major).
+    #[wasm_bindgen]
+    pub fn parallel(&self) -> KordScale {
+        self.inner.parallel().into()
+    }
+
+    /// Returns the clone of the [`Scale`].
+    #[wasm_bindgen]
+    pub fn copy(&self) -> KordScale {
+        self.clone()
+    }
+}
+
+// [`Interval`] ABI.
+
+/// Returns whether or not the [`Interval`] is consonant (either a perfect or an imperfect consonance).
+#[wasm_bindgen(js_name = intervalIsConsonant)]
+pub fn interval_is_consonant(interval: Interval) -> bool {
+    interval.is_consonant()
+}
+
+/// Returns whether or not the [`Interval`] is a perfect consonance (unison, fourth, fifth, or octave, and their compounds).
+#[wasm_bindgen(js_name = intervalIsPerfectConsonance)]
+pub fn interval_is_perfect_consonance(interval: Interval) -> bool {
+    interval.is_perfect_consonance()
+}
+
+/// Returns whether or not the [`Interval`] is an imperfect consonance (major / minor thirds and sixths, and their compounds).
+#[wasm_bindgen(js_name = intervalIsImperfectConsonance)]
+pub fn interval_is_imperfect_consonance(interval: Interval) -> bool {
+    interval.is_imperfect_consonance()
+}
+
+/// Returns whether or not the [`Interval`] is dissonant.
+#[wasm_bindgen(js_name = intervalIsDissonant)]
+pub fn interval_is_dissonant(interval: Interval) -> bool {
+    interval.is_dissonant()
+}
+
+/// The [`Interval`] wrapper.
+///
+/// The [`Interval`] enum is exported as [`KordInterval`], which cannot carry methods, so this wrapper exposes them.
+#[derive(Clone, Copy, Debug)]
+#[wasm_bindgen]
+pub struct KordIntervalValue {
+    inner: Interval,
+}
+
+impl From<Interval> for KordIntervalValue {
+    fn from(interval: Interval) -> Self {
+        KordIntervalValue { inner: interval }
+    }
+}
+
+impl From<KordIntervalValue> for Interval {
+    fn from(interval: KordIntervalValue) -> Self {
+        interval.inner
+    }
+}
+
+#[wasm_bindgen]
+impl KordIntervalValue {
+    /// Creates a new [`Interval`] wrapper from an [`Interval`].
+    #[wasm_bindgen(constructor)]
+    pub fn new(interval: Interval) -> KordIntervalValue {
+        KordIntervalValue { inner: interval }
+    }
+
+    /// Parses an [`Interval`] from its short name (e.g., `P5`, `m3`, `M9`), or its full name (e.g., `Perfect Fifth`).
+    #[wasm_bindgen]
+    pub fn parse(name: String) -> JsRes<KordIntervalValue> {
+        Ok(Self { inner: Interval::parse(&name).to_js_error()? })
+    }
+
+    /// Returns the [`Interval`] between two [`Note`]s (from the lower to the higher, regardless of order).
+    #[wasm_bindgen]
+    pub fn between(a: &KordNote, b: &KordNote) -> KordIntervalValue {
+        Self { inner: a.inner - b.inner }
+    }
+
+    /// Returns the [`Interval`] enum value.
+    #[wasm_bindgen]
+    pub fn interval(&self) -> Interval {
+        self.inner
+    }
+
+    /// Returns the number of semitones spanned by the [`Interval`].
+    #[wasm_bindgen]
+    pub fn semitones(&self) -> u8 {
+        self.inner.semitones()
+    }
+
+    /// Returns the static name of the [`Interval`].
+    #[wasm_bindgen(js_name = staticName)]
+    pub fn static_name(&self) -> String {
+        self.inner.static_name().to_string()
+    }
+
+    /// Returns the inversion of the [`Interval`].
+    #[wasm_bindgen]
+    pub fn invert(&self) -> KordIntervalValue {
+        Self { inner: self.inner.inverted() }
+    }
+
+    /// Returns the [`Interval`]'s string representation.
+    #[allow(clippy::inherent_to_string)]
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_string(&self) -> String {
+        self.inner.to_string()
+    }
+}
+
+// [`ChordRecognizer`] ABI.
+
+/// The [`ChordRecognizer`] wrapper.
+#[derive(Clone, Debug, Default)]
+#[wasm_bindgen]
+pub struct KordChordRecognizer {
+    inner: ChordRecognizer,
+}
+
+/// The [`ChordRecognizer`] impl.
+#[wasm_bindgen]
+impl KordChordRecognizer {
+    /// Creates a new, empty [`ChordRecognizer`].
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a [`Note`], and returns the new best [`Chord`] interpretation (if any).
+    #[wasm_bindgen(js_name = addNote)]
+    pub fn add_note(&mut self, note: &KordNote) -> Option<KordChord> {
+        self.inner.add_note(note.inner).cloned().map(KordChord::from)
+    }
+
+    /// Removes a [`Note`], and returns the new best [`Chord`] interpretation (if any).
+    #[wasm_bindgen(js_name = removeNote)]
+    pub fn remove_note(&mut self, note: &KordNote) -> Option<KordChord> {
+        self.inner.remove_note(note.inner).cloned().map(KordChord::from)
+    }
+
+    /// Removes all of the accumulated [`Note`]s.
+    #[wasm_bindgen]
+    pub fn clear(&mut self) {
+        self.inner.clear();
+    }
+
+    /// Returns the accumulated [`Note`]s.
+    #[wasm_bindgen]
+    pub fn notes(&self) -> Array {
+        self.inner.notes().into_iter().map(KordNote::from).into_js_array()
+    }
+
+    /// Returns the best [`Chord`] interpretation of the accumulated [`Note`]s (if any).
+    #[wasm_bindgen(js_name = currentBest)]
+    pub fn current_best(&self) -> Option<KordChord> {
+        self.inner.current_best().cloned().map(KordChord::from)
+    }
+}
+
+// Microphone ABI.
+
+/// A real-time microphone listener.
+///
+/// Chunks of (mono) samples captured in JS (e.g., from an `AudioWorklet`) are pushed in, and the
+/// [`Note`]s / [`Chord`] in the most recent window of audio can be queried at any time.
+#[cfg(feature = "analyze_base")]
+#[derive(Clone, Debug)]
+#[wasm_bindgen]
+pub struct KordMicrophone {
+    inner: AudioAnalyzer,
+}
+
+/// The [`KordMicrophone`] impl.
+#[cfg(feature = "analyze_base")]
+#[wasm_bindgen]
+impl KordMicrophone {
+    /// Creates a new [`KordMicrophone`] for samples at the given sample rate, analyzing windows of `length_in_seconds`.
+    #[wasm_bindgen(constructor)]
+    pub fn new(sample_rate: u32, length_in_seconds: u8) -> JsRes<KordMicrophone> {
+        Ok(Self {
+            inner: AudioAnalyzer::new(sample_rate, length_in_seconds).to_js_error()?,
+        })
+    }
+
+    /// Sets the number of samples between successive detections (defaults to a full window).
+    #[wasm_bindgen(js_name = withHopSize)]
+    pub fn with_hop_size(self, hop_size: usize) -> JsRes<KordMicrophone> {
+        Ok(Self {
+            inner: self.inner.with_hop_size(hop_size).to_js_error()?,
+        })
+    }
+
+    /// Pushes a chunk of samples into the listener, and returns the [`Note`]s detected at each hop completed by the chunk.
+    #[wasm_bindgen(js_name = pushSamples)]
+    pub fn push_samples(&mut self, samples: &[f32]) -> JsRes<Array> {
+        let detections = self.inner.push(samples).to_js_error()?;
+
+        Ok(detections.into_iter().map(|notes| notes.into_iter().map(KordNote::from).into_js_array()).into_js_array())
+    }
+
+    /// Flushes the listener, and returns the [`Note`]s detected in the last (zero-padded) partial window, if any.
+    #[wasm_bindgen]
+    pub fn finish(&mut self) -> JsRes<Option<Array>> {
+        Ok(self.inner.finish().to_js_error()?.map(|notes| notes.into_iter().map(KordNote::from).into_js_array()))
+    }
+
+    /// Removes all of the pushed samples.
+    #[wasm_bindgen]
+    pub fn clear(&mut self) {
+        self.inner.clear();
+    }
+
+    /// Returns whether or not a full window of samples has been pushed.
+    #[wasm_bindgen(js_name = isReady)]
+    pub fn is_ready(&self) -> bool {
+        self.inner.is_ready()
+    }
+
+    /// Returns the [`Note`]s detected in the most recent window (empty until a full window has been pushed).
+    #[wasm_bindgen(js_name = currentNotes)]
+    pub fn current_notes(&self) -> JsRes<Array> {
+        Ok(self.inner.current_notes().to_js_error()?.into_iter().map(KordNote::from).into_js_array())
+    }
+
+    /// Returns the best [`Chord`] interpretation of the detected [`Note`]s (if any).
+    #[wasm_bindgen(js_name = currentChord)]
+    pub fn current_chord(&self) -> JsRes<Option<KordChord>> {
+        let notes = self.inner.current_notes().to_js_error()?;
+
+        Ok(Chord::try_from_notes(&notes).ok().and_then(|candidates| candidates.into_iter().next()).map(KordChord::from))
+    }
+}
+
+/// Returns the chromagram of the (mono) audio data as a `Float32Array` of length 12 (the normalized energy of each pitch class,
+/// starting at `C`).
+#[cfg(feature = "analyze_base")]
+#[wasm_bindgen]
+pub fn chromagram(data: &[f32], sample_rate: u32) -> Vec<f32> {
+    crate::analyze::chromagram(data, sample_rate).to_vec()
+}
+
 // Playback handle.
 
 /// A handle to a [`Chord`] playback.
@@ -392,6 +1111,127 @@ pub enum KordModifier {
 
     /// Diminished modifier.
     Diminished,
+
+    /// Altered modifier.
+    Altered,
+}
+
+impl From<KordModifier> for Modifier {
+    fn from(kord_modifier: KordModifier) -> Self {
+        match kord_modifier {
+            KordModifier::Minor => Modifier::Minor,
+            KordModifier::Flat5 => Modifier::Flat5,
+            KordModifier::Augmented5 => Modifier::Augmented5,
+            KordModifier::Major7 => Modifier::Major7,
+            KordModifier::Dominant7 => Modifier::Dominant(Degree::Seven),
+            KordModifier::Dominant9 => Modifier::Dominant(Degree::Nine),
+            KordModifier::Dominant11 => Modifier::Dominant(Degree::Eleven),
+            KordModifier::Dominant13 => Modifier::Dominant(Degree::Thirteen),
+            KordModifier::Flat9 => Modifier::Flat9,
+            KordModifier::Sharp9 => Modifier::Sharp9,
+            KordModifier::Sharp11 => Modifier::Sharp11,
+            KordModifier::Altered => Modifier::Altered,
+            KordModifier::Diminished => Modifier::Diminished,
+        }
+    }
+}
+
+impl From<Modifier> for KordModifier {
+    fn from(modifier: Modifier) -> Self {
+        match modifier {
+            Modifier::Minor => KordModifier::Minor,
+            Modifier::Flat5 => KordModifier::Flat5,
+            Modifier::Augmented5 => KordModifier::Augmented5,
+            Modifier::Major7 => KordModifier::Major7,
+            Modifier::Dominant(Degree::Seven) => KordModifier::Dominant7,
+            Modifier::Dominant(Degree::Nine) => KordModifier::Dominant9,
+            Modifier::Dominant(Degree::Eleven) => KordModifier::Dominant11,
+            Modifier::Dominant(Degree::Thirteen) => KordModifier::Dominant13,
+            Modifier::Flat9 => KordModifier::Flat9,
+            Modifier::Sharp9 => KordModifier::Sharp9,
+            Modifier::Sharp11 => KordModifier::Sharp11,
+            Modifier::Altered => KordModifier::Altered,
+            Modifier::Diminished => KordModifier::Diminished,
+        }
+    }
+}
+
+// [`Chord`] builder.
+
+/// A builder for arbitrary [`Chord`]s.
+///
+/// Every method consumes the builder and returns it, so that calls can be chained from JS
+/// (e.g., `new KordChordBuilder().root(c).addModifier(KordModifier.Minor).build()`).
+#[derive(Clone, Debug, Default)]
+#[wasm_bindgen]
+pub struct KordChordBuilder {
+    inner: ChordBuilder,
+    has_root: bool,
+}
+
+/// The [`KordChordBuilder`] impl.
+#[wasm_bindgen]
+impl KordChordBuilder {
+    /// Creates a new, empty [`KordChordBuilder`].
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the root [`Note`] of the [`Chord`].
+    #[wasm_bindgen]
+    pub fn root(mut self, root: &KordNote) -> Self {
+        self.inner = self.inner.root(root.inner);
+        self.has_root = true;
+        self
+    }
+
+    /// Adds a modifier to the [`Chord`].
+    #[wasm_bindgen(js_name = addModifier)]
+    pub fn add_modifier(mut self, modifier: KordModifier) -> Self {
+        self.inner = self.inner.modifier(modifier.into());
+        self
+    }
+
+    /// Adds an extension to the [`Chord`].
+    #[wasm_bindgen(js_name = addExtension)]
+    pub fn add_extension(mut self, extension: Extension) -> Self {
+        self.inner = self.inner.extension(extension);
+        self
+    }
+
+    /// Sets the inversion of the [`Chord`].
+    #[wasm_bindgen]
+    pub fn inversion(mut self, inversion: u8) -> Self {
+        self.inner = self.inner.inversion(inversion);
+        self
+    }
+
+    /// Sets the slash [`Note`] of the [`Chord`].
+    #[wasm_bindgen]
+    pub fn slash(mut self, slash: &KordNote) -> Self {
+        self.inner = self.inner.slash(slash.inner);
+        self
+    }
+
+    /// Sets whether or not the [`Chord`] is "crunchy".
+    #[wasm_bindgen]
+    pub fn crunchy(mut self, is_crunchy: bool) -> Self {
+        self.inner = self.inner.crunchy(is_crunchy);
+        self
+    }
+
+    /// Builds the [`Chord`].
+    ///
+    /// Fails if no root has been set.
+    #[wasm_bindgen]
+    pub fn build(self) -> JsRes<KordChord> {
+        if !self.has_root {
+            return Err("A root note is required to build a chord.".into());
+        }
+
+        Ok(KordChord::from(self.inner.build()))
+    }
 }
 
 // Helpers.
@@ -544,6 +1384,22 @@ impl RefFromJsValue for KordChord {
 // The [`Chord`] modifier / extension impl.
 #[wasm_bindgen]
 impl KordChord {
+    /// Returns a new [`Chord`] with the given modifier.
+    #[wasm_bindgen(js_name = withModifier)]
+    pub fn with_modifier(&self, modifier: KordModifier) -> Self {
+        KordChord {
+            inner: self.inner.clone().with_modifier(modifier.into()),
+        }
+    }
+
+    /// Returns a new [`Chord`] with the given extension.
+    #[wasm_bindgen(js_name = withExtension)]
+    pub fn with_extension(&self, extension: Extension) -> Self {
+        KordChord {
+            inner: self.inner.clone().with_extension(extension),
+        }
+    }
+
     /// Returns a new [`Chord`] with the `minor` modifier.
     #[wasm_bindgen]
     pub fn minor(&self) -> Self {
@@ -622,6 +1478,12 @@ impl KordChord {
         KordChord { inner: self.inner.clone().half_dim() }
     }
 
+    /// Returns a new [`Chord`] with the `alt` modifier (adding a dominant 7 if needed).
+    #[wasm_bindgen]
+    pub fn altered(&self) -> Self {
+        KordChord { inner: self.inner.clone().altered() }
+    }
+
     /// Returns a new [`Chord`] with the `sus2` extension.
     #[wasm_bindgen]
     pub fn sus2(&self) -> Self {
@@ -688,3 +1550,166 @@ impl KordChord {
         KordChord { inner: self.inner.clone().add13() }
     }
 }
+
+// Tests.
+
+#[cfg(all(test, target_arch = "wasm32"))]
+mod tests {
+    use super::*;
+    use crate::core::{
+        modifier::known_modifier_sets,
+        note::{C, E, G},
+    };
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn test_chord_builder() {
+        let chord = KordChordBuilder::new()
+            .root(&KordNote::from(C))
+            .add_modifier(KordModifier::Minor)
+            .add_modifier(KordModifier::Dominant7)
+            .add_modifier(KordModifier::Flat5)
+            .build()
+            .unwrap();
+
+        assert_eq!(chord.name(), "Cm7(♭5)");
+        assert_eq!(chord.chord_string(), "C4 E♭4 G♭4 B♭4");
+    }
+
+    #[wasm_bindgen_test]
+    fn test_interval_value() {
+        let interval = KordIntervalValue::parse("M3".to_string()).unwrap();
+
+        assert_eq!(interval.interval(), Interval::MajorThird);
+        assert_eq!(interval.semitones(), 4);
+        assert_eq!(interval.invert().interval(), Interval::MinorSixth);
+        assert_eq!(KordIntervalValue::between(&KordNote::from(G), &KordNote::from(C)).interval(), Interval::PerfectFifth);
+        assert!(KordIntervalValue::parse("P3".to_string()).is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_intervals() {
+        let intervals = KordChord::parse("C".to_string()).unwrap().intervals();
+
+        assert_eq!(
+            intervals.iter().map(|v| v.as_string().unwrap()).collect::<Vec<_>>(),
+            vec!["Perfect Unison", "Major Third", "Perfect Fifth"]
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn test_modifier_round_trip() {
+        for modifier in known_modifier_sets().iter().flatten() {
+            assert_eq!(Modifier::from(KordModifier::from(*modifier)), *modifier);
+        }
+
+        assert_eq!(Modifier::from(KordModifier::Dominant11), Modifier::Dominant(Degree::Eleven));
+
+        let chord = KordChord::from(Chord::new(C))
+            .with_modifier(KordModifier::Minor)
+            .with_modifier(KordModifier::Dominant9)
+            .with_extension(Extension::Add13);
+
+        assert_eq!(chord.name(), "Cm9(add13)");
+        assert_eq!(chord.chord_string(), "C4 E♭4 G4 B♭4 D5 A5");
+    }
+
+    #[wasm_bindgen_test]
+    fn test_scale() {
+        let scale = KordScale::parse("C major".to_string()).unwrap();
+
+        assert_eq!(scale.name(), "C major");
+        assert_eq!(scale.notes().length(), 7);
+        assert_eq!(scale.degree(5).unwrap().name(), "G4");
+        assert!(scale.degree(0).is_err());
+        assert!(scale.degree(u8::MAX).is_err());
+        assert_eq!(scale.seventh_chord_on_degree(5).unwrap().name(), "G7");
+        assert_eq!(scale.with_root(&KordNote::from(E)).name(), "E major");
+        assert_eq!(KordScale::new(&KordNote::from(C), "dorian".to_string()).unwrap().kind(), "dorian");
+        assert_eq!(KordScale::all().length(), ALL_SCALE_KINDS.len() as u32);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_equals() {
+        let chord = |symbol: &str| KordChord::parse(symbol.to_string()).unwrap();
+        let note = |name: &str| KordNote::parse(name.to_string()).unwrap();
+
+        assert!(chord("Cmaj7").equals(&chord("Cmaj7")));
+        assert!(!chord("C♯").equals(&chord("D♭")));
+        assert!(chord("C♯").pitch_class_equals(&chord("D♭@2")));
+        assert!(chord("C/E").pitch_class_equals(&chord("C")));
+        assert!(!chord("C").pitch_class_equals(&chord("Cm")));
+        assert_eq!(chord("C♯").pitch_class_hash(), chord("D♭").pitch_class_hash());
+
+        assert!(note("C4").equals(&note("C4")));
+        assert!(!note("C4").equals(&note("C5")));
+        assert!(note("C♯4").pitch_class_equals(&note("D♭2")));
+        assert_eq!(note("B♯3").pitch_class_hash(), note("C4").pitch_class_hash());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_pitch_class() {
+        let note = |name: &str| KordNote::parse(name.to_string()).unwrap();
+
+        assert_eq!(note("C4").pitch_class(), Pitch::C);
+        assert_eq!(note("C♯4").pitch_class(), Pitch::DFlat);
+        assert_eq!(note("D♭2").pitch_class(), Pitch::DFlat);
+        assert_eq!(note("B♯3").pitch_class(), Pitch::C);
+
+        // The string form keeps the spelling.
+        assert_eq!(note("C♯4").pitch(), "C♯");
+
+        // The `KordPitch` values are the pitch class numbers.
+        assert_eq!(note("B4").pitch_class() as u8, 11);
+        assert_eq!(note("A4").pitch_class() as u32, note("A4").pitch_class_hash().trailing_zeros());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_to_object() {
+        let object = KordChord::parse("Cm7/G".to_string()).unwrap().to_object().unwrap();
+        let get = |key: &str| Reflect::get(&object, &key.into()).unwrap();
+        let strings = |key: &str| Array::from(&get(key)).iter().map(|v| v.as_string().unwrap()).collect::<Vec<_>>();
+
+        assert_eq!(get("name").as_string().unwrap(), "Cm7/G");
+        assert_eq!(get("preciseName").as_string().unwrap(), "Cm7/G");
+        assert_eq!(get("root").as_string().unwrap(), "C4");
+        assert_eq!(get("slash").as_string().unwrap(), "G3");
+        assert_eq!(get("inversion").as_f64().unwrap(), 0.0);
+        assert!(!get("isCrunchy").as_bool().unwrap());
+        assert_eq!(strings("chord"), vec!["G3", "C4", "E♭4", "G4", "B♭4"]);
+        assert_eq!(strings("scale").len(), 7);
+        assert_eq!(strings("modifiers"), vec!["m", "7"]);
+        assert!(strings("extensions").is_empty());
+
+        // Without a slash, the slash is the root.
+        let object = KordChord::parse("Cm7".to_string()).unwrap().to_object().unwrap();
+        assert_eq!(Reflect::get(&object, &"slash".into()).unwrap().as_string().unwrap(), "C4");
+    }
+
+    #[cfg(feature = "analyze_base")]
+    #[wasm_bindgen_test]
+    fn test_microphone() {
+        let sample_rate = 44_100;
+        let frequencies = [C, E, G].map(|n| n.frequency());
+
+        let samples = (0..sample_rate)
+            .map(|n| {
+                let t = n as f32 / sample_rate as f32;
+
+                frequencies.iter().map(|f| (2.0 * std::f32::consts::PI * f * t).sin()).sum::<f32>()
+            })
+            .collect::<Vec<_>>();
+
+        assert!(KordMicrophone::new(0, 1).is_err());
+
+        let mut microphone = KordMicrophone::new(sample_rate, 1).unwrap();
+
+        for chunk in samples.chunks(128) {
+            microphone.push_samples(chunk).unwrap();
+        }
+
+        assert!(microphone.is_ready());
+        assert_eq!(microphone.current_notes().unwrap().length(), 3);
+        assert_eq!(microphone.current_chord().unwrap().unwrap().name(), "C");
+    }
+}
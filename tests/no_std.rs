@@ -0,0 +1,39 @@
+//! Checks that the core note, pitch, interval, and octave math is usable from a `no_std` crate.
+//!
+//! Run with `cargo test --no-default-features --features no_std --test no_std`.
+
+#![no_std]
+
+extern crate alloc;
+
+use alloc::vec;
+
+use klib::core::{
+    base::HasName,
+    interval::Interval,
+    named_pitch::{HasNamedPitch, NamedPitch},
+    note::{HasPrimaryHarmonicSeries, Note, A, C, CFive, E, G},
+    octave::{HasOctave, Octave},
+    pitch::{HasFrequency, HasPitch, Pitch},
+};
+
+#[test]
+fn test_notes_and_intervals() {
+    let note = Note::new(NamedPitch::C, Octave::Four);
+
+    assert_eq!(note, C);
+    assert_eq!(note.name(), "C4");
+    assert_eq!(note.pitch(), Pitch::C);
+    assert_eq!(note.named_pitch(), NamedPitch::C);
+    assert_eq!(note.octave(), Octave::Four);
+
+    assert_eq!(C + Interval::MajorThird, E);
+    assert_eq!(C + Interval::PerfectFifth, G);
+    assert_eq!(G - C, Interval::PerfectFifth);
+    assert_eq!(C + Interval::PerfectOctave, CFive);
+
+    assert_eq!(A.frequency(), 440.0);
+    assert!((CFive.cents_from(&C) - 1200.0).abs() < 0.1);
+
+    assert_eq!(C.primary_harmonic_series()[..3], vec![C, CFive, CFive + Interval::PerfectFifth][..]);
+}